@@ -122,11 +122,7 @@ pub fn inner_perft<const DIV: bool>(board: &Board, depth: usize) -> usize {
     let mut total: usize = 0;
 
     if !DIV && depth <= 1 {
-        enumerate_legal_moves::<AllMoves, _>(board, |_| -> bool {
-            total += 1;
-            true
-        });
-        return total;
+        return count_legal_moves::<AllMoves>(board);
     }
 
     enumerate_legal_moves::<AllMoves, _>(board, |mv: Move| -> bool {
@@ -0,0 +1,115 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`arbitrary`] support for fuzzing, enabled by the `arbitrary` feature.
+//!
+//! [`Square`], [`Piece`] and [`CastleRights`] draw from their valid values, and [`Move`]
+//! combines any two squares with any [`MoveType`], so every valid encoding is covered. A [`Board`] is
+//! reached by a random legal playout from the starting position, so it always
+//! satisfies [`Board::validate`] and round-trips through FEN.
+
+use crate::{AllMoves, Board, CastleRights, Move, MoveList, MoveType, Piece, Square, gen_moves};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// The maximum length of the playout used to build an arbitrary [`Board`].
+const MAX_PLIES: u16 = 256;
+
+impl<'a> Arbitrary<'a> for Square {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Square::from_index(u.int_in_range(0..=63)?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Piece {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Piece::from_index(u.int_in_range(0..=11)?).unwrap())
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+/// Every [`MoveType`]; the unassigned 4-bit codes must never be produced.
+const MOVE_TYPES: &[MoveType] = &[
+    MoveType::Quiet,
+    MoveType::DoublePawn,
+    MoveType::KingCastle,
+    MoveType::QueenCastle,
+    MoveType::Capture,
+    MoveType::EnPassant,
+    #[cfg(feature = "variants")]
+    MoveType::Drop,
+    MoveType::PromotionKnight,
+    MoveType::PromotionBishop,
+    MoveType::PromotionRook,
+    MoveType::PromotionQueen,
+    MoveType::CapPromoKnight,
+    MoveType::CapPromoBishop,
+    MoveType::CapPromoRook,
+    MoveType::CapPromoQueen,
+];
+
+impl<'a> Arbitrary<'a> for Move {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Move::new(
+            u.arbitrary()?,
+            u.arbitrary()?,
+            *u.choose(MOVE_TYPES)?,
+        ))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (3, Some(3))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CastleRights {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(CastleRights::from_index(u.int_in_range(0..=15)?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Board {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut board: Board = Board::default();
+        let plies: u16 = u.int_in_range(0..=MAX_PLIES)?;
+        for _ in 0..plies {
+            // Stop at the fifty-move limit, beyond which a FEN is rejected.
+            let moves: MoveList = gen_moves::<AllMoves>(&board);
+            if moves.is_empty() || board.fifty_move() >= 100 {
+                break;
+            }
+            board = board.make_move(*u.choose(&moves)?);
+        }
+        Ok(board)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (2, None)
+    }
+}
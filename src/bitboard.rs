@@ -24,7 +24,7 @@ use core::ops::Not;
 use crate::{
     BitBoardConsts,
     Color::{self, Black, White},
-    Square,
+    Direction, Square,
 };
 
 /// A `BitBoard` represents a 64-bit chessboard where each bit corresponds to a square.
@@ -47,6 +47,7 @@ use crate::{
 ///      A  B  C  D  E  F  G  H
 /// ```
 #[derive(PartialEq, Eq, PartialOrd, Clone, Copy, Debug, Default, Hash)]
+#[repr(transparent)]
 pub struct BitBoard(pub u64);
 
 /// Implements display formatting for the `BitBoard` struct.
@@ -101,6 +102,42 @@ impl Iterator for BitBoard {
     }
 }
 
+/// Implements `FromIterator<Square>` for `BitBoard`, allowing a set of squares to be collected
+/// into a `BitBoard` with every one of them set.
+///
+/// ```
+/// # use laura_core::*;
+/// let squares = [Square::A1, Square::D4, Square::H8];
+/// let bitboard: BitBoard = squares.iter().copied().collect();
+/// assert_eq!(bitboard, BitBoard(0x8000000008000001));
+/// ```
+impl FromIterator<Square> for BitBoard {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Square>>(iter: I) -> Self {
+        let mut bitboard: BitBoard = BitBoard::EMPTY;
+        bitboard.extend(iter);
+        bitboard
+    }
+}
+
+/// Implements `Extend<Square>` for `BitBoard`, setting every square yielded by the iterator.
+///
+/// ```
+/// # use laura_core::*;
+/// let mut bitboard = BitBoard::EMPTY;
+/// bitboard.extend([Square::E4, Square::E5]);
+/// assert!(bitboard.get_square(Square::E4) && bitboard.get_square(Square::E5));
+/// assert_eq!(bitboard.count_bits(), 2);
+/// ```
+impl Extend<Square> for BitBoard {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Square>>(&mut self, iter: I) {
+        for square in iter {
+            *self = self.set_square(square);
+        }
+    }
+}
+
 /// Methods for the `BitBoard` struct, including utilities for manipulating bits and interacting with squares.
 impl BitBoard {
     // Predefined `BitBoard` constants for sides, files, and ranks
@@ -125,6 +162,7 @@ impl BitBoard {
         RANK_8 = 0xFF00_0000_0000_0000,
         DARK_SQUARES = 0xAA55_AA55_AA55_AA55,
         LIGHT_SQUARES = 0x55AA_55AA_55AA_55AA,
+        CENTER = 0x0000_0018_1800_0000,
         EMPTY = 0,
         FULL = 0xFFFF_FFFF_FFFF_FFFF,
     }
@@ -200,6 +238,69 @@ impl BitBoard {
         }
     }
 
+    /// Returns the [`Square`] corresponding to the most significant set bit (MSB),
+    /// or `None` if the bitboard is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // BitBoard with multiple bits set; returns the most significant one (E5)
+    /// let bitboard = BitBoard((1 << Square::C3 as u64) | (1 << Square::E5 as u64));
+    /// assert_eq!(bitboard.msb(), Some(Square::E5));
+    ///
+    /// // Empty BitBoard returns None
+    /// assert_eq!(BitBoard::EMPTY.msb(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn msb(self) -> Option<Square> {
+        if self.0 != 0 {
+            // SAFETY: We just checked that self.0 != 0, so leading_zeros is in range [0, 63]
+            Some(unsafe { transmute::<u8, Square>((63 - self.0.leading_zeros() as u8) & 63) })
+        } else {
+            None
+        }
+    }
+
+    /// Removes the least significant set bit (LSB) from the `BitBoard` in place and returns
+    /// its [`Square`], or `None` if the bitboard is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mut bitboard = BitBoard((1 << Square::D1 as u64) | (1 << Square::E1 as u64));
+    /// assert_eq!(bitboard.pop_lsb(), Some(Square::D1));
+    /// assert_eq!(bitboard.pop_lsb(), Some(Square::E1));
+    /// assert_eq!(bitboard.pop_lsb(), None);
+    /// assert!(bitboard.is_empty());
+    /// ```
+    #[inline(always)]
+    pub const fn pop_lsb(&mut self) -> Option<Square> {
+        let square: Option<Square> = self.to_square();
+        self.0 &= self.0.wrapping_sub(1);
+        square
+    }
+
+    /// Returns a new `BitBoard` containing only the least significant set bit (LSB),
+    /// or an empty `BitBoard` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let bitboard = BitBoard((1 << Square::C3 as u64) | (1 << Square::E5 as u64));
+    /// assert_eq!(bitboard.isolate_lsb(), BitBoard(1 << Square::C3 as u64));
+    /// assert_eq!(BitBoard::EMPTY.isolate_lsb(), BitBoard::EMPTY);
+    /// ```
+    #[inline(always)]
+    pub const fn isolate_lsb(self) -> Self {
+        Self(self.0 & self.0.wrapping_neg())
+    }
+
     /// Returns a new `BitBoard` with the bit corresponding to the given [`Square`] set to `1`.
     ///
     /// This operation does not mutate the original `BitBoard`, but instead returns a new instance
@@ -341,6 +442,148 @@ impl BitBoard {
         Self(self.0.swap_bytes())
     }
 
+    /// Mirrors the `BitBoard` horizontally across the vertical axis between files D and E.
+    ///
+    /// File A becomes file H, file B becomes file G, and so on, while ranks are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on B2 is mirrored to G2
+    /// let bitboard = BitBoard(1 << Square::B2 as u64);
+    /// assert_eq!(bitboard.mirror_horizontal(), BitBoard(1 << Square::G2 as u64));
+    ///
+    /// // Mirroring twice returns the original position
+    /// assert_eq!(bitboard.mirror_horizontal().mirror_horizontal(), bitboard);
+    /// ```
+    #[inline(always)]
+    pub const fn mirror_horizontal(self) -> Self {
+        const K1: u64 = 0x5555_5555_5555_5555;
+        const K2: u64 = 0x3333_3333_3333_3333;
+        const K4: u64 = 0x0F0F_0F0F_0F0F_0F0F;
+        let mut x: u64 = self.0;
+        x = ((x >> 1) & K1) | ((x & K1) << 1);
+        x = ((x >> 2) & K2) | ((x & K2) << 2);
+        x = ((x >> 4) & K4) | ((x & K4) << 4);
+        Self(x)
+    }
+
+    /// Flips the `BitBoard` about the a1-h8 diagonal.
+    ///
+    /// Square A8 becomes H1 while squares on the diagonal itself are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on A8 is flipped to H1
+    /// let bitboard = BitBoard(1 << Square::A8 as u64);
+    /// assert_eq!(bitboard.flip_diag_a1h8(), BitBoard(1 << Square::H1 as u64));
+    ///
+    /// // The a1-h8 diagonal is unchanged
+    /// let diagonal = Diagonal::A1.to_bitboard();
+    /// assert_eq!(diagonal.flip_diag_a1h8(), diagonal);
+    /// ```
+    #[inline(always)]
+    pub const fn flip_diag_a1h8(self) -> Self {
+        const K1: u64 = 0x5500_5500_5500_5500;
+        const K2: u64 = 0x3333_0000_3333_0000;
+        const K4: u64 = 0x0F0F_0F0F_0000_0000;
+        let mut x: u64 = self.0;
+        let mut t: u64 = K4 & (x ^ (x << 28));
+        x ^= t ^ (t >> 28);
+        t = K2 & (x ^ (x << 14));
+        x ^= t ^ (t >> 14);
+        t = K1 & (x ^ (x << 7));
+        x ^= t ^ (t >> 7);
+        Self(x)
+    }
+
+    /// Flips the `BitBoard` about the a8-h1 anti-diagonal.
+    ///
+    /// Square A1 becomes H8 while squares on the anti-diagonal itself are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on A1 is flipped to H8
+    /// let bitboard = BitBoard(1 << Square::A1 as u64);
+    /// assert_eq!(bitboard.flip_diag_a8h1(), BitBoard(1 << Square::H8 as u64));
+    ///
+    /// // The a8-h1 anti-diagonal is unchanged
+    /// let anti_diagonal = AntiDiagonal::H1.to_bitboard();
+    /// assert_eq!(anti_diagonal.flip_diag_a8h1(), anti_diagonal);
+    /// ```
+    #[inline(always)]
+    pub const fn flip_diag_a8h1(self) -> Self {
+        const K1: u64 = 0xAA00_AA00_AA00_AA00;
+        const K2: u64 = 0xCCCC_0000_CCCC_0000;
+        const K4: u64 = 0xF0F0_F0F0_0F0F_0F0F;
+        let mut x: u64 = self.0;
+        let mut t: u64 = x ^ (x << 36);
+        x ^= K4 & (t ^ (x >> 36));
+        t = K2 & (x ^ (x << 18));
+        x ^= t ^ (t >> 18);
+        t = K1 & (x ^ (x << 9));
+        x ^= t ^ (t >> 9);
+        Self(x)
+    }
+
+    /// Rotates the `BitBoard` 90 degrees clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on A1 is rotated to A8, and file A becomes rank 8
+    /// let bitboard = BitBoard(1 << Square::A1 as u64);
+    /// assert_eq!(bitboard.rotate_90(), BitBoard(1 << Square::A8 as u64));
+    /// assert_eq!(BitBoard::FILE_A.rotate_90(), BitBoard::RANK_8);
+    /// ```
+    #[inline(always)]
+    pub const fn rotate_90(self) -> Self {
+        self.flip_diag_a1h8().flip()
+    }
+
+    /// Rotates the `BitBoard` 180 degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on B1 is rotated to G8
+    /// let bitboard = BitBoard(1 << Square::B1 as u64);
+    /// assert_eq!(bitboard.rotate_180(), BitBoard(1 << Square::G8 as u64));
+    /// ```
+    #[inline(always)]
+    pub const fn rotate_180(self) -> Self {
+        Self(self.0.reverse_bits())
+    }
+
+    /// Rotates the `BitBoard` 90 degrees counter-clockwise (270 degrees clockwise).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on A1 is rotated to H1, and file A becomes rank 1
+    /// let bitboard = BitBoard(1 << Square::A1 as u64);
+    /// assert_eq!(bitboard.rotate_270(), BitBoard(1 << Square::H1 as u64));
+    /// assert_eq!(BitBoard::FILE_A.rotate_270(), BitBoard::RANK_1);
+    /// ```
+    #[inline(always)]
+    pub const fn rotate_270(self) -> Self {
+        self.flip().flip_diag_a1h8()
+    }
+
     /// Shifts the `BitBoard` one rank forward relative to the side to move.
     ///
     /// For [`White`], this shifts all bits one rank up (towards rank 8).  
@@ -383,6 +626,33 @@ impl BitBoard {
         }
     }
 
+    /// Returns a new `BitBoard` with each square moved one rank forward,
+    /// from the perspective of a specific color known at compile time.
+    ///
+    /// For [`White`], forward is toward rank 8 and bits are shifted left.
+    /// For [`Black`], forward is toward rank 1 and bits are shifted right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let bitboard = BitBoard(1 << Square::D4 as u64);
+    /// let result = bitboard.forward_for::<{ Color::White as usize }>();
+    /// assert_eq!(result, BitBoard(1 << Square::D5 as u64));
+    ///
+    /// let result = bitboard.forward_for::<{ Color::Black as usize }>();
+    /// assert_eq!(result, BitBoard(1 << Square::D3 as u64));
+    /// ```
+    #[inline(always)]
+    pub const fn forward_for<const COLOR: usize>(self) -> Self {
+        if COLOR == White as usize {
+            Self(self.0 << 8)
+        } else {
+            Self(self.0 >> 8)
+        }
+    }
+
     /// Returns a new `BitBoard` representing the squares to the `"left"` of the current positions,
     /// from the perspective of the given [`Color`].
     ///
@@ -788,4 +1058,135 @@ impl BitBoard {
     pub const fn is_empty(self) -> bool {
         self.0 == 0
     }
+
+    /// Returns `true` if every square set in `other` is also set in this `BitBoard`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    /// assert!(BitBoard::RANK_1.contains(BitBoard(1 << Square::E1 as u64)));
+    /// assert!(!BitBoard::RANK_1.contains(BitBoard::FILE_E));
+    /// assert!(BitBoard::RANK_1.contains(BitBoard::EMPTY));
+    /// ```
+    #[inline(always)]
+    pub const fn contains(self, other: BitBoard) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if every square set in this `BitBoard` is also set in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    /// assert!(BitBoard(1 << Square::E1 as u64).is_subset_of(BitBoard::FILE_E));
+    /// assert!(!BitBoard::RANK_1.is_subset_of(BitBoard::FILE_E));
+    /// ```
+    #[inline(always)]
+    pub const fn is_subset_of(self, other: BitBoard) -> bool {
+        other.contains(self)
+    }
+
+    /// Returns `true` if this `BitBoard` and `other` have at least one square in common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    /// assert!(BitBoard::RANK_1.intersects(BitBoard::FILE_E));
+    /// assert!(!BitBoard::RANK_1.intersects(BitBoard::RANK_2));
+    /// ```
+    #[inline(always)]
+    pub const fn intersects(self, other: BitBoard) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns `true` if this `BitBoard` and `other` have no squares in common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    /// assert!(BitBoard::WHITE_SIDE.is_disjoint(BitBoard::BLACK_SIDE));
+    /// assert!(!BitBoard::DARK_SQUARES.is_disjoint(BitBoard::FILE_A));
+    /// ```
+    #[inline(always)]
+    pub const fn is_disjoint(self, other: BitBoard) -> bool {
+        !self.intersects(other)
+    }
+
+    /// Returns a new `BitBoard` with every square moved one step in the given [`Direction`].
+    ///
+    /// Directions are absolute (White's point of view), so `North` always points towards rank 8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on D4 moves north-east to E5
+    /// let bitboard = BitBoard(1 << Square::D4 as u64);
+    /// assert_eq!(bitboard.shift(Direction::NorthEast), BitBoard(1 << Square::E5 as u64));
+    ///
+    /// // A piece on D4 moves south to D3
+    /// assert_eq!(bitboard.shift(Direction::South), BitBoard(1 << Square::D3 as u64));
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This operation does **not** wrap around the board. Squares leaving the board are cleared.
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on H4 cannot move east
+    /// let bitboard = BitBoard(1 << Square::H4 as u64);
+    /// assert_eq!(bitboard.shift(Direction::East), BitBoard::EMPTY);
+    ///
+    /// // A piece on C8 cannot move north-west
+    /// let bitboard = BitBoard(1 << Square::C8 as u64);
+    /// assert_eq!(bitboard.shift(Direction::NorthWest), BitBoard::EMPTY);
+    /// ```
+    #[inline(always)]
+    pub const fn shift(self, direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self(self.0 << 8),
+            Direction::NorthEast => Self((self.0 & !BitBoard::FILE_H.0) << 9),
+            Direction::East => Self((self.0 & !BitBoard::FILE_H.0) << 1),
+            Direction::SouthEast => Self((self.0 & !BitBoard::FILE_H.0) >> 7),
+            Direction::South => Self(self.0 >> 8),
+            Direction::SouthWest => Self((self.0 & !BitBoard::FILE_A.0) >> 9),
+            Direction::West => Self((self.0 & !BitBoard::FILE_A.0) >> 1),
+            Direction::NorthWest => Self((self.0 & !BitBoard::FILE_A.0) << 7),
+        }
+    }
+
+    /// Returns a new `BitBoard` with every square moved `N` steps in the given [`Direction`].
+    ///
+    /// Edge masking is applied at every step, so squares never wrap around the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// // A piece on B2 moves two steps north-east to D4
+    /// let bitboard = BitBoard(1 << Square::B2 as u64);
+    /// assert_eq!(bitboard.shift_n::<2>(Direction::NorthEast), BitBoard(1 << Square::D4 as u64));
+    ///
+    /// // A piece on G4 moving three files east leaves the board
+    /// let bitboard = BitBoard(1 << Square::G4 as u64);
+    /// assert_eq!(bitboard.shift_n::<3>(Direction::East), BitBoard::EMPTY);
+    /// ```
+    #[inline(always)]
+    pub const fn shift_n<const N: usize>(self, direction: Direction) -> Self {
+        let mut bitboard: BitBoard = self;
+        let mut step: usize = 0;
+        while step < N {
+            bitboard = bitboard.shift(direction);
+            step += 1;
+        }
+        bitboard
+    }
 }
@@ -20,9 +20,12 @@
 use core::fmt::Write;
 use core::str::FromStr;
 
-use crate::{BitBoard, BoardParseError, CastleRights, Color, File, Piece, Rank, Square, Zobrist};
+use crate::{
+    BitBoard, BoardParseError, BoardValidationError, CastleRights, Color, FenError, File, MoveType,
+    Piece, PieceMap, PieceType, Rank, Square, TacticalMoves, Zobrist, gen_moves, get_pawn_attacks,
+};
 
-use super::FenBuffer;
+use crate::castle_rights::{KING_SIDE, QUEEN_SIDE, SOURCE};
 
 // This implementation is inspired by Carp, particularly its straightforward design for
 // managing the board and its data, which simplifies move generation and game logic.
@@ -31,6 +34,12 @@ use super::FenBuffer;
 /// Represents a chess board, with bitboards for tracking piece positions,
 /// castling rights, en passant squares, the fifty-move rule counter, and
 /// Zobrist hashing for fast state comparison.
+///
+/// Two boards are equal when all of their fields are, including the move counters, so the
+/// same position reached at different points of a game compares unequal; use
+/// [`Board::same_position`] or [`Board::repetition_key`] to compare positions only.
+/// [`Hash`](core::hash::Hash) feeds the [`Zobrist`] key to the hasher, which is consistent
+/// with `==` since equal boards share their key.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct Board {
     /// Array of bitboards, one for each type of piece. Each bitboard tracks
@@ -41,7 +50,7 @@ pub struct Board {
     pub sides_bitboard: [BitBoard; 2],
 
     /// Maps squares to the piece occupying them, if any.
-    pub piece_map: [Option<Piece>; Square::NUM_SQUARES],
+    pub piece_map: PieceMap,
 
     /// The square available for an en passant capture, if applicable.
     pub enpassant_square: Option<Square>,
@@ -58,63 +67,296 @@ pub struct Board {
     /// The Zobrist hash representing the current board state.
     pub zobrist: Zobrist,
 
+    /// The Zobrist hash of the pawns alone, for use in pawn hash tables.
+    pub pawn_key: Zobrist,
+
     /// The side to move (either White or Black).
     pub side: Color,
 
-    /// Bitboard representing all enemy pieces that are directly checking the allied king.
+    /// Bitboard representing all enemy pieces that are directly checking the allied king,
+    /// or [`Board::UNKNOWN_CHECKERS`] after [`Board::make_move_lazy`].
     pub checkers: BitBoard,
 }
 
 /// Displays the current state of the chess board in a readable format, including
 /// FEN notation, Zobrist hash, and a grid representation of the board.
+///
+/// This is [`Board::display_with`] with [`DisplayOptions::DEFAULT`].
 impl core::fmt::Display for Board {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "\n FEN: {}\n Zobrist: {}\n\n\t+---+---+---+---+---+---+---+---+",
-            self.to_fen(),
-            self.zobrist
-        )?;
+        self.display_with(DisplayOptions::DEFAULT).fmt(f)
+    }
+}
 
-        for rank in (0..Rank::NUM_RANKS).rev() {
-            write!(f, "\n     {}  | ", rank + 1)?;
+/// Options controlling how [`Board::display_with`] renders a board.
+///
+/// # Examples
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let options = DisplayOptions {
+///     perspective: Color::Black,
+///     unicode: true,
+///     ..DisplayOptions::COMPACT
+/// };
+/// let grid: String = Board::default().display_with(options).to_string();
+/// assert!(grid.contains("| ♖ | ♘ | ♗ | ♔ | ♕ | ♗ | ♘ | ♖ |"));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DisplayOptions {
+    /// The side shown at the bottom of the board.
+    pub perspective: Color,
 
-            for file in 0..File::NUM_FILES {
-                let square_index: usize = rank * 8 + file;
-                let piece = self.piece_map[square_index]
-                    .map(|p| p.to_char())
-                    .unwrap_or(' ');
-                write!(f, "{}", piece)?;
-                write!(f, " | ")?;
+    /// Draw pieces with Unicode chess symbols (e.g. `♘`) instead of FEN letters.
+    pub unicode: bool,
+
+    /// Label the ranks and files around the board.
+    pub coordinates: bool,
+
+    /// Print the FEN and the Zobrist hash above the board.
+    pub header: bool,
+
+    /// Print the side to move, castling rights, en passant square and fifty-move counter
+    /// below the board.
+    pub details: bool,
+}
+
+impl DisplayOptions {
+    /// The layout of the board's [`Display`](core::fmt::Display) implementation: White at the
+    /// bottom, FEN letters, coordinates, header and details.
+    pub const DEFAULT: Self = Self {
+        perspective: Color::White,
+        unicode: false,
+        coordinates: true,
+        header: true,
+        details: true,
+    };
+
+    /// Only the labelled grid, seen from White.
+    pub const COMPACT: Self = Self {
+        perspective: Color::White,
+        unicode: false,
+        coordinates: true,
+        header: false,
+        details: false,
+    };
+}
+
+impl Default for DisplayOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A board rendered with [`DisplayOptions`], created by [`Board::display_with`].
+#[derive(Copy, Clone, Debug)]
+pub struct BoardDisplay<'a> {
+    board: &'a Board,
+    options: DisplayOptions,
+}
+
+/// Unicode chess symbols indexed by [`Piece::to_index`].
+const PIECE_GLYPHS: [char; Piece::NUM_PIECES] =
+    ['♙', '♘', '♗', '♖', '♕', '♔', '♟', '♞', '♝', '♜', '♛', '♚'];
+
+impl core::fmt::Display for BoardDisplay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const SEPARATOR: &str = "\n\t+---+---+---+---+---+---+---+---+";
+        let (board, options): (&Board, DisplayOptions) = (self.board, self.options);
+        let flipped: bool = options.perspective == Color::Black;
+
+        if options.header {
+            write!(
+                f,
+                "\n FEN: {}\n Zobrist: {}\n",
+                board.to_fen(),
+                board.zobrist
+            )?;
+        }
+        f.write_str(SEPARATOR)?;
+
+        for row in 0..Rank::NUM_RANKS {
+            let rank: usize = if flipped {
+                row
+            } else {
+                Rank::NUM_RANKS - 1 - row
+            };
+            if options.coordinates {
+                write!(f, "\n     {}  | ", rank + 1)?;
+            } else {
+                f.write_str("\n\t| ")?;
             }
 
-            if rank != 0 {
-                write!(f, "\n\t+---+---+---+---+---+---+---+---+")?;
+            for column in 0..File::NUM_FILES {
+                let file: usize = if flipped {
+                    File::NUM_FILES - 1 - column
+                } else {
+                    column
+                };
+                let piece: char = board
+                    .piece_map
+                    .get(Square::from_index(rank * 8 + file))
+                    .map(|piece| match options.unicode {
+                        true => PIECE_GLYPHS[piece.to_index()],
+                        false => piece.to_char(),
+                    })
+                    .unwrap_or(' ');
+                write!(f, "{} | ", piece)?;
             }
-        }
 
-        write!(
-            f,
-            "\n\t+---+---+---+---+---+---+---+---+\n\t  A   B   C   D   E   F   G   H\n\n"
-        )?;
+            f.write_str(SEPARATOR)?;
+        }
 
-        write!(f, "\t    Side to move        : ")?;
-        match self.side {
-            Color::White => writeln!(f, "White")?,
-            Color::Black => writeln!(f, "Black")?,
+        if options.coordinates {
+            f.write_str("\n\t ")?;
+            for column in 0..File::NUM_FILES {
+                let file: usize = if flipped {
+                    File::NUM_FILES - 1 - column
+                } else {
+                    column
+                };
+                let gap: &str = if column == 0 { " " } else { "   " };
+                write!(f, "{}{}", gap, (b'A' + file as u8) as char)?;
+            }
+            f.write_str("\n")?;
         }
-        writeln!(f, "\t    Castling Rights     : {}", self.castling)?;
-        write!(f, "\t    En Passante square  : ")?;
-        if let Some(square) = self.enpassant_square {
-            writeln!(f, "{}", square)?;
-        } else {
-            writeln!(f, "-")?;
+        f.write_str("\n")?;
+
+        if options.details {
+            write!(f, "\t    Side to move        : ")?;
+            match board.side {
+                Color::White => writeln!(f, "White")?,
+                Color::Black => writeln!(f, "Black")?,
+            }
+            writeln!(f, "\t    Castling Rights     : {}", board.castling)?;
+            write!(f, "\t    En Passante square  : ")?;
+            if let Some(square) = board.enpassant_square {
+                writeln!(f, "{}", square)?;
+            } else {
+                writeln!(f, "-")?;
+            }
+            writeln!(f, "\t    Fifty Rule          : {}", board.fifty_move)?;
         }
-        writeln!(f, "\t    Fifty Rule          : {}", self.fifty_move)?;
         Ok(())
     }
 }
 
+/// The length in bytes of the longest FEN a [`Board`] can produce: a full board of 64 pieces,
+/// four castling rights, an en passant square and the largest move counters.
+pub const MAX_FEN_LENGTH: usize = 91;
+
+/// Writes into a byte slice, failing once it is full.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end: usize = self.len + s.len();
+        let target: &mut [u8] = self.buf.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Counts the bytes written to it.
+struct LengthCounter(usize);
+
+impl Write for LengthCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// A board formatted as a FEN string, created by [`Board::fen`] and [`Board::to_fen`].
+///
+/// The FEN is written straight into the destination each time the adapter is formatted. It
+/// compares equal to a `&str` holding the same FEN.
+#[derive(Copy, Clone)]
+pub struct Fen<'a> {
+    board: &'a Board,
+}
+
+impl core::fmt::Display for Fen<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.board.write_fen(f)
+    }
+}
+
+impl core::fmt::Debug for Fen<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_char('"')?;
+        self.board.write_fen(f)?;
+        f.write_char('"')
+    }
+}
+
+/// Consumes a string piece by piece, failing as soon as the written text diverges from it.
+struct FenMatcher<'a> {
+    rest: &'a str,
+}
+
+impl Write for FenMatcher<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        match self.rest.strip_prefix(s) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(core::fmt::Error),
+        }
+    }
+}
+
+impl PartialEq<str> for Fen<'_> {
+    fn eq(&self, other: &str) -> bool {
+        let mut matcher: FenMatcher<'_> = FenMatcher { rest: other };
+        self.board.write_fen(&mut matcher).is_ok() && matcher.rest.is_empty()
+    }
+}
+
+impl PartialEq<&str> for Fen<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+/// Options controlling how [`Board::from_fen_with`] parses a FEN string.
+///
+/// The default options are strict and match the behaviour of [`Board::from_str`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct FenOptions {
+    /// Accept FEN strings without the halfmove clock and fullmove number fields,
+    /// defaulting them to `0` and `1` respectively.
+    pub allow_missing_counters: bool,
+
+    /// Drop the en passant square unless a pawn can actually capture en passant
+    /// (X-FEN convention), see [`Board::normalize_ep`].
+    pub normalize_enpassant: bool,
+}
+
+impl FenOptions {
+    /// Strict parsing: all six FEN fields are required.
+    pub const STRICT: Self = Self {
+        allow_missing_counters: false,
+        normalize_enpassant: false,
+    };
+
+    /// Lenient parsing: 4-field FENs, as emitted by many tools and EPD files, are accepted.
+    pub const PERMISSIVE: Self = Self {
+        allow_missing_counters: true,
+        normalize_enpassant: false,
+    };
+}
+
 /// Parses a FEN string to create a new `Board` instance. The FEN string is split
 /// into 6 parts: piece placement, active color, castling rights, en passant target
 /// square, halfmove clock, and fullmove number.
@@ -122,20 +364,58 @@ impl FromStr for Board {
     type Err = BoardParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Board::from_fen_with(s, FenOptions::STRICT)
+    }
+}
+
+impl Board {
+    /// Parses a FEN string into a `Board` using the given [`FenOptions`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let fen: &str = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -";
+    /// assert_eq!(
+    ///     Board::from_fen_with(fen, FenOptions::STRICT),
+    ///     Err(BoardParseError::MissingHalfmoveClock)
+    /// );
+    ///
+    /// let board: Board = Board::from_fen_with(fen, FenOptions::PERMISSIVE).unwrap();
+    /// assert_eq!(board.fifty_move(), 0);
+    /// assert_eq!(board.full_move(), 1);
+    /// ```
+    pub fn from_fen_with(s: &str, options: FenOptions) -> Result<Board, BoardParseError> {
         let mut fen_iter: core::str::SplitWhitespace<'_> = s.split_whitespace();
 
         let board_str: &str = fen_iter.next().ok_or(BoardParseError::FenTooShort)?;
+        Self::from_fen_fields(board_str, fen_iter, options, None)
+    }
+
+    /// Parses the piece placement and the remaining FEN fields, which variants
+    /// use after stripping their own annotations from the placement or the fields.
+    ///
+    /// The `horde` side, if any, must have no king instead of exactly one.
+    pub(crate) fn from_fen_fields<'a>(
+        board_str: &str,
+        mut fen_iter: impl Iterator<Item = &'a str>,
+        options: FenOptions,
+        horde: Option<Color>,
+    ) -> Result<Board, BoardParseError> {
         let side_str: &str = fen_iter.next().ok_or(BoardParseError::MissingSideToMove)?;
         let castling_str: &str = fen_iter
             .next()
             .ok_or(BoardParseError::MissingCastlingRights)?;
         let enpassant_str: &str = fen_iter.next().ok_or(BoardParseError::MissingEnPassant)?;
-        let halfmove_str: &str = fen_iter
-            .next()
-            .ok_or(BoardParseError::MissingHalfmoveClock)?;
-        let fullmove_str: &str = fen_iter
-            .next()
-            .ok_or(BoardParseError::MissingFullmoveNumber)?;
+        let halfmove_str: &str = match fen_iter.next() {
+            Some(halfmove) => halfmove,
+            None if options.allow_missing_counters => "0",
+            None => return Err(BoardParseError::MissingHalfmoveClock),
+        };
+        let fullmove_str: &str = match fen_iter.next() {
+            Some(fullmove) => fullmove,
+            None if options.allow_missing_counters => "1",
+            None => return Err(BoardParseError::MissingFullmoveNumber),
+        };
 
         let mut board: Board = Self::empty();
         let mut count: i32 = 0;
@@ -147,6 +427,9 @@ impl FromStr for Board {
                     if count != 8 {
                         return Err(BoardParseError::InvalidRowLength);
                     };
+                    if rank == Rank::One {
+                        return Err(BoardParseError::InvalidBoardLayout);
+                    }
 
                     rank = rank.down();
                     count = 0;
@@ -168,10 +451,17 @@ impl FromStr for Board {
             }
         }
 
-        if count != 8 {
+        if count != 8 || rank != Rank::One {
             return Err(BoardParseError::InvalidBoardLayout);
         }
 
+        for color in [Color::White, Color::Black] {
+            let kings: BitBoard = board.kings() & board.sides_bitboard[color as usize];
+            if kings.count_bits() != u32::from(horde != Some(color)) {
+                return Err(BoardParseError::InvalidKingCount);
+            }
+        }
+
         board.side = match side_str {
             "w" => {
                 board.zobrist.hash_side();
@@ -215,7 +505,13 @@ impl FromStr for Board {
             return Err(BoardParseError::FullmoveMustBePositive);
         }
 
-        board.checkers = board.checkers();
+        if horde != Some(board.side) {
+            board.checkers = board.checkers();
+        }
+
+        if options.normalize_enpassant {
+            board.normalize_ep();
+        }
 
         Ok(board)
     }
@@ -234,6 +530,54 @@ impl Default for Board {
 }
 
 impl Board {
+    /// Returns a [`Display`](core::fmt::Display) adapter rendering the board as a grid with the
+    /// given [`DisplayOptions`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert_eq!(board.display_with(DisplayOptions::DEFAULT).to_string(), board.to_string());
+    ///
+    /// let black: String = board.display_with(DisplayOptions {
+    ///     perspective: Color::Black,
+    ///     ..DisplayOptions::COMPACT
+    /// }).to_string();
+    /// assert!(black.ends_with("  H   G   F   E   D   C   B   A\n\n"));
+    /// ```
+    #[inline]
+    pub fn display_with(&self, options: DisplayOptions) -> BoardDisplay<'_> {
+        BoardDisplay {
+            board: self,
+            options,
+        }
+    }
+}
+
+impl core::hash::Hash for Board {
+    /// Hashes the board through its [`Zobrist`] key.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut seen: HashSet<Board> = HashSet::new();
+    /// assert!(seen.insert(Board::default()));
+    /// assert!(!seen.insert(Board::default()));
+    /// assert!(seen.insert(Board::kiwipete()));
+    /// ```
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.zobrist.0);
+    }
+}
+
+impl Board {
+    /// The value of [`Board::checkers`] on a board whose checkers have not been computed yet,
+    /// see [`Board::make_move_lazy`]. No position has a checker on every square.
+    pub const UNKNOWN_CHECKERS: BitBoard = BitBoard::FULL;
+
     /// Creates a new empty board with no pieces. The bitboards are initialized as empty,
     /// and castling rights, en passant square, and other attributes are set to their
     /// default (empty or zero) values.
@@ -241,12 +585,13 @@ impl Board {
         Self {
             pieces_bitboard: [BitBoard::EMPTY; Piece::COUNT],
             sides_bitboard: [BitBoard::EMPTY; 2],
-            piece_map: [None; Square::NUM_SQUARES],
+            piece_map: PieceMap::EMPTY,
             enpassant_square: None,
             castling: CastleRights::null(),
             fifty_move: 0,
             full_move: 1,
             zobrist: Zobrist::null(),
+            pawn_key: Zobrist::null(),
             side: Color::White,
             checkers: BitBoard::EMPTY,
         }
@@ -257,51 +602,122 @@ impl Board {
     /// FEN is a standard notation for describing a particular board position of a chess game.
     /// It includes information about the placement of pieces, which side is to move, castling rights,
     /// en passant target squares, the half-move clock (for the fifty-move rule), and the full-move number.
-    pub fn to_fen(&self) -> FenBuffer {
-        let mut fen: FenBuffer = FenBuffer::new();
+    ///
+    /// The returned [`Fen`] formats the position on demand, so it never truncates. It is the same
+    /// adapter as [`Board::fen`].
+    #[inline]
+    pub fn to_fen(&self) -> Fen<'_> {
+        self.fen()
+    }
+
+    /// Returns a [`Display`](core::fmt::Display) adapter formatting the board as a FEN string.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert_eq!(
+    ///     format!("position fen {}", board.fen()),
+    ///     "position fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+    /// );
+    /// ```
+    #[inline]
+    pub fn fen(&self) -> Fen<'_> {
+        Fen { board: self }
+    }
+
+    /// Writes the board as a FEN string into any [`core::fmt::Write`] sink, such as a
+    /// `String` or a `Formatter`, without an intermediate buffer.
+    ///
+    /// # Errors
+    /// Returns an error only if the writer itself fails.
+    pub fn write_fen<W: Write + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
+        self.write_placement(writer)?;
+        writer.write_char(' ')?;
+        self.write_state(writer)?;
+        write!(writer, " {} {}", self.fifty_move, self.full_move)
+    }
+
+    /// Writes the board as a FEN string into `buf` and returns the written part as a `&str`.
+    ///
+    /// A buffer of [`MAX_FEN_LENGTH`] bytes holds the FEN of any board.
+    ///
+    /// # Errors
+    /// Returns [`FenError::BufferTooSmall`] with the length of the FEN if it does not fit in
+    /// `buf`; the buffer contents are then unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let mut buf: [u8; MAX_FEN_LENGTH] = [0; MAX_FEN_LENGTH];
+    /// assert_eq!(
+    ///     board.fen_into(&mut buf),
+    ///     Ok("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+    /// );
+    /// assert_eq!(board.fen_into(&mut [0; 16]), Err(FenError::BufferTooSmall(56)));
+    /// ```
+    pub fn fen_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, FenError> {
+        let mut writer: SliceWriter<'_> = SliceWriter { buf, len: 0 };
+        if self.write_fen(&mut writer).is_err() {
+            return Err(FenError::BufferTooSmall(self.fen_len()));
+        }
+
+        let len: usize = writer.len;
+        // The buffer only ever receives whole `&str` pieces, so it holds valid UTF-8.
+        Ok(unsafe { core::str::from_utf8_unchecked(&buf[..len]) })
+    }
+
+    /// Returns the length in bytes of the board's FEN string.
+    pub(crate) fn fen_len(&self) -> usize {
+        let mut counter: LengthCounter = LengthCounter(0);
+        let _ = self.write_fen(&mut counter);
+        counter.0
+    }
+
+    /// Writes the piece placement field of the FEN.
+    pub(crate) fn write_placement<W: Write + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
         for rank in (0..Rank::NUM_RANKS).rev() {
             let mut empty_squares: i32 = 0;
 
             for file in 0..File::NUM_FILES {
                 let square_index: usize = rank * 8 + file;
 
-                if let Some(piece) = self.piece_map[square_index] {
+                if let Some(piece) = self.piece_map.get(Square::from_index(square_index)) {
                     if empty_squares > 0 {
-                        let _ = write!(fen, "{}", empty_squares);
+                        write!(writer, "{}", empty_squares)?;
                         empty_squares = 0;
                     }
-                    let _ = write!(fen, "{}", piece.to_char());
+                    writer.write_char(piece.to_char())?;
                 } else {
                     empty_squares += 1;
                 }
             }
 
             if empty_squares > 0 {
-                let _ = write!(fen, "{}", empty_squares);
+                write!(writer, "{}", empty_squares)?;
             }
 
             if rank != Rank::One.to_index() {
-                let _ = write!(fen, "/");
+                writer.write_char('/')?;
             }
         }
+        Ok(())
+    }
 
-        let _ = write!(fen, " {} ", self.side);
-
-        let _ = write!(fen, "{} ", self.castling);
+    /// Writes the side to move, castling and en passant fields of the FEN.
+    pub(crate) fn write_state<W: Write + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
+        write!(writer, "{} {} ", self.side, self.castling)?;
 
         if let Some(enpassant_square) = self.enpassant_square {
-            let _ = write!(fen, "{}", enpassant_square);
+            write!(writer, "{}", enpassant_square)
         } else {
-            let _ = write!(fen, "-");
+            writer.write_char('-')
         }
-
-        let _ = write!(fen, " {} {}", self.fifty_move, self.full_move);
-
-        fen
     }
 
     /// Sets a piece on the board at a given square and updates the corresponding bitboards
-    /// and [`Zobrist`] hash. This method modifies both the specific piece bitboard and the
+    /// and [`Zobrist`] hashes. This method modifies both the specific piece bitboard and the
     /// side's [`BitBoard`] (either White or Black).
     #[inline(always)]
     pub fn set_piece(&mut self, piece: Piece, square: Square) {
@@ -310,12 +726,16 @@ impl Board {
 
         self.pieces_bitboard[index] = self.pieces_bitboard[index].set_square(square);
         self.sides_bitboard[color] = self.sides_bitboard[color].set_square(square);
-        self.piece_map[square.to_index()] = Some(piece);
+        self.piece_map.set(square, Some(piece));
         self.zobrist.hash_piece(piece, square);
+
+        if piece.piece_type() == PieceType::Pawn {
+            self.pawn_key.hash_piece(piece, square);
+        }
     }
 
     /// Removes a piece from a square and updates the corresponding bitboards and
-    /// [`Zobrist`] hash.
+    /// [`Zobrist`] hashes.
     ///
     /// # Panics
     /// This function will panic if no piece is present on the specified square,
@@ -328,14 +748,18 @@ impl Board {
 
         self.pieces_bitboard[index] = self.pieces_bitboard[index].pop_square(square);
         self.sides_bitboard[color] = self.sides_bitboard[color].pop_square(square);
-        self.piece_map[square.to_index()] = None;
+        self.piece_map.set(square, None);
         self.zobrist.hash_piece(piece, square);
+
+        if piece.piece_type() == PieceType::Pawn {
+            self.pawn_key.hash_piece(piece, square);
+        }
     }
 
     /// Returns the piece located on the specified square.
     #[inline(always)]
     pub const fn piece_on(&self, square: Square) -> Option<Piece> {
-        self.piece_map[square.to_index()]
+        self.piece_map.get(square)
     }
 
     /// Returns the side to move (white or black).
@@ -359,6 +783,71 @@ impl Board {
         self.zobrist
     }
 
+    /// Returns the Zobrist hash of the pawn structure of the current board position.
+    ///
+    /// Only the pawns of both sides contribute to this key, so positions with the same
+    /// pawn structure share it regardless of the other pieces, side to move, castling
+    /// rights or en passant square. It is maintained incrementally alongside [`Board::zobrist`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let moved: Board = board.make_uci_move("g1f3").unwrap();
+    /// assert_eq!(moved.pawn_key(), board.pawn_key());
+    /// assert_ne!(moved.make_uci_move("e7e5").unwrap().pawn_key(), board.pawn_key());
+    /// ```
+    #[inline(always)]
+    pub const fn pawn_key(&self) -> Zobrist {
+        self.pawn_key
+    }
+
+    /// Returns the key to compare positions for repetition detection.
+    ///
+    /// Like [`Board::zobrist`] it covers the piece placement, the side to move, the castling
+    /// rights and the en passant square, and not the move counters. Unlike it, the en passant
+    /// square only counts when a pawn of the side to move attacks it, so a double push that
+    /// allows no capture does not make the position differ from its repetitions.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default().make_uci_move("e2e4").unwrap();
+    /// let cycled: Board = ["g8f6", "g1f3", "f6g8", "f3g1"]
+    ///     .iter()
+    ///     .fold(board, |board, uci| board.make_uci_move(uci).unwrap());
+    /// assert_ne!(cycled.zobrist(), board.zobrist());
+    /// assert_eq!(cycled.repetition_key(), board.repetition_key());
+    /// assert!(cycled.same_position(&board));
+    /// ```
+    #[inline]
+    pub fn repetition_key(&self) -> Zobrist {
+        let mut key: Zobrist = self.zobrist;
+        if let (Some(square), None) = (self.enpassant_square, self.repetition_ep()) {
+            key.hash_enpassant(square);
+        }
+        key
+    }
+
+    /// Returns `true` if both boards are the same position for repetition purposes: the same
+    /// piece placement, side to move, castling rights and en passant square, the latter as
+    /// defined by [`Board::repetition_key`]. The move counters are ignored, unlike with `==`.
+    pub fn same_position(&self, other: &Board) -> bool {
+        self.pieces_bitboard == other.pieces_bitboard
+            && self.sides_bitboard == other.sides_bitboard
+            && self.side == other.side
+            && self.castling == other.castling
+            && self.repetition_ep() == other.repetition_ep()
+    }
+
+    /// Returns the en passant square if a pawn of the side to move attacks it.
+    #[inline(always)]
+    fn repetition_ep(&self) -> Option<Square> {
+        self.enpassant_square.filter(|&square| {
+            !(get_pawn_attacks(!self.side, square) & self.allied_pawns()).is_empty()
+        })
+    }
+
     /// Returns the current value of the fifty-move counter.
     ///
     /// The fifty-move rule in chess allows a draw to be claimed if no capture or pawn movement
@@ -374,6 +863,233 @@ impl Board {
         self.full_move
     }
 
+    /// Clears the en passant square, updating the [`Zobrist`] hash, unless a pawn of the side
+    /// to move can legally capture en passant.
+    ///
+    /// This implements the X-FEN convention, so positions that only differ by an unusable
+    /// en passant square share the same FEN and hash.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let mut board: Board = Board::default().make_uci_move("e2e4").unwrap();
+    /// assert_eq!(board.enpassant_square, Some(Square::E3));
+    ///
+    /// board.normalize_ep();
+    /// assert_eq!(board.enpassant_square, None);
+    /// ```
+    pub fn normalize_ep(&mut self) {
+        let Some(square) = self.enpassant_square else {
+            return;
+        };
+
+        let capturers: BitBoard = get_pawn_attacks(!self.side, square) & self.allied_pawns();
+        if !capturers.is_empty()
+            && gen_moves::<TacticalMoves>(self)
+                .iter()
+                .any(|mv| mv.get_type() == MoveType::EnPassant)
+        {
+            return;
+        }
+
+        self.enpassant_square = None;
+        self.zobrist.hash_enpassant(square);
+    }
+
+    /// Returns the color-mirrored position: the board is flipped vertically, every piece
+    /// changes color, and the castling rights and side to move are swapped.
+    ///
+    /// The resulting position is equivalent to the original from the other side's point of view,
+    /// with its [`Zobrist`] hash and checkers recomputed. Useful for NNUE data augmentation
+    /// and for testing that an evaluation is color-symmetric.
+    pub fn mirror(&self) -> Board {
+        let mut board: Board = self.remap(Square::flip, true);
+        if let Some(square) = self.enpassant_square {
+            board.enpassant_square = Some(square.flip());
+            board.zobrist.hash_enpassant(square.flip());
+        }
+        board.castling = self.castling.swap_colors();
+        board.zobrist.hash_castle(board.castling);
+        board.side = !self.side;
+        if board.side == Color::White {
+            board.zobrist.hash_side();
+        }
+        board.checkers = board.checkers();
+        board
+    }
+
+    /// Returns the position flipped vertically (rank 1 <-> rank 8) without changing piece colors
+    /// or the side to move.
+    ///
+    /// Castling rights are only kept while the king and rook still stand on their original squares,
+    /// and the en passant square is dropped when the flip makes it unreachable for the side to move.
+    pub fn flip_vertical(&self) -> Board {
+        self.transform(Square::flip)
+    }
+
+    /// Returns the position mirrored horizontally (file A <-> file H) without changing piece
+    /// colors or the side to move.
+    ///
+    /// Castling rights are only kept while the king and rook still stand on their original squares.
+    pub fn flip_horizontal(&self) -> Board {
+        self.transform(Square::mirror_horizontal)
+    }
+
+    /// Returns the position rotated 180 degrees without changing piece colors or the side to move.
+    ///
+    /// Castling rights are only kept while the king and rook still stand on their original squares,
+    /// and the en passant square is dropped when the rotation makes it unreachable for the side to move.
+    pub fn rotate_180(&self) -> Board {
+        self.transform(Square::rotate_180)
+    }
+
+    /// Applies a geometric transform to every square of the board, keeping the side to move
+    /// and only the castling rights and en passant square that remain coherent afterwards.
+    fn transform(&self, map: impl Fn(Square) -> Square) -> Board {
+        let mut board: Board = self.remap(&map, false);
+        board.side = self.side;
+        if board.side == Color::White {
+            board.zobrist.hash_side();
+        }
+
+        board.castling = board.castling_on_board(self.castling);
+        board.zobrist.hash_castle(board.castling);
+
+        if let Some(square) = self.enpassant_square.map(&map) {
+            if square.relative_rank(self.side) == Rank::Six {
+                board.enpassant_square = Some(square);
+                board.zobrist.hash_enpassant(square);
+            }
+        }
+
+        board.checkers = board.checkers();
+        board
+    }
+
+    /// Builds a new board placing every piece on the square given by `map`, optionally
+    /// swapping piece colors, and carrying over the move counters. Castling rights, the en
+    /// passant square and the side to move are left for the caller to set.
+    fn remap(&self, map: impl Fn(Square) -> Square, swap_colors: bool) -> Board {
+        let mut board: Board = Board::empty();
+        for square in self.combined_bitboard() {
+            let piece: Piece = unsafe { self.piece_on(square).unwrap_unchecked() };
+            let piece: Piece = if swap_colors {
+                Piece::new(piece.piece_type(), !piece.color())
+            } else {
+                piece
+            };
+            board.set_piece(piece, map(square));
+        }
+
+        board.fifty_move = self.fifty_move;
+        board.full_move = self.full_move;
+        board
+    }
+
+    /// Filters the given castling rights, keeping only those whose king and rook
+    /// still stand on their original squares on this board.
+    fn castling_on_board(&self, rights: CastleRights) -> CastleRights {
+        let mut castling: CastleRights = rights;
+        for color in [Color::White, Color::Black] {
+            let king: Piece = Piece::new(PieceType::King, color);
+            let rook: Piece = Piece::new(PieceType::Rook, color);
+            let king_home: bool = self.piece_on(SOURCE[color as usize]) == Some(king);
+            for side in [KING_SIDE, QUEEN_SIDE] {
+                if !king_home || self.piece_on(rights.rook_square(color, side)) != Some(rook) {
+                    castling = castling.without(color, side);
+                }
+            }
+        }
+        castling
+    }
+
+    /// Verifies the structural invariants of the position.
+    ///
+    /// The checks cover the consistency of the bitboards with the piece map, exactly one king
+    /// per side, no pawns on the back ranks, at most two checkers, the side not to move not being
+    /// in check, castling rights matching the king and rook placement, and the plausibility of the
+    /// en passant square.
+    ///
+    /// # Errors
+    /// Returns the first [`BoardValidationError`] found.
+    pub fn validate(&self) -> Result<(), BoardValidationError> {
+        self.validate_with(None)
+    }
+
+    /// Verifies the invariants like [`Board::validate`], allowing the `horde` side, if any,
+    /// to have no king and pawns on its first rank.
+    pub(crate) fn validate_with(&self, horde: Option<Color>) -> Result<(), BoardValidationError> {
+        let mut pieces: BitBoard = BitBoard::EMPTY;
+        for (index, bitboard) in self.pieces_bitboard.iter().enumerate() {
+            if pieces.intersects(*bitboard) {
+                return Err(BoardValidationError::InconsistentBitboards);
+            }
+            pieces |= *bitboard;
+            for square in *bitboard {
+                if self.piece_on(square).map(|piece| piece.piece_index()) != Some(index) {
+                    return Err(BoardValidationError::InconsistentBitboards);
+                }
+            }
+        }
+        let [white, black] = self.sides_bitboard;
+        if white.intersects(black) || white | black != pieces {
+            return Err(BoardValidationError::InconsistentBitboards);
+        }
+        for square in pieces {
+            let piece: Piece = unsafe { self.piece_on(square).unwrap_unchecked() };
+            if !self.sides_bitboard[piece.color() as usize].get_square(square) {
+                return Err(BoardValidationError::InconsistentBitboards);
+            }
+        }
+        if self.piece_map.iter().flatten().count() != pieces.count_bits() as usize {
+            return Err(BoardValidationError::InconsistentBitboards);
+        }
+
+        for color in [Color::White, Color::Black] {
+            let kings: u32 = (self.kings() & self.sides_bitboard[color as usize]).count_bits();
+            if kings != u32::from(horde != Some(color)) {
+                return Err(BoardValidationError::InvalidKingCount(color));
+            }
+        }
+
+        // The horde may keep pawns on its own first rank.
+        let horde_first_rank: BitBoard = match horde {
+            Some(Color::White) => self.white_bitboard() & BitBoard::RANK_1,
+            Some(Color::Black) => self.black_bitboard() & BitBoard::RANK_8,
+            None => BitBoard::EMPTY,
+        };
+        if (self.pawns() & !horde_first_rank).intersects(BitBoard::RANK_1 | BitBoard::RANK_8) {
+            return Err(BoardValidationError::PawnOnBackRank);
+        }
+
+        if let Some(enemy_king) = self.enemy_king().to_square() {
+            if self.is_attacked_by(self.side, enemy_king) {
+                return Err(BoardValidationError::OpponentInCheck);
+            }
+        }
+
+        if horde != Some(self.side) && self.checkers().count_bits() > 2 {
+            return Err(BoardValidationError::TooManyCheckers);
+        }
+
+        if self.castling_on_board(self.castling) != self.castling {
+            return Err(BoardValidationError::InconsistentCastlingRights);
+        }
+
+        if let Some(square) = self.enpassant_square {
+            let pawn: Piece = Piece::new(PieceType::Pawn, !self.side);
+            if square.relative_rank(self.side) != Rank::Six
+                || self.piece_on(square).is_some()
+                || self.piece_on(square.forward(self.side)).is_some()
+                || self.piece_on(square.forward(!self.side)) != Some(pawn)
+            {
+                return Err(BoardValidationError::InvalidEnPassant);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Constructs the classic "Kiwipete" position (Peter McKenzie),
     /// a well-known test position for perft.
     #[inline]
@@ -0,0 +1,185 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{BitBoard, Board, BoardParseError, CastleRights, Color, Piece, Rank, Square};
+
+/// A fluent builder for [`Board`] positions.
+///
+/// Pieces are placed square by square, and the side to move, castling rights, en passant square
+/// and move counters can be set before calling [`BoardBuilder::build`], which checks the result
+/// with the same rules applied when parsing a FEN string.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let board: Board = BoardBuilder::new()
+///     .piece(Square::E1, Piece::WK)
+///     .piece(Square::E8, Piece::BK)
+///     .piece(Square::D2, Piece::WP)
+///     .side(Color::Black)
+///     .build()
+///     .unwrap();
+/// assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/3P4/4K3 b - - 0 1");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BoardBuilder {
+    piece_map: [Option<Piece>; Square::NUM_SQUARES],
+    side: Color,
+    castling: CastleRights,
+    enpassant_square: Option<Square>,
+    fifty_move: u8,
+    full_move: u16,
+}
+
+impl Default for BoardBuilder {
+    /// Constructs an empty builder, equivalent to [`BoardBuilder::new`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Starts a builder from an existing [`Board`], keeping all of its state.
+impl From<Board> for BoardBuilder {
+    fn from(board: Board) -> Self {
+        Self {
+            piece_map: board.piece_map.to_array(),
+            side: board.side,
+            castling: board.castling,
+            enpassant_square: board.enpassant_square,
+            fifty_move: board.fifty_move,
+            full_move: board.full_move,
+        }
+    }
+}
+
+impl BoardBuilder {
+    /// Creates a builder for an empty board with White to move, no castling rights,
+    /// no en passant square and the move counters set to `0 1`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            piece_map: [None; Square::NUM_SQUARES],
+            side: Color::White,
+            castling: CastleRights::null(),
+            enpassant_square: None,
+            fifty_move: 0,
+            full_move: 1,
+        }
+    }
+
+    /// Places a piece on the given square, replacing any piece already there.
+    #[inline]
+    pub const fn piece(mut self, square: Square, piece: Piece) -> Self {
+        self.piece_map[square.to_index()] = Some(piece);
+        self
+    }
+
+    /// Removes any piece from the given square.
+    #[inline]
+    pub const fn clear(mut self, square: Square) -> Self {
+        self.piece_map[square.to_index()] = None;
+        self
+    }
+
+    /// Sets the side to move.
+    #[inline]
+    pub const fn side(mut self, side: Color) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the castling rights.
+    #[inline]
+    pub const fn castling(mut self, castling: CastleRights) -> Self {
+        self.castling = castling;
+        self
+    }
+
+    /// Sets the en passant target square, or clears it with `None`.
+    #[inline]
+    pub const fn enpassant(mut self, square: Option<Square>) -> Self {
+        self.enpassant_square = square;
+        self
+    }
+
+    /// Sets the fifty-move rule counter (halfmove clock).
+    #[inline]
+    pub const fn fifty_move(mut self, fifty_move: u8) -> Self {
+        self.fifty_move = fifty_move;
+        self
+    }
+
+    /// Sets the full move number.
+    #[inline]
+    pub const fn full_move(mut self, full_move: u16) -> Self {
+        self.full_move = full_move;
+        self
+    }
+
+    /// Builds the [`Board`], computing its [`Zobrist`](crate::Zobrist) hash and checkers.
+    ///
+    /// # Errors
+    /// Returns a [`BoardParseError`] if a side does not have exactly one king, the en passant
+    /// square is not on rank 3 or 6, the halfmove clock exceeds 100, or the full move number is zero.
+    pub fn build(&self) -> Result<Board, BoardParseError> {
+        let mut board: Board = Board::empty();
+        for (index, piece) in self.piece_map.iter().enumerate() {
+            if let Some(piece) = *piece {
+                board.set_piece(piece, Square::from_index(index));
+            }
+        }
+
+        for color in [Color::White, Color::Black] {
+            let kings: BitBoard = board.kings() & board.sides_bitboard[color as usize];
+            if kings.count_bits() != 1 {
+                return Err(BoardParseError::InvalidKingCount);
+            }
+        }
+
+        board.side = self.side;
+        if board.side == Color::White {
+            board.zobrist.hash_side();
+        }
+
+        board.castling = self.castling;
+        board.zobrist.hash_castle(self.castling);
+
+        if let Some(square) = self.enpassant_square {
+            if !matches!(square.rank(), Rank::Three | Rank::Six) {
+                return Err(BoardParseError::InvalidEnPassantRank);
+            }
+            board.enpassant_square = Some(square);
+            board.zobrist.hash_enpassant(square);
+        }
+
+        if self.fifty_move > 100 {
+            return Err(BoardParseError::HalfmoveClockOverflow);
+        }
+        board.fifty_move = self.fifty_move;
+
+        if self.full_move == 0 {
+            return Err(BoardParseError::FullmoveMustBePositive);
+        }
+        board.full_move = self.full_move;
+
+        board.checkers = board.checkers();
+        Ok(board)
+    }
+}
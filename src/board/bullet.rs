@@ -0,0 +1,100 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{BitBoard, Board, Color, GameResult, Piece, PieceType, Square};
+
+/// Size in bytes of a record encoded with [`Board::to_bullet`].
+pub const BULLET_BOARD_SIZE: usize = 32;
+
+impl Board {
+    /// Encodes the position as a training record of the [bullet](https://github.com/jw1912/bullet)
+    /// trainer, in the layout of its `ChessBoard` type.
+    ///
+    /// The position is stored from the side to move's point of view: for Black the board is
+    /// flipped vertically and the colors are swapped, and the score and result are negated.
+    /// `score` is in centipawns and, like `result`, given from White's point of view, as in
+    /// bullet's text format.
+    ///
+    /// The layout is:
+    ///
+    /// ```ignore
+    /// bytes  0..8    occupancy bitboard (little endian)
+    /// bytes  8..24   one 4-bit piece per occupied square, from A1 upwards, low nibble first:
+    ///                piece type (bits 0-2) and opponent flag (bit 3)
+    /// bytes 24..26   score (little endian)
+    /// byte   26      result: 0 for a loss, 1 for a draw and 2 for a win
+    /// byte   27      king square of the side to move
+    /// byte   28      king square of the opponent, flipped to its own point of view
+    /// bytes 29..32   unused
+    /// ```
+    ///
+    /// Returns `None` if `result` is [`GameResult::Unfinished`], or if a side does not have
+    /// exactly one king or the board holds more than 32 pieces, which can't happen in a legal
+    /// game of standard chess but can in variants such as Horde.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let record: [u8; BULLET_BOARD_SIZE] = board.to_bullet(35, GameResult::Draw).unwrap();
+    /// assert_eq!(record[0..8], board.combined_bitboard().0.to_le_bytes());
+    /// assert_eq!(record[26], 1);
+    /// ```
+    pub fn to_bullet(&self, score: i16, result: GameResult) -> Option<[u8; BULLET_BOARD_SIZE]> {
+        let orient = |square: Square| match self.side {
+            Color::White => square,
+            Color::Black => square.flip(),
+        };
+        let white_result: u8 = match result {
+            GameResult::WhiteWins => 2,
+            GameResult::Draw => 1,
+            GameResult::BlackWins => 0,
+            GameResult::Unfinished => return None,
+        };
+        let (score, result): (i16, u8) = match self.side {
+            Color::White => (score, white_result),
+            Color::Black => (score.saturating_neg(), 2 - white_result),
+        };
+
+        let occupancy: BitBoard = match self.side {
+            Color::White => self.combined_bitboard(),
+            Color::Black => self.combined_bitboard().flip(),
+        };
+        if occupancy.count_bits() > 32
+            || self.pieces_of(Color::White, PieceType::King).count_bits() != 1
+            || self.pieces_of(Color::Black, PieceType::King).count_bits() != 1
+        {
+            return None;
+        }
+
+        let mut record: [u8; BULLET_BOARD_SIZE] = [0u8; BULLET_BOARD_SIZE];
+        record[0..8].copy_from_slice(&occupancy.0.to_le_bytes());
+        for (index, square) in occupancy.enumerate() {
+            let piece: Piece = unsafe { self.piece_on(orient(square)).unwrap_unchecked() };
+            let nibble: u8 = piece.piece_index() as u8 | ((piece.color() != self.side) as u8) << 3;
+            record[8 + index / 2] |= nibble << (4 * (index % 2));
+        }
+
+        record[24..26].copy_from_slice(&score.to_le_bytes());
+        record[26] = result;
+        record[27] = orient(self.king_square(self.side)).to_index() as u8;
+        record[28] = orient(self.king_square(!self.side)).flip().to_index() as u8;
+        Some(record)
+    }
+}
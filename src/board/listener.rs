@@ -0,0 +1,127 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{Board, DeltaKind, Move, Piece, Square};
+
+/// Receives notifications about board updates performed through a [`ListenedBoard`].
+///
+/// All methods have empty default implementations, so listeners only implement the
+/// events they care about.
+pub trait BoardListener {
+    /// Called when a piece is placed on a square.
+    fn on_piece_added(&mut self, _piece: Piece, _square: Square) {}
+
+    /// Called when a piece is removed from a square.
+    fn on_piece_removed(&mut self, _piece: Piece, _square: Square) {}
+
+    /// Called once the move or null move is complete, with the resulting board.
+    fn on_state_changed(&mut self, _board: &Board) {}
+}
+
+/// A [`Board`] paired with a [`BoardListener`] that is notified of every change made by
+/// [`ListenedBoard::make_move`] and [`ListenedBoard::null_move`].
+///
+/// # Example
+///
+/// ```
+/// # use laura_core::*;
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     added: usize,
+///     removed: usize,
+/// }
+///
+/// impl BoardListener for Counter {
+///     fn on_piece_added(&mut self, _piece: Piece, _square: Square) {
+///         self.added += 1;
+///     }
+///
+///     fn on_piece_removed(&mut self, _piece: Piece, _square: Square) {
+///         self.removed += 1;
+///     }
+/// }
+///
+/// let mut board = ListenedBoard::new(Board::default(), Counter::default());
+/// board.make_move(Move::new(Square::E2, Square::E4, MoveType::DoublePawn));
+///
+/// assert_eq!((board.listener().added, board.listener().removed), (1, 1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListenedBoard<L: BoardListener> {
+    board: Board,
+    listener: L,
+}
+
+impl<L: BoardListener> ListenedBoard<L> {
+    /// Wraps a board with a listener.
+    #[inline]
+    pub const fn new(board: Board, listener: L) -> Self {
+        Self { board, listener }
+    }
+
+    /// Returns the current board.
+    #[inline]
+    pub const fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns the listener.
+    #[inline]
+    pub const fn listener(&self) -> &L {
+        &self.listener
+    }
+
+    /// Returns the listener mutably.
+    #[inline]
+    pub const fn listener_mut(&mut self) -> &mut L {
+        &mut self.listener
+    }
+
+    /// Splits the wrapper into the board and the listener.
+    #[inline]
+    pub fn into_parts(self) -> (Board, L) {
+        (self.board, self.listener)
+    }
+
+    /// Plays a move, notifying the listener of each piece change and then of the new state.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`Board::make_move`].
+    pub fn make_move(&mut self, mv: Move) {
+        let (board, deltas) = self.board.make_move_with_deltas(mv);
+        for delta in deltas.iter() {
+            match delta.kind {
+                DeltaKind::Added => self.listener.on_piece_added(delta.piece, delta.square),
+                DeltaKind::Removed => self.listener.on_piece_removed(delta.piece, delta.square),
+            }
+        }
+        self.board = board;
+        self.listener.on_state_changed(&self.board);
+    }
+
+    /// Plays a null move and notifies the listener of the new state.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`Board::null_move`].
+    pub fn null_move(&mut self) {
+        self.board = self.board.null_move();
+        self.listener.on_state_changed(&self.board);
+    }
+}
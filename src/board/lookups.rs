@@ -20,7 +20,8 @@
 use crate::get_king_attacks;
 use crate::get_knight_attacks;
 use crate::get_pawn_attacks;
-use crate::{BitBoard, Board, Color, Piece, Square};
+use crate::{BitBoard, Board, Color, Piece, PieceType, Square};
+use crate::{get_between, get_bishop_rays, get_rook_rays};
 use crate::{get_bishop_attacks, get_rook_attacks};
 
 impl Board {
@@ -55,6 +56,129 @@ impl Board {
         )
     }
 
+    /// Returns a [`BitBoard`] of the pieces of the given [`Color`] and [`PieceType`], for code
+    /// generic over the piece type or the color instead of the side to move.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert_eq!(board.pieces_of(Color::White, PieceType::Knight), board.allied_knights());
+    /// assert_eq!(board.pieces_of(Color::Black, PieceType::Pawn), BitBoard::RANK_7);
+    /// ```
+    #[inline(always)]
+    pub const fn pieces_of(&self, color: Color, piece_type: PieceType) -> BitBoard {
+        BitBoard(
+            self.pieces_bitboard[piece_type as usize].0 & self.sides_bitboard[color as usize].0,
+        )
+    }
+
+    /// Returns the [`Square`] of the king of the given [`Color`].
+    ///
+    /// A valid board always has one king per side; on a board without a king of that
+    /// color the result is meaningless, and debug builds panic.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert_eq!(board.king_square(Color::White), Square::E1);
+    /// assert_eq!(board.king_square(Color::Black), Square::E8);
+    /// ```
+    #[inline(always)]
+    pub const fn king_square(&self, color: Color) -> Square {
+        let king: BitBoard = self.pieces_of(color, PieceType::King);
+        debug_assert!(king.0 != 0, "no king of the given color on the board");
+        Square::from_index(king.0.trailing_zeros() as usize)
+    }
+
+    /// Returns the [`Square`] of the king of the side to move, see [`Board::king_square`].
+    #[inline(always)]
+    pub const fn allied_king_square(&self) -> Square {
+        self.king_square(self.side)
+    }
+
+    /// Returns a [`BitBoard`] of the knights, bishops, rooks and queens of the given [`Color`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = "4k3/4p3/8/8/8/8/3NP3/4K3 w - - 0 1".parse().unwrap();
+    /// assert_eq!(board.non_pawn_material(Color::White), Square::D2.to_bitboard());
+    /// assert!(!board.has_non_pawn_material(Color::Black));
+    /// ```
+    #[inline(always)]
+    pub const fn non_pawn_material(&self, color: Color) -> BitBoard {
+        BitBoard(
+            (self.pieces_bitboard[PieceType::KNIGHT].0
+                | self.pieces_bitboard[PieceType::BISHOP].0
+                | self.pieces_bitboard[PieceType::ROOK].0
+                | self.pieces_bitboard[PieceType::QUEEN].0)
+                & self.sides_bitboard[color as usize].0,
+        )
+    }
+
+    /// Returns `true` if the given [`Color`] has any knight, bishop, rook or queen, the usual
+    /// guard against zugzwang before a null move.
+    #[inline(always)]
+    pub const fn has_non_pawn_material(&self, color: Color) -> bool {
+        self.non_pawn_material(color).0 != 0
+    }
+
+    /// Returns `true` if the given [`Color`] has bishops on both light and dark squares.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert!(board.has_bishop_pair(Color::White));
+    ///
+    /// let board: Board = "4k3/8/8/8/8/8/8/2B1K1B1 w - - 0 1".parse().unwrap();
+    /// assert!(!board.has_bishop_pair(Color::White));
+    /// ```
+    #[inline(always)]
+    pub const fn has_bishop_pair(&self, color: Color) -> bool {
+        let bishops: BitBoard = self.pieces_of(color, PieceType::Bishop);
+        bishops.0 & BitBoard::LIGHT_SQUARES.0 != 0 && bishops.0 & BitBoard::DARK_SQUARES.0 != 0
+    }
+
+    /// Returns `true` if each side has exactly one bishop and the two bishops stand on
+    /// squares of different colors, a common sign of a drawish endgame.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = "2b1k3/8/8/8/8/8/8/2B1K3 w - - 0 1".parse().unwrap();
+    /// assert!(board.opposite_colored_bishops());
+    /// ```
+    #[inline(always)]
+    pub const fn opposite_colored_bishops(&self) -> bool {
+        let white: BitBoard = self.pieces_of(Color::White, PieceType::Bishop);
+        let black: BitBoard = self.pieces_of(Color::Black, PieceType::Bishop);
+        white.0.count_ones() == 1
+            && black.0.count_ones() == 1
+            && (white.0 & BitBoard::LIGHT_SQUARES.0 == 0)
+                != (black.0 & BitBoard::LIGHT_SQUARES.0 == 0)
+    }
+
+    /// Returns `true` if the king of the given color stands on one of the four
+    /// [`BitBoard::CENTER`] squares, the winning condition of King of the Hill.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = "8/8/8/4k3/8/8/8/4K3 w - - 0 1".parse().unwrap();
+    /// assert!(board.king_in_center(Color::Black));
+    /// assert!(!board.king_in_center(Color::White));
+    /// ```
+    #[inline(always)]
+    pub const fn king_in_center(&self, color: Color) -> bool {
+        self.pieces_bitboard[PieceType::KING].0
+            & self.sides_bitboard[color as usize].0
+            & BitBoard::CENTER.0
+            != 0
+    }
+
     /// Returns a [`BitBoard`] representing the presence of all allied pieces for the current side on the board.
     #[inline(always)]
     pub const fn allied_presence(&self) -> BitBoard {
@@ -86,12 +210,32 @@ impl Board {
     /// kings, pawns, queens, bishops, and rooks against the square.
     #[inline(always)]
     pub fn attackers(&self, square: Square, blockers: BitBoard) -> BitBoard {
-        self.enemy_presence()
+        self.attackers_of(!self.side, square, blockers)
+    }
+
+    /// Returns a [`BitBoard`] representing all pieces of both colors that are attacking a specified
+    /// square, based on the given occupancy of the board.
+    #[inline(always)]
+    pub fn attackers_to(&self, square: Square, occupancy: BitBoard) -> BitBoard {
+        self.knights() & get_knight_attacks(square)
+            | self.kings() & get_king_attacks(square)
+            | self.pawns() & self.white_bitboard() & get_pawn_attacks(Color::Black, square)
+            | self.pawns() & self.black_bitboard() & get_pawn_attacks(Color::White, square)
+            | (self.queens() | self.bishops()) & get_bishop_attacks(square, occupancy)
+            | (self.queens() | self.rooks()) & get_rook_attacks(square, occupancy)
+    }
+
+    /// Returns a [`BitBoard`] representing all pieces of the given [`Color`] that are attacking a
+    /// specified square, based on the given occupancy of the board. Unlike [`Board::attackers`],
+    /// this does not depend on the side to move.
+    #[inline(always)]
+    pub fn attackers_of(&self, color: Color, square: Square, occupancy: BitBoard) -> BitBoard {
+        self.sides_bitboard[color as usize]
             & (self.knights() & get_knight_attacks(square)
                 | self.kings() & get_king_attacks(square)
-                | self.pawns() & get_pawn_attacks(self.side, square)
-                | (self.queens() | self.bishops()) & get_bishop_attacks(square, blockers)
-                | (self.queens() | self.rooks()) & get_rook_attacks(square, blockers))
+                | self.pawns() & get_pawn_attacks(!color, square)
+                | (self.queens() | self.bishops()) & get_bishop_attacks(square, occupancy)
+                | (self.queens() | self.rooks()) & get_rook_attacks(square, occupancy))
     }
 
     /// Checks if a specified square is currently under attack by any enemy piece.
@@ -100,11 +244,150 @@ impl Board {
         self.attackers(square, blockers) != BitBoard::EMPTY
     }
 
+    /// Checks if a specified square is currently under attack by any piece of the given [`Color`],
+    /// using the current occupancy of the board. Unlike [`Board::attacked_square`], this does not
+    /// depend on the side to move.
+    #[inline(always)]
+    pub fn is_attacked_by(&self, color: Color, square: Square) -> bool {
+        !self
+            .attackers_of(color, square, self.combined_bitboard())
+            .is_empty()
+    }
+
+    /// Returns a [`BitBoard`] with every square attacked by the pieces of the given [`Color`].
+    ///
+    /// Combines the pawn attack fans, the knight and king attack tables and the slider attacks
+    /// computed over the current occupancy of the board.
+    #[inline]
+    pub fn attacked_squares(&self, color: Color) -> BitBoard {
+        self.attack_maps(color).0
+    }
+
+    /// Returns a [`BitBoard`] with every square attacked at least twice by the pieces of the
+    /// given [`Color`].
+    #[inline]
+    pub fn attacked_twice(&self, color: Color) -> BitBoard {
+        self.attack_maps(color).1
+    }
+
+    /// Computes, in a single pass, the squares attacked by the pieces of the given [`Color`]
+    /// and the squares attacked at least twice by them.
+    ///
+    /// Returns the tuple `(attacked, attacked_twice)`. Prefer this over calling
+    /// [`Board::attacked_squares`] and [`Board::attacked_twice`] separately when both maps are needed.
+    #[inline]
+    pub fn attack_maps(&self, color: Color) -> (BitBoard, BitBoard) {
+        let allied: BitBoard = self.sides_bitboard[color as usize];
+        let occupancy: BitBoard = self.combined_bitboard();
+        let pawns: BitBoard = self.pawns() & allied;
+
+        let left: BitBoard = pawns.up_left(color);
+        let right: BitBoard = pawns.up_right(color);
+        let mut attacked: BitBoard = left | right;
+        let mut twice: BitBoard = left & right;
+
+        let mut add = |attacks: BitBoard| {
+            twice |= attacked & attacks;
+            attacked |= attacks;
+        };
+
+        for square in self.knights() & allied {
+            add(get_knight_attacks(square));
+        }
+
+        for square in (self.bishops() | self.queens()) & allied {
+            add(get_bishop_attacks(square, occupancy));
+        }
+
+        for square in (self.rooks() | self.queens()) & allied {
+            add(get_rook_attacks(square, occupancy));
+        }
+
+        for square in self.kings() & allied {
+            add(get_king_attacks(square));
+        }
+
+        (attacked, twice)
+    }
+
+    /// Returns a [`BitBoard`] with every square attacked by the pawns of the given [`Color`].
+    #[inline]
+    pub fn pawn_attacks(&self, color: Color) -> BitBoard {
+        let pawns: BitBoard = self.pawns() & self.sides_bitboard[color as usize];
+        pawns.up_left(color) | pawns.up_right(color)
+    }
+
+    /// Returns a [`BitBoard`] with every square the pawns of the given [`Color`] can push to
+    /// against the current occupancy, including double pushes from their starting rank.
+    ///
+    /// Pins and checks are not taken into account, so these are pseudo-legal targets.
+    #[inline]
+    pub fn pawn_push_targets(&self, color: Color) -> BitBoard {
+        const RANK_3: [BitBoard; 2] = [BitBoard::RANK_3, BitBoard::RANK_6];
+        let empty: BitBoard = !self.combined_bitboard();
+        let pawns: BitBoard = self.pawns() & self.sides_bitboard[color as usize];
+        let single_push: BitBoard = pawns.forward(color) & empty;
+        let double_push: BitBoard = (single_push & RANK_3[color as usize]).forward(color) & empty;
+        single_push | double_push
+    }
+
+    /// Returns a [`BitBoard`] with the squares from which a piece of the given [`PieceType`] and
+    /// [`Color`] could give check to the opposing king without being capturable.
+    ///
+    /// A square qualifies when it is reachable in one move by a piece of that type, is not occupied
+    /// by an allied piece, checks the opposing king from there and is not attacked by the opponent.
+    /// Pawns only consider single pushes, and kings can never give check, so they yield an empty set.
+    pub fn safe_checks(&self, color: Color, piece_type: PieceType) -> BitBoard {
+        let king: Square = unsafe {
+            (self.kings() & self.sides_bitboard[!color as usize])
+                .to_square()
+                .unwrap_unchecked()
+        };
+        let allied: BitBoard = self.sides_bitboard[color as usize];
+        let occupancy: BitBoard = self.combined_bitboard();
+        let pieces: BitBoard = self.pieces_bitboard[piece_type as usize] & allied;
+
+        let (checks, reach): (BitBoard, BitBoard) = match piece_type {
+            PieceType::Pawn => (
+                get_pawn_attacks(!color, king),
+                pieces.forward(color) & !occupancy,
+            ),
+            PieceType::Knight => (
+                get_knight_attacks(king),
+                pieces.into_iter().fold(BitBoard::EMPTY, |acc, square| {
+                    acc | get_knight_attacks(square)
+                }),
+            ),
+            PieceType::Bishop => (
+                get_bishop_attacks(king, occupancy),
+                pieces.into_iter().fold(BitBoard::EMPTY, |acc, square| {
+                    acc | get_bishop_attacks(square, occupancy)
+                }),
+            ),
+            PieceType::Rook => (
+                get_rook_attacks(king, occupancy),
+                pieces.into_iter().fold(BitBoard::EMPTY, |acc, square| {
+                    acc | get_rook_attacks(square, occupancy)
+                }),
+            ),
+            PieceType::Queen => (
+                get_bishop_attacks(king, occupancy) | get_rook_attacks(king, occupancy),
+                pieces.into_iter().fold(BitBoard::EMPTY, |acc, square| {
+                    acc | get_bishop_attacks(square, occupancy)
+                        | get_rook_attacks(square, occupancy)
+                }),
+            ),
+            PieceType::King => return BitBoard::EMPTY,
+        };
+
+        checks & reach & !allied & !self.attacked_squares(!color)
+    }
+
     /// Returns a [`BitBoard`] representing all enemy pieces that are directly checking the allied king.
     /// Uses the current combined board state to evaluate potential checks.
     #[inline(always)]
     pub fn checkers(&self) -> BitBoard {
-        let king: Square = unsafe { self.allied_king().to_square().unwrap_unchecked() };
+        let king: Square = self.allied_king_square();
         let blockers: BitBoard = self.combined_bitboard();
         self.enemy_presence()
             & (self.knights() & get_knight_attacks(king)
@@ -112,4 +395,28 @@ impl Board {
                 | (self.queens() | self.bishops()) & get_bishop_attacks(king, blockers)
                 | (self.queens() | self.rooks()) & get_rook_attacks(king, blockers))
     }
+
+    /// Returns a [`BitBoard`] with the allied pieces that stand alone between an allied slider
+    /// and the enemy king.
+    ///
+    /// Moving one of these pieces off the line uncovers an attack on the enemy king, giving a
+    /// discovered check. Useful for check detection, quiet-check generation and tactical evaluation.
+    #[inline(always)]
+    pub fn discovered_check_candidates(&self) -> BitBoard {
+        let king: Square = self.king_square(!self.side);
+        let blockers: BitBoard = self.combined_bitboard();
+        let snipers: BitBoard = get_bishop_rays(king)
+            & (self.allied_queens() | self.allied_bishops())
+            | get_rook_rays(king) & (self.allied_queens() | self.allied_rooks());
+
+        let mut candidates: BitBoard = BitBoard::EMPTY;
+        for sniper in snipers {
+            let line: BitBoard = get_between(king, sniper).pop_square(sniper) & blockers;
+            if line.count_bits() == 1 {
+                candidates |= line & self.allied_presence();
+            }
+        }
+
+        candidates
+    }
 }
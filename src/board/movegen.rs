@@ -17,14 +17,20 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use crate::get_king_attacks;
 use crate::get_knight_attacks;
 use crate::get_pawn_attacks;
-use crate::{DESTINATION, KING_SIDE, MEDIUM, PRESENCE, QUEEN_SIDE, SOURCE};
+use crate::{DESTINATION, KING_SIDE, QUEEN_SIDE, ROOK_DESTINATION, SOURCE};
 use crate::{get_between, get_bishop_rays, get_rook_rays};
 use crate::{get_bishop_attacks, get_rook_attacks};
 
-use crate::{BitBoard, Board, Call_Handler, Enumerate_Moves, Move, MoveList, MoveType, Square};
+use crate::{
+    BitBoard, Board, Call_Handler, CastleSide, Color, Enumerate_Moves, Move, MoveList, MoveType,
+    PieceType, Square,
+};
 
 // This file is responsible for generating legal moves for pieces, which is a core
 // part of the chess engine's functionality. It works with bitboards and evaluates
@@ -37,6 +43,19 @@ use crate::{BitBoard, Board, Call_Handler, Enumerate_Moves, Move, MoveList, Move
 // - Belette (GPLv3): https://github.com/vincentbab/Belette/blob/main/src/movegen.h
 // - Cozy-Chess (MIT): https://github.com/analog-hors/cozy-chess/blob/master/cozy-chess/src/board/movegen/mod.rs
 
+const WHITE: usize = Color::White as usize;
+const BLACK: usize = Color::Black as usize;
+
+/// Returns the [`Color`] matching a `COLOR` const generic parameter.
+#[inline(always)]
+const fn side_of<const COLOR: usize>() -> Color {
+    if COLOR == WHITE {
+        Color::White
+    } else {
+        Color::Black
+    }
+}
+
 /// A move filter that includes only quiet moves (non-captures and non-promotions).
 ///
 /// This filter is typically used during positional search phases where
@@ -85,6 +104,51 @@ impl MoveFilter for AllMoves {
     const TACTICALS: bool = true;
 }
 
+/// The rule hooks consulted by the move generator and by [`Board::play_move_with`].
+///
+/// Every hook defaults to standard chess, implemented by [`StandardRules`], so the standard
+/// generator compiles to the same code as without the hooks. Variants plug their own rules in
+/// through the hooks of [`crate::Variant`].
+pub(crate) trait MoveRules {
+    /// Whether moves must not leave the own king attacked. Without king safety the generator
+    /// ignores pins and checks, and the king may step onto attacked squares.
+    #[inline(always)]
+    fn king_safety(&self) -> bool {
+        true
+    }
+
+    /// The squares, besides the second rank, from which pawns may double push. These
+    /// double pushes do not set an en passant square.
+    #[inline(always)]
+    fn double_push_squares(&self) -> BitBoard {
+        BitBoard::EMPTY
+    }
+
+    /// Whether pawns may promote to the given piece type.
+    #[inline(always)]
+    fn is_promotion_target(&self, _piece_type: PieceType) -> bool {
+        true
+    }
+
+    /// Whether castling on `side` is allowed for the side to move, once the castling right,
+    /// an empty path and a king not in check have been established.
+    #[inline(always)]
+    fn castling_allowed(&self, board: &Board, side: CastleSide) -> bool {
+        castling_path_safe(board, side)
+    }
+
+    /// An extra legality filter applied to every generated move.
+    #[inline(always)]
+    fn is_legal(&self, _mv: Move) -> bool {
+        true
+    }
+}
+
+/// The rules of standard chess.
+pub(crate) struct StandardRules;
+
+impl MoveRules for StandardRules {}
+
 /// Generates a list of legal moves for the given board based on the specified move filter.
 ///
 /// This function enumerates all legal moves for the provided [`Board`] according to the move
@@ -111,6 +175,27 @@ pub fn gen_moves<M: MoveFilter>(board: &Board) -> MoveList {
     move_list
 }
 
+/// Generates the legal moves for the given board like [`gen_moves`], collecting them into a `Vec`.
+///
+/// Available with the `alloc` feature, for consumers that prefer a growable container
+/// over the fixed-capacity [`MoveList`].
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let moves: Vec<Move> = gen_moves_vec::<AllMoves>(&Board::default());
+/// assert_eq!(moves.len(), 20);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn gen_moves_vec<M: MoveFilter>(board: &Board) -> Vec<Move> {
+    let mut moves: Vec<Move> = Vec::new();
+    enumerate_legal_moves::<M, _>(board, |mv| -> bool {
+        moves.push(mv);
+        true
+    });
+    moves
+}
+
 /// Enumerates all legal moves for the given board and passes them to a handler function.
 ///
 /// This function generates legal moves for the current board position based on the move
@@ -133,104 +218,306 @@ pub fn gen_moves<M: MoveFilter>(board: &Board) -> MoveList {
 /// assert!(!moves.is_empty());
 /// ```
 #[inline(always)]
-pub fn enumerate_legal_moves<M, F>(board: &Board, mut handler: F) -> bool
+pub fn enumerate_legal_moves<M, F>(board: &Board, handler: F) -> bool
+where
+    M: MoveFilter,
+    F: FnMut(Move) -> bool,
+{
+    enumerate_legal_moves_with::<M, F>(board, &MovegenContext::new(board), handler)
+}
+
+/// Generates a list of legal moves like [`gen_moves`], reusing a precomputed [`MovegenContext`].
+///
+/// The context must have been created from the same board.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let board = Board::kiwipete();
+/// let ctx = MovegenContext::new(&board);
+/// let tacticals: MoveList = gen_moves_with::<TacticalMoves>(&board, &ctx);
+/// let quiets: MoveList = gen_moves_with::<QuietMoves>(&board, &ctx);
+/// assert_eq!(tacticals.len() + quiets.len(), gen_moves::<AllMoves>(&board).len());
+/// ```
+#[inline(always)]
+pub fn gen_moves_with<M: MoveFilter>(board: &Board, ctx: &MovegenContext) -> MoveList {
+    let mut move_list: MoveList = MoveList::default();
+    enumerate_legal_moves_with::<M, _>(board, ctx, |mv| -> bool {
+        move_list.push(mv);
+        true
+    });
+    move_list
+}
+
+/// Enumerates all legal moves like [`enumerate_legal_moves`], reusing a precomputed
+/// [`MovegenContext`] instead of computing the pins and check mask again.
+///
+/// The context must have been created from the same board, otherwise the generated
+/// moves are not guaranteed to be legal.
+#[inline(always)]
+pub fn enumerate_legal_moves_with<M, F>(board: &Board, ctx: &MovegenContext, handler: F) -> bool
+where
+    M: MoveFilter,
+    F: FnMut(Move) -> bool,
+{
+    match board.side {
+        Color::White => {
+            enumerate_color_moves::<WHITE, _, M, F>(board, ctx, &StandardRules, handler)
+        }
+        Color::Black => {
+            enumerate_color_moves::<BLACK, _, M, F>(board, ctx, &StandardRules, handler)
+        }
+    }
+}
+
+/// Enumerates the moves of the board under the rules `R`, passing them to the handler.
+#[cfg(feature = "variants")]
+#[inline(always)]
+pub(crate) fn enumerate_moves_with_rules<R, M, F>(board: &Board, rules: &R, handler: F) -> bool
+where
+    R: MoveRules,
+    M: MoveFilter,
+    F: FnMut(Move) -> bool,
+{
+    let ctx: MovegenContext = if rules.king_safety() {
+        MovegenContext::new(board)
+    } else {
+        MovegenContext::PSEUDO_LEGAL
+    };
+    match board.side {
+        Color::White => enumerate_color_moves::<WHITE, R, M, F>(board, &ctx, rules, handler),
+        Color::Black => enumerate_color_moves::<BLACK, R, M, F>(board, &ctx, rules, handler),
+    }
+}
+
+/// Generates a list of legal moves like [`gen_moves`], with the side to move fixed at compile time.
+///
+/// `COLOR` is the side to move as a `usize` (`Color::White as usize` or `Color::Black as usize`),
+/// which lets the compiler specialize the pawn and castling code for that side. Useful when the
+/// caller already knows the side to move, for example in a color-specialized search.
+///
+/// # Panics
+/// Panics if `COLOR` is not the side to move of the board.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let board = Board::default();
+/// let moves: MoveList = gen_moves_for::<{ Color::White as usize }, AllMoves>(&board);
+/// assert_eq!(moves.len(), 20);
+/// ```
+#[inline(always)]
+pub fn gen_moves_for<const COLOR: usize, M: MoveFilter>(board: &Board) -> MoveList {
+    let mut move_list: MoveList = MoveList::default();
+    enumerate_legal_moves_for::<COLOR, M, _>(board, |mv| -> bool {
+        move_list.push(mv);
+        true
+    });
+    move_list
+}
+
+/// Enumerates all legal moves like [`enumerate_legal_moves`], with the side to move fixed
+/// at compile time. See [`gen_moves_for`].
+///
+/// # Panics
+/// Panics if `COLOR` is not the side to move of the board.
+#[inline(always)]
+pub fn enumerate_legal_moves_for<const COLOR: usize, M, F>(board: &Board, handler: F) -> bool
+where
+    M: MoveFilter,
+    F: FnMut(Move) -> bool,
+{
+    assert_eq!(board.side as usize, COLOR, "COLOR must be the side to move");
+    enumerate_color_moves::<COLOR, _, M, F>(
+        board,
+        &MovegenContext::new(board),
+        &StandardRules,
+        handler,
+    )
+}
+
+/// The color-specialized core of the move enumeration.
+#[inline(always)]
+fn enumerate_color_moves<const COLOR: usize, R, M, F>(
+    board: &Board,
+    ctx: &MovegenContext,
+    rules: &R,
+    mut handler: F,
+) -> bool
 where
+    R: MoveRules,
     M: MoveFilter,
     F: FnMut(Move) -> bool,
 {
-    let (diagonal_pins, linear_pins) = pinners(board);
-    match board.checkers.count_bits() {
+    let mut handler = |mv: Move| -> bool { !rules.is_legal(mv) || handler(mv) };
+    let checkers: BitBoard = board.current_checkers();
+
+    if !rules.king_safety() {
+        // Pseudo-legal moves: the context has no pins and an open check mask.
+        Enumerate_Moves!(false, board, ctx, rules, handler);
+        if M::QUIETS && checkers.is_empty() {
+            enumerate_castling_moves::<COLOR, R, _>(board, rules, &mut handler);
+        }
+        for king in board.allied_king() {
+            enumerate_king_moves::<M, R, _>(board, king, rules, &mut handler);
+        }
+        return true;
+    }
+
+    match checkers.count_bits() {
         0 => {
-            Enumerate_Moves!(false, board, diagonal_pins, linear_pins, handler);
+            Enumerate_Moves!(false, board, ctx, rules, handler);
             if M::QUIETS {
-                enumerate_castling_moves(board, &mut handler);
+                enumerate_castling_moves::<COLOR, R, _>(board, rules, &mut handler);
             }
         }
         1 => {
-            Enumerate_Moves!(true, board, diagonal_pins, linear_pins, handler);
+            Enumerate_Moves!(true, board, ctx, rules, handler);
         }
         _ => {}
     }
-    enumerate_king_moves::<M, F>(
-        board,
-        unsafe { board.allied_king().to_square().unwrap_unchecked() },
-        &mut handler,
-    );
+    enumerate_king_moves::<M, R, _>(board, ctx.king_square, rules, &mut handler);
     true
 }
 
+/// The move generation state derived from a board that does not depend on the move filter:
+/// the allied king square, the pin lines and the check mask.
+///
+/// Computing it once with [`MovegenContext::new`] and passing it to [`enumerate_legal_moves_with`]
+/// or [`gen_moves_with`] avoids paying the setup again when moves are generated in stages,
+/// for example captures first and quiet moves later.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MovegenContext {
+    /// The square of the king of the side to move.
+    pub king_square: Square,
+
+    /// Squares along diagonal pin lines, see [`pinners`].
+    pub diagonal_pins: BitBoard,
+
+    /// Squares along orthogonal pin lines, see [`pinners`].
+    pub linear_pins: BitBoard,
+
+    /// The squares non-king moves must land on: the checker and the squares between it and the king
+    /// when in single check, every square when not in check, and none in double check.
+    pub check_mask: BitBoard,
+}
+
+impl MovegenContext {
+    /// The context of a generator without king safety: no pins and every square open.
+    #[cfg(feature = "variants")]
+    const PSEUDO_LEGAL: MovegenContext = MovegenContext {
+        king_square: Square::A1,
+        diagonal_pins: BitBoard::EMPTY,
+        linear_pins: BitBoard::EMPTY,
+        check_mask: BitBoard::FULL,
+    };
+
+    /// Computes the move generation context for the given board.
+    #[inline(always)]
+    pub fn new(board: &Board) -> Self {
+        let (diagonal_pins, linear_pins) = pinners(board);
+        Self {
+            king_square: board.allied_king_square(),
+            diagonal_pins,
+            linear_pins,
+            check_mask: check_mask(board),
+        }
+    }
+}
+
 /// Enumerates the normal pawn moves for the given board, considering quiet moves and tactical moves.
 ///
 /// This function handles the generation of all possible pawn normal moves, including:
 /// - Single and double pushes, with special handling for pawns on the second or seventh ranks.
 /// - Normal captures, considering any pins and the presence of enemy pieces.
 #[inline(always)]
-fn enumerate_pawn_normal_moves<const IN_CHECK: bool, M, F>(
+fn enumerate_pawn_normal_moves<const IN_CHECK: bool, const COLOR: usize, R, M, F>(
     board: &Board,
     src: BitBoard,
-    diagonal_pins: BitBoard,
-    linear_pins: BitBoard,
+    ctx: &MovegenContext,
+    rules: &R,
     handler: &mut F,
 ) -> bool
 where
+    R: MoveRules,
     M: MoveFilter,
     F: FnMut(Move) -> bool,
 {
+    let side: Color = side_of::<COLOR>();
     const RANK_7: [BitBoard; 2] = [BitBoard::RANK_7, BitBoard::RANK_2];
     const RANK_3: [BitBoard; 2] = [BitBoard::RANK_3, BitBoard::RANK_6];
-    let check_mask = check_mask::<IN_CHECK>(board);
+    const RANK_2: [BitBoard; 2] = [BitBoard::RANK_2, BitBoard::RANK_7];
 
     //Single & Double Push
     if M::QUIETS {
-        let pawns: BitBoard = src & !RANK_7[board.side as usize] & !diagonal_pins;
+        let pawns: BitBoard = src & !RANK_7[COLOR] & !ctx.diagonal_pins;
 
         // Non-promotion single pawn pushes.
-        let mut single_push: BitBoard = ((pawns & !linear_pins).forward(board.side)
-            | ((pawns & linear_pins).forward(board.side) & linear_pins))
+        let mut single_push: BitBoard = ((pawns & !ctx.linear_pins).forward_for::<COLOR>()
+            | ((pawns & ctx.linear_pins).forward_for::<COLOR>() & ctx.linear_pins))
             & !board.combined_bitboard();
 
-        let mut double_push: BitBoard = (single_push & RANK_3[board.side as usize])
-            .forward(board.side)
-            & !board.combined_bitboard();
+        let mut double_push: BitBoard =
+            (single_push & RANK_3[COLOR]).forward_for::<COLOR>() & !board.combined_bitboard();
 
         if IN_CHECK {
-            single_push &= check_mask;
-            double_push &= check_mask;
+            single_push &= ctx.check_mask;
+            double_push &= ctx.check_mask;
         }
 
         for dest in single_push {
-            let src: Square = dest.backward(board.side);
+            let src: Square = dest.backward(side);
             Call_Handler!(handler, src, dest, Quiet);
         }
 
         for dest in double_push {
-            let src: Square = (dest.backward(board.side)).backward(board.side);
+            let src: Square = dest.backward(side).backward(side);
             Call_Handler!(handler, src, dest, DoublePawn);
         }
+
+        // Double pushes the rules allow from other squares.
+        let pawns: BitBoard = pawns & rules.double_push_squares() & !RANK_2[COLOR];
+        if !pawns.is_empty() {
+            let single_push: BitBoard = ((pawns & !ctx.linear_pins).forward_for::<COLOR>()
+                | ((pawns & ctx.linear_pins).forward_for::<COLOR>() & ctx.linear_pins))
+                & !board.combined_bitboard();
+
+            let mut double_push: BitBoard =
+                single_push.forward_for::<COLOR>() & !board.combined_bitboard();
+
+            if IN_CHECK {
+                double_push &= ctx.check_mask;
+            }
+
+            for dest in double_push {
+                let src: Square = dest.backward(side).backward(side);
+                Call_Handler!(handler, src, dest, DoublePawn);
+            }
+        }
     }
 
     // Normal Captures (Non promotions)
     if M::TACTICALS {
-        let pawns: BitBoard = src & !RANK_7[board.side as usize] & !linear_pins;
-        let mut capture_left: BitBoard = ((pawns & !diagonal_pins).up_left(board.side)
-            | ((pawns & diagonal_pins).up_left(board.side) & diagonal_pins))
+        let pawns: BitBoard = src & !RANK_7[COLOR] & !ctx.linear_pins;
+        let mut capture_left: BitBoard = ((pawns & !ctx.diagonal_pins).up_left_for::<COLOR>()
+            | ((pawns & ctx.diagonal_pins).up_left_for::<COLOR>() & ctx.diagonal_pins))
             & board.enemy_presence();
-        let mut capture_right: BitBoard = ((pawns & !diagonal_pins).up_right(board.side)
-            | ((pawns & diagonal_pins).up_right(board.side) & diagonal_pins))
+        let mut capture_right: BitBoard = ((pawns & !ctx.diagonal_pins).up_right_for::<COLOR>()
+            | ((pawns & ctx.diagonal_pins).up_right_for::<COLOR>() & ctx.diagonal_pins))
             & board.enemy_presence();
 
         if IN_CHECK {
-            capture_left &= check_mask;
-            capture_right &= check_mask;
+            capture_left &= ctx.check_mask;
+            capture_right &= ctx.check_mask;
         }
 
         for dest in capture_left {
-            let src: Square = dest.backward(board.side).right_color(board.side);
+            let src: Square = dest.backward(side).right_color(side);
             Call_Handler!(handler, src, dest, Capture);
         }
 
         for dest in capture_right {
-            let src: Square = dest.backward(board.side).left_color(board.side);
+            let src: Square = dest.backward(side).left_color(side);
             Call_Handler!(handler, src, dest, Capture);
         }
     }
@@ -245,62 +532,67 @@ where
 /// - Capture promotions, where pawns capture an enemy piece diagonally and promote.
 /// - Quiet promotions, where pawns advance forward and promote without capturing.
 #[inline(always)]
-fn enumerate_pawn_promotion_moves<const IN_CHECK: bool, M, F>(
+fn enumerate_pawn_promotion_moves<const IN_CHECK: bool, const COLOR: usize, R, M, F>(
     board: &Board,
     src: BitBoard,
-    diagonal_pins: BitBoard,
-    linear_pins: BitBoard,
+    ctx: &MovegenContext,
+    rules: &R,
     handler: &mut F,
 ) -> bool
 where
+    R: MoveRules,
     M: MoveFilter,
     F: FnMut(Move) -> bool,
 {
+    let side: Color = side_of::<COLOR>();
     const RANK_7: [BitBoard; 2] = [BitBoard::RANK_7, BitBoard::RANK_2];
 
-    let pawns_to_promote: BitBoard = src & RANK_7[board.side as usize];
+    let pawns_to_promote: BitBoard = src & RANK_7[COLOR];
 
     if pawns_to_promote.0 != 0 {
         // Capture Promotions
         {
-            let pawns: BitBoard = pawns_to_promote & !linear_pins;
-            let mut capture_left_prom: BitBoard = ((pawns & !diagonal_pins).up_left(board.side)
-                | ((pawns & diagonal_pins).up_left(board.side) & diagonal_pins))
+            let pawns: BitBoard = pawns_to_promote & !ctx.linear_pins;
+            let mut capture_left_prom: BitBoard = ((pawns & !ctx.diagonal_pins)
+                .up_left_for::<COLOR>()
+                | ((pawns & ctx.diagonal_pins).up_left_for::<COLOR>() & ctx.diagonal_pins))
                 & board.enemy_presence();
-            let mut capture_right_prom: BitBoard = ((pawns & !diagonal_pins).up_right(board.side)
-                | ((pawns & diagonal_pins).up_right(board.side) & diagonal_pins))
+            let mut capture_right_prom: BitBoard = ((pawns & !ctx.diagonal_pins)
+                .up_right_for::<COLOR>()
+                | ((pawns & ctx.diagonal_pins).up_right_for::<COLOR>() & ctx.diagonal_pins))
                 & board.enemy_presence();
 
             if IN_CHECK {
-                capture_left_prom &= check_mask::<IN_CHECK>(board);
-                capture_right_prom &= check_mask::<IN_CHECK>(board);
+                capture_left_prom &= ctx.check_mask;
+                capture_right_prom &= ctx.check_mask;
             }
 
             for dest in capture_left_prom {
-                let src: Square = dest.backward(board.side).right_color(board.side);
-                enumerate_promotions::<M, F>(src, dest, handler, true);
+                let src: Square = dest.backward(side).right_color(side);
+                enumerate_promotions::<R, M, F>(src, dest, rules, handler, true);
             }
 
             for dest in capture_right_prom {
-                let src: Square = dest.backward(board.side).left_color(board.side);
-                enumerate_promotions::<M, F>(src, dest, handler, true);
+                let src: Square = dest.backward(side).left_color(side);
+                enumerate_promotions::<R, M, F>(src, dest, rules, handler, true);
             }
         }
 
         // Quiet Promotions
         {
-            let pawns: BitBoard = pawns_to_promote & !diagonal_pins;
-            let mut quiet_promotions: BitBoard = ((pawns & !linear_pins).forward(board.side)
-                | ((pawns & linear_pins).forward(board.side) & linear_pins))
+            let pawns: BitBoard = pawns_to_promote & !ctx.diagonal_pins;
+            let mut quiet_promotions: BitBoard = ((pawns & !ctx.linear_pins)
+                .forward_for::<COLOR>()
+                | ((pawns & ctx.linear_pins).forward_for::<COLOR>() & ctx.linear_pins))
                 & !board.combined_bitboard();
 
             if IN_CHECK {
-                quiet_promotions &= check_mask::<IN_CHECK>(board);
+                quiet_promotions &= ctx.check_mask;
             }
 
             for dest in quiet_promotions {
-                let src: Square = dest.backward(board.side);
-                enumerate_promotions::<M, F>(src, dest, handler, false);
+                let src: Square = dest.backward(side);
+                enumerate_promotions::<R, M, F>(src, dest, rules, handler, false);
             }
         }
     }
@@ -314,30 +606,41 @@ where
 /// It handles:
 /// - Tactical moves (capture and quiet promotions) to Queen.
 /// - Quiet moves to Rook, Bishop, or Knight.
+///
+/// Only the piece types the rules accept as promotion targets are generated.
 #[inline(always)]
-fn enumerate_promotions<M, F>(src: Square, dest: Square, handler: &mut F, capture: bool) -> bool
+fn enumerate_promotions<R, M, F>(
+    src: Square,
+    dest: Square,
+    rules: &R,
+    handler: &mut F,
+    capture: bool,
+) -> bool
 where
+    R: MoveRules,
     M: MoveFilter,
     F: FnMut(Move) -> bool,
 {
     macro_rules! Call_Promotion {
-        ($promo_type:ident, $cap_type:ident) => {
-            if capture {
-                Call_Handler!(handler, src, dest, $cap_type);
-            } else {
-                Call_Handler!(handler, src, dest, $promo_type);
+        ($piece_type:ident, $promo_type:ident, $cap_type:ident) => {
+            if rules.is_promotion_target(PieceType::$piece_type) {
+                if capture {
+                    Call_Handler!(handler, src, dest, $cap_type);
+                } else {
+                    Call_Handler!(handler, src, dest, $promo_type);
+                }
             }
         };
     }
 
     if M::TACTICALS {
-        Call_Promotion!(PromotionQueen, CapPromoQueen);
+        Call_Promotion!(Queen, PromotionQueen, CapPromoQueen);
     }
 
     if M::QUIETS {
-        Call_Promotion!(PromotionRook, CapPromoRook);
-        Call_Promotion!(PromotionBishop, CapPromoBishop);
-        Call_Promotion!(PromotionKnight, CapPromoKnight);
+        Call_Promotion!(Rook, PromotionRook, CapPromoRook);
+        Call_Promotion!(Bishop, PromotionBishop, CapPromoBishop);
+        Call_Promotion!(Knight, PromotionKnight, CapPromoKnight);
     }
 
     true
@@ -349,27 +652,35 @@ where
 ///
 /// The function works by first identifying the en passant square, then checking which pawns can attack
 /// that square. It ensures that performing an en passant capture does not leave the king vulnerable to
-/// attacks by rooks, queens, or bishops.
+/// attacks by rooks, queens, or bishops, unless the rules do not require king safety.
 #[inline(always)]
-fn enumerate_pawn_en_passant_moves<F>(
+fn enumerate_pawn_en_passant_moves<const COLOR: usize, R, F>(
     board: &Board,
     src: BitBoard,
-    linear_pins: BitBoard,
+    ctx: &MovegenContext,
+    rules: &R,
     handler: &mut F,
 ) -> bool
 where
+    R: MoveRules,
     F: FnMut(Move) -> bool,
 {
-    let pawns: BitBoard = src & !linear_pins;
-    let king_square: Square = unsafe { board.allied_king().to_square().unwrap_unchecked() };
+    let side: Color = side_of::<COLOR>();
+    let pawns: BitBoard = src & !ctx.linear_pins;
+    let king_square: Square = ctx.king_square;
 
     // En Passant captures
     if let Some(en_passant) = board.enpassant_square {
         let dest: Square = en_passant;
-        let victim: Square = en_passant.forward(!board.side);
+        let victim: Square = en_passant.forward(!side);
 
         // Check which pawns can capture en passant.
-        for src in pawns & get_pawn_attacks(!board.side, dest) {
+        for src in pawns & get_pawn_attacks(!side, dest) {
+            if !rules.king_safety() {
+                Call_Handler!(handler, src, dest, EnPassant);
+                continue;
+            }
+
             // Simulate the board after en passant capture.
             let blockers: BitBoard =
                 board.combined_bitboard() ^ victim.to_bitboard() ^ src.to_bitboard()
@@ -404,74 +715,113 @@ where
 /// and en passant captures.
 /// The function handles different types of pawn moves based on the game state and the `MoveFilter` trait.
 #[inline(always)]
-fn enumerate_pawn_moves<const IN_CHECK: bool, M, F>(
+fn enumerate_pawn_moves<const IN_CHECK: bool, const COLOR: usize, R, M, F>(
     board: &Board,
     src: BitBoard,
-    diagonal_pins: BitBoard,
-    linear_pins: BitBoard,
+    ctx: &MovegenContext,
+    rules: &R,
     handler: &mut F,
 ) -> bool
 where
+    R: MoveRules,
     M: MoveFilter,
     F: FnMut(Move) -> bool,
 {
-    enumerate_pawn_normal_moves::<IN_CHECK, M, F>(board, src, diagonal_pins, linear_pins, handler);
-    enumerate_pawn_promotion_moves::<IN_CHECK, M, F>(
-        board,
-        src,
-        diagonal_pins,
-        linear_pins,
-        handler,
-    );
+    enumerate_pawn_normal_moves::<IN_CHECK, COLOR, R, M, F>(board, src, ctx, rules, handler);
+    enumerate_pawn_promotion_moves::<IN_CHECK, COLOR, R, M, F>(board, src, ctx, rules, handler);
     if M::TACTICALS {
-        enumerate_pawn_en_passant_moves::<F>(board, src, linear_pins, handler);
+        enumerate_pawn_en_passant_moves::<COLOR, R, F>(board, src, ctx, rules, handler);
     }
     true
 }
 
 /// Enumerates all possible castling moves for the current side, both kingside and queenside castling.
-/// The function checks if castling is available and whether the king and relevant squares are not under attack,
-/// and if there are no obstructions between the king and the rook.
+/// The function checks if castling is available, if there are no obstructions between the king and the rook,
+/// and whether the rules allow it, which by default means the squares the king crosses are not under attack.
 #[inline(always)]
-fn enumerate_castling_moves<F>(board: &Board, handler: &mut F) -> bool
+fn enumerate_castling_moves<const COLOR: usize, R, F>(
+    board: &Board,
+    rules: &R,
+    handler: &mut F,
+) -> bool
 where
+    R: MoveRules,
     F: FnMut(Move) -> bool,
 {
+    let side: Color = side_of::<COLOR>();
     // King Side Castling
-    if board.castling.has_kingside(board.side) {
-        let side: usize = board.side as usize;
-        let src: Square = SOURCE[side];
-        let dest: Square = DESTINATION[KING_SIDE][side];
-
-        if (board.combined_bitboard() & PRESENCE[KING_SIDE][side]).is_empty()
-            && !board.attacked_square(MEDIUM[KING_SIDE][side], board.combined_bitboard())
-            && !board.attacked_square(dest, board.combined_bitboard())
-        {
-            Call_Handler!(handler, src, dest, KingCastle);
-        }
+    if board.castling.has_kingside(side)
+        && castling_path_empty::<COLOR>(board, KING_SIDE)
+        && rules.castling_allowed(board, CastleSide::King)
+    {
+        Call_Handler!(
+            handler,
+            SOURCE[COLOR],
+            DESTINATION[KING_SIDE][COLOR],
+            KingCastle
+        );
     }
     // Queen Side Castling
-    if board.castling.has_queenside(board.side) {
-        let side: usize = board.side as usize;
-        let src: Square = SOURCE[side];
-        let dest: Square = DESTINATION[QUEEN_SIDE][side];
-
-        if (board.combined_bitboard() & PRESENCE[QUEEN_SIDE][side]).is_empty()
-            && !board.attacked_square(MEDIUM[QUEEN_SIDE][side], board.combined_bitboard())
-            && !board.attacked_square(dest, board.combined_bitboard())
-        {
-            Call_Handler!(handler, src, dest, QueenCastle);
-        }
+    if board.castling.has_queenside(side)
+        && castling_path_empty::<COLOR>(board, QUEEN_SIDE)
+        && rules.castling_allowed(board, CastleSide::Queen)
+    {
+        Call_Handler!(
+            handler,
+            SOURCE[COLOR],
+            DESTINATION[QUEEN_SIDE][COLOR],
+            QueenCastle
+        );
     }
 
     true
 }
 
-/// Enumerates all legal king moves, ensuring the king does not move into an attacked square.
+/// Checks that every square the king and rook travel through when castling on `side`
+/// is empty, apart from the king and rook themselves.
+#[inline(always)]
+fn castling_path_empty<const COLOR: usize>(board: &Board, side: usize) -> bool {
+    let king: Square = SOURCE[COLOR];
+    let king_dest: Square = DESTINATION[side][COLOR];
+    let rook: Square = board.castling.rook_square(side_of::<COLOR>(), side);
+    let rook_dest: Square = ROOK_DESTINATION[side][COLOR];
+
+    let path: BitBoard = (get_between(king, king_dest)
+        | king_dest.to_bitboard()
+        | get_between(king, rook)
+        | rook_dest.to_bitboard())
+        & !king.to_bitboard()
+        & !rook.to_bitboard();
+
+    (board.combined_bitboard() & path).is_empty()
+}
+
+/// Checks that no square the king of the side to move crosses or lands on when castling
+/// on `side` is attacked, for a king that is not in check.
+#[inline(always)]
+pub(crate) fn castling_path_safe(board: &Board, side: CastleSide) -> bool {
+    let color: usize = board.side as usize;
+    let side: usize = side as usize;
+    let king: Square = SOURCE[color];
+    let king_dest: Square = DESTINATION[side][color];
+    let rook: Square = board.castling.rook_square(board.side, side);
+
+    let king_path: BitBoard = get_between(king, king_dest) | king_dest.to_bitboard();
+    // The king cannot be shielded by the castling rook from an attack along the back rank.
+    let blockers: BitBoard = board.combined_bitboard() & !rook.to_bitboard();
+
+    king_path
+        .into_iter()
+        .all(|square| !board.attacked_square(square, blockers))
+}
+
+/// Enumerates all legal king moves, ensuring the king does not move into an attacked square
+/// unless the rules do not require king safety.
 /// The function considers both tactical (captures) and quiet moves based on the `MoveFilter` trait.
 #[inline(always)]
-fn enumerate_king_moves<M, F>(board: &Board, src: Square, handler: &mut F) -> bool
+fn enumerate_king_moves<M, R, F>(board: &Board, src: Square, rules: &R, handler: &mut F) -> bool
 where
+    R: MoveRules,
     M: MoveFilter,
     F: FnMut(Move) -> bool,
 {
@@ -488,7 +838,7 @@ where
 
     // Iterate through the possible king moves and ensure the king does not move into check.
     for dest in king {
-        if !board.attacked_square(dest, blockers) {
+        if !rules.king_safety() || !board.attacked_square(dest, blockers) {
             let is_capture: bool = (board.enemy_presence().0 & dest.to_bitboard().0) != 0;
             let move_type: MoveType = if is_capture {
                 MoveType::Capture
@@ -508,8 +858,7 @@ where
 fn enumerate_knight_moves<const IN_CHECK: bool, M, F>(
     board: &Board,
     src: BitBoard,
-    diagonal_pins: BitBoard,
-    linear_pins: BitBoard,
+    ctx: &MovegenContext,
     handler: &mut F,
 ) -> bool
 where
@@ -517,14 +866,14 @@ where
     F: FnMut(Move) -> bool,
 {
     // Remove pinned knights from the move generation.
-    let knights: BitBoard = src & !(diagonal_pins | linear_pins);
+    let knights: BitBoard = src & !(ctx.diagonal_pins | ctx.linear_pins);
 
     for src in knights {
         let mut attacks: BitBoard = get_knight_attacks(src) & !board.allied_presence();
 
         // If in check, restrict moves to those that block or capture the checking piece.
         if IN_CHECK {
-            attacks &= check_mask::<IN_CHECK>(board);
+            attacks &= ctx.check_mask;
         }
 
         if !M::QUIETS {
@@ -559,8 +908,7 @@ where
 fn enumerate_bishop_moves<const IN_CHECK: bool, M, F>(
     board: &Board,
     src: BitBoard,
-    diagonal_pins: BitBoard,
-    linear_pins: BitBoard,
+    ctx: &MovegenContext,
     handler: &mut F,
 ) -> bool
 where
@@ -568,14 +916,14 @@ where
     F: FnMut(Move) -> bool,
 {
     // Non pinned Bishops|Queens
-    let bishops: BitBoard = src & !linear_pins & !diagonal_pins;
+    let bishops: BitBoard = src & !ctx.linear_pins & !ctx.diagonal_pins;
 
     for src in bishops {
         let mut attacks: BitBoard =
             get_bishop_attacks(src, board.combined_bitboard()) & !board.allied_presence();
 
         if IN_CHECK {
-            attacks &= check_mask::<IN_CHECK>(board);
+            attacks &= ctx.check_mask;
         }
 
         if !M::QUIETS {
@@ -598,15 +946,15 @@ where
     }
 
     // Pinned Bishops|Queens along diagonal lines.
-    let bishops: BitBoard = src & !linear_pins & diagonal_pins;
+    let bishops: BitBoard = src & !ctx.linear_pins & ctx.diagonal_pins;
 
     for src in bishops {
         let mut attacks: BitBoard = get_bishop_attacks(src, board.combined_bitboard())
             & !board.allied_presence()
-            & diagonal_pins;
+            & ctx.diagonal_pins;
 
         if IN_CHECK {
-            attacks &= check_mask::<IN_CHECK>(board);
+            attacks &= ctx.check_mask;
         }
 
         if !M::QUIETS {
@@ -640,8 +988,7 @@ where
 fn enumerate_rook_moves<const IN_CHECK: bool, M, F>(
     board: &Board,
     src: BitBoard,
-    diagonal_pins: BitBoard,
-    linear_pins: BitBoard,
+    ctx: &MovegenContext,
     handler: &mut F,
 ) -> bool
 where
@@ -649,14 +996,14 @@ where
     F: FnMut(Move) -> bool,
 {
     // Non pinned Rooks|Queens
-    let rooks: BitBoard = src & !diagonal_pins & !linear_pins;
+    let rooks: BitBoard = src & !ctx.diagonal_pins & !ctx.linear_pins;
 
     for src in rooks {
         let mut attacks: BitBoard =
             get_rook_attacks(src, board.combined_bitboard()) & !board.allied_presence();
 
         if IN_CHECK {
-            attacks &= check_mask::<IN_CHECK>(board);
+            attacks &= ctx.check_mask;
         }
 
         if !M::QUIETS {
@@ -679,15 +1026,15 @@ where
     }
 
     // Pinned Rooks|Queens along rank or file.
-    let rooks: BitBoard = src & !diagonal_pins & linear_pins;
+    let rooks: BitBoard = src & !ctx.diagonal_pins & ctx.linear_pins;
 
     for src in rooks {
         let mut attacks: BitBoard = get_rook_attacks(src, board.combined_bitboard())
             & !board.allied_presence()
-            & linear_pins;
+            & ctx.linear_pins;
 
         if IN_CHECK {
-            attacks &= check_mask::<IN_CHECK>(board);
+            attacks &= ctx.check_mask;
         }
 
         if !M::QUIETS {
@@ -711,6 +1058,137 @@ where
     true
 }
 
+/// Counts the legal moves for the given board based on the specified move filter.
+///
+/// The result always equals `gen_moves::<M>(board).len()`, but instead of passing the moves
+/// one by one to a handler, the destination sets of pawns, knights and sliders are counted
+/// with a population count. This is the bulk-counting path used at the leaves of [`perft`](crate::perft).
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let board = Board::kiwipete();
+/// assert_eq!(count_legal_moves::<AllMoves>(&board), 48);
+/// assert_eq!(count_legal_moves::<TacticalMoves>(&board), 8);
+/// ```
+#[inline(always)]
+pub fn count_legal_moves<M: MoveFilter>(board: &Board) -> usize {
+    let ctx: MovegenContext = MovegenContext::new(board);
+    match board.side {
+        Color::White => count_color_moves::<WHITE, M>(board, &ctx),
+        Color::Black => count_color_moves::<BLACK, M>(board, &ctx),
+    }
+}
+
+/// The color-specialized core of [`count_legal_moves`].
+#[inline(always)]
+fn count_color_moves<const COLOR: usize, M: MoveFilter>(
+    board: &Board,
+    ctx: &MovegenContext,
+) -> usize {
+    const RANK_7: [BitBoard; 2] = [BitBoard::RANK_7, BitBoard::RANK_2];
+    const RANK_3: [BitBoard; 2] = [BitBoard::RANK_3, BitBoard::RANK_6];
+
+    let mut count: usize = 0;
+    let mut counter = |_: Move| -> bool {
+        count += 1;
+        true
+    };
+    enumerate_king_moves::<M, _, _>(board, ctx.king_square, &StandardRules, &mut counter);
+    let checkers: BitBoard = board.current_checkers();
+    if checkers.count_bits() > 1 {
+        return count;
+    }
+    if M::QUIETS && checkers.is_empty() {
+        enumerate_castling_moves::<COLOR, _, _>(board, &StandardRules, &mut counter);
+    }
+    if M::TACTICALS {
+        enumerate_pawn_en_passant_moves::<COLOR, _, _>(
+            board,
+            board.allied_pawns(),
+            ctx,
+            &StandardRules,
+            &mut counter,
+        );
+    }
+
+    let empty: BitBoard = !board.combined_bitboard();
+    let enemy: BitBoard = board.enemy_presence();
+    let mut targets: BitBoard = BitBoard::EMPTY;
+    if M::QUIETS {
+        targets |= empty;
+    }
+    if M::TACTICALS {
+        targets |= enemy;
+    }
+    targets &= ctx.check_mask;
+
+    let (diagonal_pins, linear_pins) = (ctx.diagonal_pins, ctx.linear_pins);
+    let mut total: u32 = 0;
+
+    // Pawn pushes and captures, with promotions counted once per promotion piece.
+    let pawns: BitBoard = board.allied_pawns();
+    let pushers: BitBoard = pawns & !diagonal_pins;
+    let pushes: BitBoard = ((pushers & !linear_pins).forward_for::<COLOR>()
+        | ((pushers & linear_pins).forward_for::<COLOR>() & linear_pins))
+        & empty;
+    let capturers: BitBoard = pawns & !linear_pins;
+    let captures: [BitBoard; 2] = [
+        ((capturers & !diagonal_pins).up_left_for::<COLOR>()
+            | ((capturers & diagonal_pins).up_left_for::<COLOR>() & diagonal_pins))
+            & enemy,
+        ((capturers & !diagonal_pins).up_right_for::<COLOR>()
+            | ((capturers & diagonal_pins).up_right_for::<COLOR>() & diagonal_pins))
+            & enemy,
+    ];
+    let promotion_rank: BitBoard = RANK_7[COLOR].forward_for::<COLOR>();
+    let mut promotions: u32 = 0;
+    for attacks in captures {
+        let attacks: BitBoard = attacks & ctx.check_mask;
+        if M::TACTICALS {
+            total += (attacks & !promotion_rank).count_bits();
+        }
+        promotions += (attacks & promotion_rank).count_bits();
+    }
+    let single_push: BitBoard = pushes & ctx.check_mask;
+    if M::QUIETS {
+        let double_push: BitBoard =
+            (pushes & RANK_3[COLOR]).forward_for::<COLOR>() & empty & ctx.check_mask;
+        total += (single_push & !promotion_rank).count_bits() + double_push.count_bits();
+    }
+    promotions += (single_push & promotion_rank).count_bits();
+    if M::TACTICALS {
+        total += promotions;
+    }
+    if M::QUIETS {
+        total += promotions * 3;
+    }
+
+    // Knights: pinned knights cannot move.
+    for src in board.allied_knights() & !(diagonal_pins | linear_pins) {
+        total += (get_knight_attacks(src) & targets).count_bits();
+    }
+
+    // Sliders: pinned sliders can only move along their pin line.
+    let blockers: BitBoard = board.combined_bitboard();
+    let bishops: BitBoard = (board.allied_bishops() | board.allied_queens()) & !linear_pins;
+    for src in bishops & !diagonal_pins {
+        total += (get_bishop_attacks(src, blockers) & targets).count_bits();
+    }
+    for src in bishops & diagonal_pins {
+        total += (get_bishop_attacks(src, blockers) & targets & diagonal_pins).count_bits();
+    }
+    let rooks: BitBoard = (board.allied_rooks() | board.allied_queens()) & !diagonal_pins;
+    for src in rooks & !linear_pins {
+        total += (get_rook_attacks(src, blockers) & targets).count_bits();
+    }
+    for src in rooks & linear_pins {
+        total += (get_rook_attacks(src, blockers) & targets & linear_pins).count_bits();
+    }
+
+    count + total as usize
+}
+
 /// Identifies all possible squares where a piece could be pinned to the king.
 ///
 /// This function determines squares that are along a potential pinning line
@@ -724,7 +1202,7 @@ where
 /// 3. Collects all such pinning paths and returns them as bitboards.
 #[inline(always)]
 pub fn pinners(board: &Board) -> (BitBoard, BitBoard) {
-    let king_square: Square = board.allied_king().to_square().unwrap();
+    let king_square: Square = board.allied_king_square();
     let blockers_mask: BitBoard = board.combined_bitboard();
 
     let probe: BitBoard = (get_bishop_rays(king_square) | get_rook_rays(king_square))
@@ -772,14 +1250,17 @@ pub fn pinners(board: &Board) -> (BitBoard, BitBoard) {
 /// - If the king is in check, the mask includes only the squares between the king and the attacking piece,
 ///   as well as the square occupied by the checker. This ensures only blocking or capturing moves are considered.
 /// - If the king is not in check, the mask allows movement to any square.
+/// - In double check, only king moves are legal and the mask is empty.
 #[inline(always)]
-fn check_mask<const IN_CHECK: bool>(board: &Board) -> BitBoard {
-    if IN_CHECK {
-        get_between(
-            unsafe { board.allied_king().to_square().unwrap_unchecked() },
-            unsafe { board.checkers.to_square().unwrap_unchecked() },
-        ) | board.checkers
-    } else {
-        BitBoard::FULL
+fn check_mask(board: &Board) -> BitBoard {
+    let checkers: BitBoard = board.current_checkers();
+    match checkers.count_bits() {
+        0 => BitBoard::FULL,
+        1 => {
+            get_between(board.allied_king_square(), unsafe {
+                checkers.to_square().unwrap_unchecked()
+            }) | checkers
+        }
+        _ => BitBoard::EMPTY,
     }
 }
@@ -17,10 +17,15 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::board::movegen::{MoveRules, StandardRules};
+use crate::castle_rights::{DESTINATION, KING_SIDE, QUEEN_SIDE, ROOK_DESTINATION};
 #[allow(unused_imports)]
 use crate::{
-    AllMoves, BitBoard, Board, CastleRights, Color, Move, MoveType, Piece, PieceType, SanBuffered,
-    Square, Zobrist, gen_moves, get_rook_castling, to_san,
+    AllMoves, BitBoard, Board, CastleRights, CastlingNotation, Color, ExtMove, LanBuffered, Move,
+    MoveDeltas, MoveType, Piece, PieceType, SanBuffered, SanParseError, Square, UciMove,
+    UciMoveError, Zobrist, ZobristTables, enumerate_legal_moves, gen_moves, get_bishop_attacks,
+    get_bishop_rays, get_knight_attacks, get_pawn_attacks, get_rook_attacks, get_rook_rays,
+    parse_san, to_lan, to_san,
 };
 
 // This implementation is based on the approach used in Carp,
@@ -37,7 +42,99 @@ impl Board {
     ///
     /// # Panics
     /// The function will panic if the source and destination squares of the move are the same.
+    ///
+    /// With the `strict` feature enabled, it also panics if the move is not generated by the
+    /// move generator for this board, or if the resulting position fails [`Board::validate`].
     pub fn make_move(&self, mv: Move) -> Board {
+        let (mut board, changed) = self.play_move(mv);
+
+        // Update checkers for the new board state
+        board.checkers = board.checkers_after(changed);
+
+        #[cfg(feature = "strict")]
+        if let Err(error) = board.validate() {
+            panic!(
+                "move {mv} in {} led to an invalid position: {error}",
+                self.to_fen()
+            );
+        }
+
+        // Return the updated board
+        board
+    }
+
+    /// Executes a move like [`Board::make_move`], but leaves the checkers of the resulting
+    /// board uncomputed, set to [`Board::UNKNOWN_CHECKERS`].
+    ///
+    /// This saves the check detection for callers that often discard the child before
+    /// generating its moves, for example after a transposition table cutoff. The move
+    /// generator, [`Board::status`] and [`Board::null_move`] compute the checkers when they
+    /// need them; [`Board::cache_checkers`] computes and stores them once so later calls
+    /// don't repeat the work. Code reading the [`Board::checkers`] field directly must call
+    /// it first.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = "4k3/8/8/8/8/8/8/4K2R w - - 0 1".parse().unwrap();
+    /// let mv: Move = board.move_from_uci("h1h8").unwrap();
+    ///
+    /// let mut lazy: Board = board.make_move_lazy(mv);
+    /// assert!(!lazy.checkers_known());
+    /// assert_eq!(lazy.cache_checkers(), Square::H8.to_bitboard());
+    /// assert_eq!(lazy, board.make_move(mv));
+    /// ```
+    pub fn make_move_lazy(&self, mv: Move) -> Board {
+        let (mut board, _) = self.play_move(mv);
+        board.checkers = Board::UNKNOWN_CHECKERS;
+        board
+    }
+
+    /// Returns `true` unless the checkers were left uncomputed by [`Board::make_move_lazy`].
+    #[inline(always)]
+    pub const fn checkers_known(&self) -> bool {
+        self.checkers.0 != Board::UNKNOWN_CHECKERS.0
+    }
+
+    /// Returns the checkers of the side to move, computing and storing them first if they
+    /// were left uncomputed by [`Board::make_move_lazy`].
+    #[inline(always)]
+    pub fn cache_checkers(&mut self) -> BitBoard {
+        self.checkers = self.current_checkers();
+        self.checkers
+    }
+
+    /// Returns the checkers of the side to move, computing them without storing them if
+    /// they were left uncomputed by [`Board::make_move_lazy`].
+    #[inline(always)]
+    pub(crate) fn current_checkers(&self) -> BitBoard {
+        if self.checkers_known() {
+            self.checkers
+        } else {
+            self.checkers()
+        }
+    }
+
+    /// Applies a move to a copy of the board, everything but the checkers, returning it
+    /// with the squares whose occupancy changed.
+    #[inline(always)]
+    fn play_move(&self, mv: Move) -> (Board, BitBoard) {
+        // Catch corrupted or illegal moves before they are applied.
+        #[cfg(feature = "strict")]
+        assert!(
+            self.generates_move(mv),
+            "illegal move {mv} in {}",
+            self.to_fen()
+        );
+
+        self.play_move_with(&StandardRules, mv)
+    }
+
+    /// Applies a move like [`Board::play_move`] under the rules `R`, without checking that
+    /// the standard move generator produces it. Double pushes from the
+    /// [`MoveRules::double_push_squares`] do not set an en passant square.
+    #[inline(always)]
+    pub(crate) fn play_move_with<R: MoveRules>(&self, rules: &R, mv: Move) -> (Board, BitBoard) {
         let mut board: Board = *self;
 
         // Ensure the source and destination squares are different.
@@ -65,16 +162,22 @@ impl Board {
             board.full_move = board.full_move.saturating_add(1);
         }
 
+        // Squares whose occupancy changes, used to update the checkers incrementally.
+        let mut changed: BitBoard = src.to_bitboard() | dest.to_bitboard();
+
         // Handle special move types (En Passant, Castling, Captures)
         match move_type {
             MoveType::EnPassant => {
-                board.remove_piece(dest.forward(!self.side));
+                let victim: Square = dest.forward(!self.side);
+                board.remove_piece(victim);
+                changed = changed.set_square(victim);
             }
             MoveType::KingCastle | MoveType::QueenCastle => {
                 let rook: Piece = Piece::new(PieceType::Rook, self.side);
-                let (rook_src, rook_dest) = get_rook_castling(dest);
+                let (rook_src, rook_dest) = self.castling_rook(move_type);
                 board.remove_piece(rook_src);
                 board.set_piece(rook, rook_dest);
+                changed = changed.set_square(rook_src).set_square(rook_dest);
             }
             _ if is_capture => {
                 board.remove_piece(dest);
@@ -95,7 +198,7 @@ impl Board {
             board.zobrist.hash_enpassant(square);
         }
 
-        if move_type == MoveType::DoublePawn {
+        if move_type == MoveType::DoublePawn && !rules.double_push_squares().get_square(src) {
             let enpassant_target: Square = src.forward(self.side);
             board.enpassant_square = Some(enpassant_target);
             board.zobrist.hash_enpassant(enpassant_target);
@@ -112,11 +215,122 @@ impl Board {
         board.side = !self.side;
         board.zobrist.hash_side();
 
-        // Recalculate checkers for the new board state
-        board.checkers = board.checkers();
+        (board, changed)
+    }
+
+    /// Returns the starting and destination squares of the rook for a castling move
+    /// of the side to move, given its [`MoveType`].
+    #[inline(always)]
+    fn castling_rook(&self, move_type: MoveType) -> (Square, Square) {
+        let side: usize = match move_type {
+            MoveType::KingCastle => KING_SIDE,
+            _ => QUEEN_SIDE,
+        };
+        (
+            self.castling.rook_square(self.side, side),
+            ROOK_DESTINATION[side][self.side as usize],
+        )
+    }
 
-        // Return the updated board
-        board
+    /// Computes the checkers of a board reached by a move that only changed the occupancy
+    /// of the `changed` squares, from a position where that side was not in check.
+    ///
+    /// Knight and pawn checks only need table lookups, while the slider attacks from the king
+    /// are only computed when an enemy slider and a changed square both lie on one of its lines:
+    /// otherwise no slider check can have been given or discovered.
+    #[inline(always)]
+    fn checkers_after(&self, changed: BitBoard) -> BitBoard {
+        let king: Square = self.allied_king_square();
+        let blockers: BitBoard = self.combined_bitboard();
+        let mut checkers: BitBoard = self.knights() & get_knight_attacks(king)
+            | self.pawns() & get_pawn_attacks(self.side, king);
+
+        let rays: BitBoard = get_bishop_rays(king);
+        let bishops: BitBoard = self.enemy_queen_bishops() & rays;
+        if !bishops.is_empty() && changed.intersects(rays) {
+            checkers |= bishops & get_bishop_attacks(king, blockers);
+        }
+
+        let rays: BitBoard = get_rook_rays(king);
+        let rooks: BitBoard = self.enemy_queen_rooks() & rays;
+        if !rooks.is_empty() && changed.intersects(rays) {
+            checkers |= rooks & get_rook_attacks(king, blockers);
+        }
+
+        checkers & self.enemy_presence()
+    }
+
+    /// Returns the piece changes that [`Board::make_move`] performs for the given move,
+    /// without applying it.
+    pub fn move_deltas(&self, mv: Move) -> MoveDeltas {
+        let mut deltas: MoveDeltas = MoveDeltas::new();
+
+        let src: Square = mv.get_src();
+        let dest: Square = mv.get_dest();
+        let piece: Piece = unsafe { self.piece_on(src).unwrap_unchecked() };
+
+        deltas.removed(piece, src);
+
+        match mv.get_type() {
+            MoveType::EnPassant => {
+                let captured: Square = dest.forward(!self.side);
+                deltas.removed(Piece::new(PieceType::Pawn, !self.side), captured);
+            }
+            MoveType::KingCastle | MoveType::QueenCastle => {
+                let rook: Piece = Piece::new(PieceType::Rook, self.side);
+                let (rook_src, rook_dest) = self.castling_rook(mv.get_type());
+                deltas.removed(rook, rook_src);
+                deltas.added(rook, rook_dest);
+            }
+            _ if mv.is_capture() => {
+                deltas.removed(unsafe { self.piece_on(dest).unwrap_unchecked() }, dest);
+            }
+            _ => {}
+        }
+
+        if mv.is_promotion() {
+            deltas.added(mv.get_prom(self.side), dest);
+        } else {
+            deltas.added(piece, dest);
+        }
+
+        deltas
+    }
+
+    /// Extends a move of this board with the moving and captured pieces, see [`ExtMove`].
+    pub fn ext_move(&self, mv: Move) -> ExtMove {
+        let piece: Piece = unsafe { self.piece_on(mv.get_src()).unwrap_unchecked() };
+        let captured: Option<Piece> = match mv.get_type() {
+            MoveType::EnPassant => Some(Piece::new(PieceType::Pawn, !self.side)),
+            _ if mv.is_capture() => self.piece_on(mv.get_dest()),
+            _ => None,
+        };
+        ExtMove::new(mv, piece, captured)
+    }
+
+    /// Executes a move like [`Board::make_move`] and also returns the piece changes it performed.
+    #[inline]
+    pub fn make_move_with_deltas(&self, mv: Move) -> (Board, MoveDeltas) {
+        (self.make_move(mv), self.move_deltas(mv))
+    }
+
+    /// Predicts the [`Zobrist`] hash of the position reached after the given move,
+    /// without making it.
+    ///
+    /// The result is identical to `self.make_move(mv).zobrist()` and accounts for captures,
+    /// castling, promotions and the en passant, castling and side-to-move keys. This is
+    /// intended for prefetching transposition table entries before the move is made.
+    /// [`ZobristTables::key_after`] does the same for other key sets.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let mv: Move = board.find_move("e2e4").unwrap();
+    /// assert_eq!(board.key_after(mv), board.make_move(mv).zobrist());
+    /// ```
+    pub fn key_after(&self, mv: Move) -> Zobrist {
+        ZobristTables::DEFAULT.key_after(self, self.zobrist, mv)
     }
 
     /// Executes a null move, switching the turn to the opponent without making any actual moves.
@@ -128,10 +342,11 @@ impl Board {
     ///
     /// # Panics
     /// This function will panic if the current player's checkers are not empty, indicating that the
-    /// game state is invalid for performing a null move.
+    /// game state is invalid for performing a null move. Use [`Board::try_null_move`] to branch on
+    /// that case instead.
     pub fn null_move(&self) -> Board {
         // Ensure there are no checkers on the board.
-        assert!(self.checkers.is_empty());
+        assert!(self.current_checkers().is_empty());
 
         // Create a copy of the current board, switch the side to move and update the Zobrist hash.
         let mut board: Board = *self;
@@ -153,24 +368,193 @@ impl Board {
         board
     }
 
-    /// Finds legal move in board from the uci-formatted move string
+    /// Executes a null move like [`Board::null_move`], returning `None` instead of
+    /// panicking when the side to move is in check.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert_eq!(board.try_null_move().map(|board| board.side()), Some(Color::Black));
+    ///
+    /// let check: Board = "4k3/8/8/8/8/8/8/4K2r w - - 0 1".parse().unwrap();
+    /// assert!(check.try_null_move().is_none());
+    /// ```
+    #[inline]
+    pub fn try_null_move(&self) -> Option<Board> {
+        if self.current_checkers().is_empty() {
+            Some(self.null_move())
+        } else {
+            None
+        }
+    }
+
+    /// Resolves a UCI-formatted move string into a legal [`Move`] for this board.
+    ///
+    /// The [`MoveType`] (capture, double push, en passant, castling or promotion)
+    /// is derived from the position, and the resulting move is then checked for
+    /// legality without generating and string-comparing the full move list.
+    ///
+    /// Castling is accepted both as the king's destination (`e1g1`) and as the king
+    /// taking its own rook (`e1h1`), see [`CastlingNotation`].
+    ///
+    /// # Errors
+    /// Returns a [`UciMoveError`] describing whether the string is malformed
+    /// or the move is not legal in the current position.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::kiwipete();
+    /// let castle: Move = Move::new(Square::E1, Square::G1, MoveType::KingCastle);
+    /// assert_eq!(board.move_from_uci("e1g1"), Ok(castle));
+    /// assert_eq!(board.move_from_uci("e1h1"), Ok(castle));
+    /// ```
+    pub fn move_from_uci(&self, uci: &str) -> Result<Move, UciMoveError> {
+        let (src, dest, prom) = Move::parse_uci(uci)?;
+
+        let piece: Piece = match self.piece_on(src) {
+            Some(piece) if piece.color() == self.side => piece,
+            _ => return Err(UciMoveError::IllegalMove),
+        };
+
+        // A king taking its own rook is a castling move: resolve it to the king's destination.
+        let rook: Piece = Piece::new(PieceType::Rook, self.side);
+        if piece.piece_type() == PieceType::King && self.piece_on(dest) == Some(rook) {
+            let (side, castle) = if dest.file() > src.file() {
+                (KING_SIDE, MoveType::KingCastle)
+            } else {
+                (QUEEN_SIDE, MoveType::QueenCastle)
+            };
+            let king_dest: Square = DESTINATION[side][self.side as usize];
+            let mv: Move = Move::new(src, king_dest, castle);
+            return if self.castling.rook_square(self.side, side) == dest && self.generates_move(mv)
+            {
+                Ok(mv)
+            } else {
+                Err(UciMoveError::IllegalMove)
+            };
+        }
+
+        let is_capture: bool = self
+            .piece_on(dest)
+            .is_some_and(|target| target.color() != self.side);
+
+        let move_type: MoveType = match (piece.piece_type(), prom) {
+            (PieceType::Pawn, Some(prom)) => match (prom, is_capture) {
+                (PieceType::Knight, false) => MoveType::PromotionKnight,
+                (PieceType::Bishop, false) => MoveType::PromotionBishop,
+                (PieceType::Rook, false) => MoveType::PromotionRook,
+                (PieceType::Queen, false) => MoveType::PromotionQueen,
+                (PieceType::Knight, true) => MoveType::CapPromoKnight,
+                (PieceType::Bishop, true) => MoveType::CapPromoBishop,
+                (PieceType::Rook, true) => MoveType::CapPromoRook,
+                _ => MoveType::CapPromoQueen,
+            },
+            (_, Some(_)) => return Err(UciMoveError::IllegalMove),
+            (PieceType::Pawn, None) if Some(dest) == self.enpassant_square => MoveType::EnPassant,
+            (PieceType::Pawn, None) if src.rank_distance(dest) == 2 => MoveType::DoublePawn,
+            (PieceType::King, None) if src.file_distance(dest) == 2 => {
+                if dest.file() > src.file() {
+                    MoveType::KingCastle
+                } else {
+                    MoveType::QueenCastle
+                }
+            }
+            _ if is_capture => MoveType::Capture,
+            _ => MoveType::Quiet,
+        };
+
+        let mv: Move = Move::new(src, dest, move_type);
+        if self.generates_move(mv) {
+            Ok(mv)
+        } else {
+            Err(UciMoveError::IllegalMove)
+        }
+    }
+
+    /// Returns whether the move generator produces the given move for this board.
+    fn generates_move(&self, mv: Move) -> bool {
+        let mut legal: bool = false;
+        enumerate_legal_moves::<AllMoves, _>(self, |candidate| {
+            legal |= candidate == mv;
+            true
+        });
+        legal
+    }
+
+    /// Finds the legal move in the board matching the UCI-formatted move string.
+    ///
+    /// This is equivalent to [`Board::move_from_uci`].
+    ///
+    /// # Errors
+    /// Returns a [`UciMoveError`] describing whether the string is malformed
+    /// or the move is not legal in the current position.
     #[inline]
-    pub fn find_move(&self, move_str: &str) -> Option<Move> {
-        gen_moves::<AllMoves>(self)
-            .iter()
-            .find(|&mv| *mv == move_str)
-            .copied()
+    pub fn find_move(&self, move_str: &str) -> Result<Move, UciMoveError> {
+        self.move_from_uci(move_str)
     }
 
     /// Attempts to make a move on the board using the UCI (Universal Chess Interface) notation.
-    pub fn make_uci_move(&self, uci_move: &str) -> Result<Board, &str> {
-        self.find_move(uci_move)
-            .map(|mv| self.make_move(mv))
-            .ok_or("Ilegal UCI move from the current board")
+    ///
+    /// # Errors
+    /// Returns a [`UciMoveError`] if the move cannot be resolved, see [`Board::find_move`].
+    pub fn make_uci_move(&self, uci_move: &str) -> Result<Board, UciMoveError> {
+        self.find_move(uci_move).map(|mv| self.make_move(mv))
+    }
+
+    /// Applies a whitespace-separated sequence of UCI moves, as found after
+    /// `moves` in the UCI `position` command, and returns the resulting board.
+    ///
+    /// # Errors
+    /// Returns the [`UciMoveError`] of the first move that cannot be resolved;
+    /// the moves before it are discarded along with the intermediate board.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default().apply_uci_moves("e2e4 e7e5 g1f3").unwrap();
+    /// assert_eq!(board.piece_on(Square::F3), Some(Piece::WN));
+    /// assert_eq!(board.side(), Color::Black);
+    /// ```
+    pub fn apply_uci_moves(&self, moves: &str) -> Result<Board, UciMoveError> {
+        moves
+            .split_ascii_whitespace()
+            .try_fold(*self, |board, uci| board.make_uci_move(uci))
     }
 
     /// Converts the move to a San
-    pub fn to_san(&self, mv: Move) -> SanBuffered {
+    pub fn to_san(&self, mv: Move) -> SanBuffered<'_> {
         to_san(mv, self)
     }
+
+    /// Parses a SAN move, ignoring any trailing annotation. See [`parse_san`] for the accepted syntax.
+    pub fn parse_san(&self, san: &str) -> Result<Move, SanParseError> {
+        parse_san(san, self).map(|(mv, _)| mv)
+    }
+
+    /// Converts the move to a Lan
+    pub fn to_lan(&self, mv: Move) -> LanBuffered<'_> {
+        to_lan(mv, self)
+    }
+
+    /// Formats the move in UCI notation, encoding castling as given by `notation`.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::kiwipete();
+    /// let castle: Move = board.move_from_uci("e1c1").unwrap();
+    /// assert_eq!(board.to_uci(castle, CastlingNotation::KingDestination).to_string(), "e1c1");
+    /// assert_eq!(board.to_uci(castle, CastlingNotation::KingTakesRook).to_string(), "e1a1");
+    /// ```
+    pub fn to_uci(&self, mv: Move, notation: CastlingNotation) -> UciMove {
+        let rook: Option<Square> = match notation {
+            CastlingNotation::KingTakesRook if mv.is_castle() => {
+                Some(self.castling_rook(mv.get_type()).0)
+            }
+            _ => None,
+        };
+        UciMove::new(mv, rook)
+    }
 }
@@ -0,0 +1,105 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{Board, Color, Piece, PieceType, Square};
+
+/// Number of input features of the HalfKP feature set (`64 * 641`).
+pub const HALFKP_DIMENSIONS: usize = 64 * HALFKP_PIECE_SQUARES;
+
+/// Number of input features of the HalfKAv2 feature set (`64 * 704`).
+pub const HALFKAV2_DIMENSIONS: usize = 64 * HALFKAV2_PIECE_SQUARES;
+
+/// Piece-square features per king square in HalfKP: 10 non-king pieces plus the unused `0` index.
+const HALFKP_PIECE_SQUARES: usize = 10 * Square::NUM_SQUARES + 1;
+
+/// Piece-square features per king square in HalfKAv2: 10 non-king pieces plus both kings sharing a plane.
+const HALFKAV2_PIECE_SQUARES: usize = 11 * Square::NUM_SQUARES;
+
+impl Board {
+    /// Returns the king square of the given color.
+    #[inline(always)]
+    fn nnue_king(&self, color: Color) -> Square {
+        let kings = self.kings() & self.sides_bitboard[color as usize];
+        unsafe { kings.to_square().unwrap_unchecked() }
+    }
+
+    /// Returns the piece plane of a piece as seen from `perspective`, with friendly
+    /// pieces on even planes and enemy pieces on odd planes.
+    #[inline(always)]
+    fn nnue_plane(piece: Piece, perspective: Color) -> usize {
+        piece.piece_index() * 2 + (piece.color() != perspective) as usize
+    }
+
+    /// Returns the active HalfKP feature indices from the point of view of `perspective`.
+    ///
+    /// Uses the Stockfish HalfKP layout: for Black the board is rotated by 180 degrees,
+    /// kings are only used as the bucket, and each index is below [`HALFKP_DIMENSIONS`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert_eq!(board.halfkp_indices(Color::White).count(), 30);
+    /// assert!(board.halfkp_indices(Color::Black).all(|index| index < HALFKP_DIMENSIONS));
+    /// ```
+    pub fn halfkp_indices(&self, perspective: Color) -> impl Iterator<Item = usize> + '_ {
+        let orient = move |square: Square| match perspective {
+            Color::White => square,
+            Color::Black => square.rotate_180(),
+        };
+        let king: usize = orient(self.nnue_king(perspective)).to_index();
+
+        (self.combined_bitboard() & !self.kings()).map(move |square| {
+            let piece: Piece = unsafe { self.piece_on(square).unwrap_unchecked() };
+            king * HALFKP_PIECE_SQUARES
+                + 1
+                + Self::nnue_plane(piece, perspective) * Square::NUM_SQUARES
+                + orient(square).to_index()
+        })
+    }
+
+    /// Returns the active HalfKAv2 feature indices from the point of view of `perspective`.
+    ///
+    /// Uses the Stockfish HalfKAv2 layout: for Black the board is flipped vertically,
+    /// both kings share the last piece plane, and each index is below [`HALFKAV2_DIMENSIONS`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// assert_eq!(board.halfkav2_indices(Color::White).count(), 32);
+    /// ```
+    pub fn halfkav2_indices(&self, perspective: Color) -> impl Iterator<Item = usize> + '_ {
+        let orient = move |square: Square| match perspective {
+            Color::White => square,
+            Color::Black => square.flip(),
+        };
+        let king: usize = orient(self.nnue_king(perspective)).to_index();
+
+        self.combined_bitboard().map(move |square| {
+            let piece: Piece = unsafe { self.piece_on(square).unwrap_unchecked() };
+            let plane: usize = if piece.piece_type() == PieceType::King {
+                10
+            } else {
+                Self::nnue_plane(piece, perspective)
+            };
+            king * HALFKAV2_PIECE_SQUARES + plane * Square::NUM_SQUARES + orient(square).to_index()
+        })
+    }
+}
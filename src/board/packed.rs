@@ -0,0 +1,140 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{BitBoard, Board, BoardBuilder, BoardParseError, CastleRights, Color, Piece, Square};
+use crate::{File, Rank};
+
+/// Size in bytes of a position encoded with [`Board::to_packed`].
+pub const PACKED_BOARD_SIZE: usize = 28;
+
+/// Maximum number of pieces that fit in a packed position.
+const MAX_PACKED_PIECES: usize = 32;
+
+/// Flag marking that the state byte holds an en passant file.
+const PACKED_EP_FLAG: u8 = 0b1000_0000;
+
+/// Flag marking Black to move in the halfmove byte.
+const PACKED_BLACK_FLAG: u8 = 0b1000_0000;
+
+impl Board {
+    /// Encodes the position into a compact, fixed-size binary form.
+    ///
+    /// The layout is:
+    ///
+    /// ```ignore
+    /// bytes  0..8    occupancy bitboard (little endian)
+    /// bytes  8..24   one 4-bit piece index per occupied square, from A1 upwards,
+    ///                low nibble first
+    /// byte   24      castling rights (bits 0-3), en passant file (bits 4-6)
+    ///                and en passant flag (bit 7)
+    /// byte   25      halfmove clock (bits 0-6) and side to move (bit 7, set for Black)
+    /// bytes 26..28   fullmove number (little endian)
+    /// ```
+    ///
+    /// Castling rights are stored without their rook files, which unpack as the standard
+    /// H and A files.
+    ///
+    /// Returns `None` if the board holds more than 32 pieces, which can't happen in a legal
+    /// game of standard chess but can in variants such as Horde.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::kiwipete();
+    /// let packed: [u8; PACKED_BOARD_SIZE] = board.to_packed().unwrap();
+    /// assert_eq!(Board::from_packed(&packed), Ok(board));
+    /// ```
+    pub fn to_packed(&self) -> Option<[u8; PACKED_BOARD_SIZE]> {
+        let mut packed: [u8; PACKED_BOARD_SIZE] = [0u8; PACKED_BOARD_SIZE];
+        let occupancy: BitBoard = self.combined_bitboard();
+        if occupancy.count_bits() as usize > MAX_PACKED_PIECES {
+            return None;
+        }
+
+        packed[0..8].copy_from_slice(&occupancy.0.to_le_bytes());
+
+        for (index, square) in occupancy.enumerate() {
+            let piece: Piece = unsafe { self.piece_on(square).unwrap_unchecked() };
+            packed[8 + index / 2] |= (piece.to_index() as u8) << (4 * (index % 2));
+        }
+
+        packed[24] = self.castling.to_index() as u8;
+        if let Some(square) = self.enpassant_square {
+            packed[24] |= PACKED_EP_FLAG | ((square.file() as u8) << 4);
+        }
+
+        packed[25] = self.fifty_move;
+        if self.side == Color::Black {
+            packed[25] |= PACKED_BLACK_FLAG;
+        }
+
+        packed[26..28].copy_from_slice(&self.full_move.to_le_bytes());
+        Some(packed)
+    }
+
+    /// Decodes a position produced by [`Board::to_packed`].
+    ///
+    /// # Errors
+    /// Returns a [`BoardParseError`] if the bytes contain an invalid piece index,
+    /// more than 32 pieces, or a state rejected by [`BoardBuilder::build`].
+    pub fn from_packed(packed: &[u8; PACKED_BOARD_SIZE]) -> Result<Board, BoardParseError> {
+        let mut occupancy_bytes: [u8; 8] = [0u8; 8];
+        occupancy_bytes.copy_from_slice(&packed[0..8]);
+        let occupancy: BitBoard = BitBoard(u64::from_le_bytes(occupancy_bytes));
+        if occupancy.count_bits() as usize > MAX_PACKED_PIECES {
+            return Err(BoardParseError::InvalidBoardLayout);
+        }
+
+        let mut builder: BoardBuilder = BoardBuilder::new();
+        for (index, square) in occupancy.enumerate() {
+            let nibble: u8 = (packed[8 + index / 2] >> (4 * (index % 2))) & 0b1111;
+            let piece: Piece =
+                Piece::from_index(nibble as usize).ok_or(BoardParseError::InvalidBoardLayout)?;
+            builder = builder.piece(square, piece);
+        }
+
+        let side: Color = if packed[25] & PACKED_BLACK_FLAG != 0 {
+            Color::Black
+        } else {
+            Color::White
+        };
+
+        let enpassant: Option<Square> = if packed[24] & PACKED_EP_FLAG != 0 {
+            let file: File = File::from_index(((packed[24] >> 4) & 0b111) as usize);
+            let rank: Rank = match side {
+                Color::White => Rank::Six,
+                Color::Black => Rank::Three,
+            };
+            Some(Square::from_file_rank(file, rank))
+        } else {
+            None
+        };
+
+        let mut full_move_bytes: [u8; 2] = [0u8; 2];
+        full_move_bytes.copy_from_slice(&packed[26..28]);
+
+        builder
+            .side(side)
+            .castling(CastleRights::from_index(packed[24] as usize))
+            .enpassant(enpassant)
+            .fifty_move(packed[25] & !PACKED_BLACK_FLAG)
+            .full_move(u16::from_le_bytes(full_move_bytes))
+            .build()
+    }
+}
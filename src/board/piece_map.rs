@@ -0,0 +1,112 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt;
+
+use crate::{Piece, Square};
+
+/// Number of bytes of a [`PieceMap`], one nibble per square.
+const PIECE_MAP_BYTES: usize = Square::NUM_SQUARES / 2;
+
+/// Maps each square to the piece occupying it, if any, packed into one nibble per square.
+///
+/// An empty square is stored as `0` and a [`Piece`] as its index plus one, so the whole
+/// mailbox takes 32 bytes instead of the 64 of an `[Option<Piece>; 64]`, keeping the
+/// [`Board`](crate::Board) small for copy-make search.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let mut map: PieceMap = PieceMap::EMPTY;
+/// map.set(Square::E4, Some(Piece::WN));
+/// assert_eq!(map.get(Square::E4), Some(Piece::WN));
+/// assert_eq!(map.get(Square::E5), None);
+/// assert_eq!(map.iter().flatten().count(), 1);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PieceMap([u8; PIECE_MAP_BYTES]);
+
+impl Default for PieceMap {
+    /// An empty piece map.
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// Lists the content of every square, from A1 to H8.
+impl fmt::Debug for PieceMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Packs an unpacked mailbox indexed by square.
+impl From<[Option<Piece>; Square::NUM_SQUARES]> for PieceMap {
+    fn from(pieces: [Option<Piece>; Square::NUM_SQUARES]) -> Self {
+        let mut map: PieceMap = PieceMap::EMPTY;
+        for (index, piece) in pieces.into_iter().enumerate() {
+            map.set(Square::from_index(index), piece);
+        }
+        map
+    }
+}
+
+/// Unpacks the mailbox into an array indexed by square.
+impl From<PieceMap> for [Option<Piece>; Square::NUM_SQUARES] {
+    fn from(map: PieceMap) -> Self {
+        map.to_array()
+    }
+}
+
+impl PieceMap {
+    /// A piece map with every square empty.
+    pub const EMPTY: PieceMap = PieceMap([0; PIECE_MAP_BYTES]);
+
+    /// Returns the piece on the given square, if any.
+    #[inline(always)]
+    pub const fn get(&self, square: Square) -> Option<Piece> {
+        let index: usize = square.to_index();
+        let nibble: u8 = (self.0[index >> 1] >> ((index & 1) << 2)) & 0xF;
+        Piece::from_index((nibble as usize).wrapping_sub(1))
+    }
+
+    /// Places a piece on the given square, or empties it with `None`.
+    #[inline(always)]
+    pub const fn set(&mut self, square: Square, piece: Option<Piece>) {
+        let index: usize = square.to_index();
+        let shift: usize = (index & 1) << 2;
+        let nibble: u8 = match piece {
+            Some(piece) => piece.to_index() as u8 + 1,
+            None => 0,
+        };
+        self.0[index >> 1] = (self.0[index >> 1] & !(0xF << shift)) | (nibble << shift);
+    }
+
+    /// Returns an iterator over the content of every square, from A1 to H8.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Option<Piece>> + '_ {
+        (0..Square::NUM_SQUARES).map(|index| self.get(Square::from_index(index)))
+    }
+
+    /// Unpacks the mailbox into an array indexed by square.
+    pub fn to_array(&self) -> [Option<Piece>; Square::NUM_SQUARES] {
+        core::array::from_fn(|index| self.get(Square::from_index(index)))
+    }
+}
@@ -0,0 +1,189 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{
+    AllMoves, BitBoard, Board, Color, Move, MoveList, Zobrist, count_legal_moves, gen_moves,
+};
+
+/// The outcome of a position, as reported by [`Board::status`], or of a game played out
+/// by [`Board::random_playout`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GameResult {
+    /// White delivered checkmate.
+    WhiteWins,
+    /// Black delivered checkmate.
+    BlackWins,
+    /// The game was drawn by stalemate, the fifty-move rule, threefold repetition
+    /// or insufficient material.
+    Draw,
+    /// The game has not ended, or the ply limit of a playout was reached first.
+    Unfinished,
+}
+
+/// The longest sequence of reversible plies, bounded by the fifty-move rule.
+const MAX_REVERSIBLE: usize = 101;
+
+impl Board {
+    /// Returns a uniformly chosen legal move, or `None` if there is none.
+    ///
+    /// `rng` must return uniformly distributed 64-bit values; any generator can be
+    /// adapted with a closure, so no random number crate is required.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    /// let mut rng = || {
+    ///     state ^= state << 13;
+    ///     state ^= state >> 7;
+    ///     state ^= state << 17;
+    ///     state
+    /// };
+    ///
+    /// let board: Board = Board::default();
+    /// let mv: Move = board.random_move(&mut rng).unwrap();
+    /// assert!(gen_moves::<AllMoves>(&board).contains(&mv));
+    /// ```
+    pub fn random_move<R: FnMut() -> u64>(&self, rng: &mut R) -> Option<Move> {
+        let moves: MoveList = gen_moves::<AllMoves>(self);
+        if moves.is_empty() {
+            return None;
+        }
+
+        // Map the 64-bit value onto the move count with a multiply-shift.
+        let index: usize = ((rng() as u128 * moves.len() as u128) >> 64) as usize;
+        Some(moves[index])
+    }
+
+    /// Plays uniformly random legal moves until the game ends or `max_plies` plies were played.
+    ///
+    /// The playout adjudicates checkmate, stalemate, the fifty-move rule, threefold
+    /// repetition within the playout and insufficient material. `rng` is used as in
+    /// [`Board::random_move`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// # use std::str::FromStr;
+    /// let mut state: u64 = 1;
+    /// let mut rng = || {
+    ///     state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///     state
+    /// };
+    ///
+    /// let mated: Board = Board::from_str("7k/6Q1/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    /// assert_eq!(mated.random_playout(&mut rng, 10), GameResult::WhiteWins);
+    ///
+    /// let result = Board::default().random_playout(&mut rng, 1000);
+    /// assert_ne!(result, GameResult::Unfinished);
+    /// ```
+    pub fn random_playout<R: FnMut() -> u64>(&self, rng: &mut R, max_plies: usize) -> GameResult {
+        let mut board: Board = *self;
+        let mut history: [Zobrist; MAX_REVERSIBLE] = [Zobrist::null(); MAX_REVERSIBLE];
+        let mut len: usize = 0;
+
+        let mut plies: usize = 0;
+
+        loop {
+            let Some(mv) = board.random_move(rng) else {
+                return match (board.current_checkers().is_empty(), board.side) {
+                    (true, _) => GameResult::Draw,
+                    (false, Color::White) => GameResult::BlackWins,
+                    (false, Color::Black) => GameResult::WhiteWins,
+                };
+            };
+
+            if board.fifty_move >= 100 || board.is_insufficient_material() {
+                return GameResult::Draw;
+            }
+
+            // Only positions since the last irreversible move can repeat, and the
+            // fifty-move rule bounds how many of them there are.
+            if board.fifty_move == 0 {
+                len = 0;
+            }
+            let repetitions: usize = history[..len]
+                .iter()
+                .filter(|&&key| key == board.zobrist)
+                .count();
+            if repetitions >= 2 {
+                return GameResult::Draw;
+            }
+            history[len] = board.zobrist;
+            len += 1;
+
+            if plies == max_plies {
+                return GameResult::Unfinished;
+            }
+            board = board.make_move(mv);
+            plies += 1;
+        }
+    }
+
+    /// Returns the outcome of the position: a win on checkmate, a draw on stalemate,
+    /// the fifty-move rule or insufficient material, and [`GameResult::Unfinished`] otherwise.
+    ///
+    /// Repetitions are not detected, as they depend on the game history.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let mated: Board = "7k/6Q1/6K1/8/8/8/8/8 b - - 0 1".parse().unwrap();
+    /// assert_eq!(mated.status(), GameResult::WhiteWins);
+    ///
+    /// let stalemate: Board = "7k/8/6QK/8/8/8/8/8 b - - 0 1".parse().unwrap();
+    /// assert_eq!(stalemate.status(), GameResult::Draw);
+    /// assert_eq!(Board::default().status(), GameResult::Unfinished);
+    /// ```
+    pub fn status(&self) -> GameResult {
+        match self.terminal_status() {
+            Some(result) => result,
+            None if self.is_insufficient_material() => GameResult::Draw,
+            None => GameResult::Unfinished,
+        }
+    }
+
+    /// Returns the outcome by checkmate, stalemate or the fifty-move rule, the part of
+    /// [`Board::status`] shared by variants with their own material rules.
+    pub(crate) fn terminal_status(&self) -> Option<GameResult> {
+        if count_legal_moves::<AllMoves>(self) == 0 {
+            return Some(match (self.current_checkers().is_empty(), self.side) {
+                (true, _) => GameResult::Draw,
+                (false, Color::White) => GameResult::BlackWins,
+                (false, Color::Black) => GameResult::WhiteWins,
+            });
+        }
+
+        (self.fifty_move >= 100).then_some(GameResult::Draw)
+    }
+
+    /// Returns `true` if neither side can possibly deliver checkmate: only kings remain,
+    /// plus a single minor piece or any number of bishops on squares of one color.
+    fn is_insufficient_material(&self) -> bool {
+        if !(self.pawns() | self.rooks() | self.queens()).is_empty() {
+            return false;
+        }
+
+        let bishops: BitBoard = self.bishops();
+        (self.knights() | bishops).count_bits() <= 1
+            || (self.knights().is_empty()
+                && ((bishops & BitBoard::LIGHT_SQUARES).is_empty()
+                    || (bishops & BitBoard::DARK_SQUARES).is_empty()))
+    }
+}
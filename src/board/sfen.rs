@@ -0,0 +1,367 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::castle_rights::{KING_SIDE, QUEEN_SIDE};
+use crate::{
+    AllMoves, Board, BoardBuilder, BoardParseError, CastleRights, Color, Move, MoveType,
+    PackedSfenError, Piece, PieceType, Square, gen_moves,
+};
+
+/// Size in bytes of a position encoded with [`Board::to_packed_sfen`].
+pub const PACKED_SFEN_SIZE: usize = 32;
+
+/// Size in bytes of a training record encoded with [`PackedSfenValue::to_bytes`].
+pub const PACKED_SFEN_VALUE_SIZE: usize = 40;
+
+/// 4-bit Huffman codes of the non-king piece types, indexed by [`PieceType`].
+/// An empty square is a single `0` bit, so every piece code starts with a `1` bit.
+const HUFFMAN_CODES: [(PieceType, u32); 5] = [
+    (PieceType::Pawn, 0b0001),
+    (PieceType::Knight, 0b0011),
+    (PieceType::Bishop, 0b0101),
+    (PieceType::Rook, 0b0111),
+    (PieceType::Queen, 0b1001),
+];
+
+/// Stockfish move type flags, stored in the top two bits of a move.
+const SF_PROMOTION: u16 = 1 << 14;
+const SF_EN_PASSANT: u16 = 2 << 14;
+const SF_CASTLING: u16 = 3 << 14;
+
+/// Writes values into a 256-bit stream, least significant bit first.
+struct BitWriter {
+    data: [u8; PACKED_SFEN_SIZE],
+    cursor: usize,
+}
+
+impl BitWriter {
+    /// Writes the low `bits` bits of `value`, or returns `None` if they overflow the stream.
+    fn write(&mut self, value: u32, bits: usize) -> Option<()> {
+        for bit in 0..bits {
+            if self.cursor >= PACKED_SFEN_SIZE * 8 {
+                return None;
+            }
+            self.data[self.cursor / 8] |= (((value >> bit) & 1) as u8) << (self.cursor % 8);
+            self.cursor += 1;
+        }
+        Some(())
+    }
+}
+
+/// Reads values written by a [`BitWriter`].
+struct BitReader<'a> {
+    data: &'a [u8; PACKED_SFEN_SIZE],
+    cursor: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, bits: usize) -> Result<u32, BoardParseError> {
+        let mut value: u32 = 0;
+        for bit in 0..bits {
+            if self.cursor >= PACKED_SFEN_SIZE * 8 {
+                return Err(BoardParseError::InvalidBoardLayout);
+            }
+            value |= (((self.data[self.cursor / 8] >> (self.cursor % 8)) & 1) as u32) << bit;
+            self.cursor += 1;
+        }
+        Ok(value)
+    }
+
+    fn read_square(&mut self, bits: usize) -> Result<Square, BoardParseError> {
+        let index: u32 = self.read(bits)?;
+        if index as usize >= Square::NUM_SQUARES {
+            return Err(BoardParseError::InvalidBoardLayout);
+        }
+        Ok(Square::from_index(index as usize))
+    }
+
+    /// Reads a Huffman-coded piece type, or `None` for an empty square.
+    fn read_piece_type(&mut self) -> Result<Option<PieceType>, BoardParseError> {
+        if self.read(1)? == 0 {
+            return Ok(None);
+        }
+        let code: u32 = 1 | self.read(3)? << 1;
+        HUFFMAN_CODES
+            .iter()
+            .find(|&&(_, huffman)| huffman == code)
+            .map(|&(piece_type, _)| Some(piece_type))
+            .ok_or(BoardParseError::InvalidBoardLayout)
+    }
+}
+
+impl Board {
+    /// Encodes the position in Stockfish's 256-bit `PackedSfen` format, as used by its NNUE
+    /// training data.
+    ///
+    /// The side to move and both king squares come first, followed by a Huffman code for every
+    /// other square from A8 to H1 rank by rank, the four castling flags, the en passant square,
+    /// and the move counters. Castling rights are stored without their rook files, which unpack
+    /// as the standard H and A files.
+    ///
+    /// Returns `None` if a side does not have exactly one king or the pieces do not fit in
+    /// 256 bits, which can't happen in a legal game of standard chess but can in variants such
+    /// as Horde.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::kiwipete();
+    /// let packed: [u8; PACKED_SFEN_SIZE] = board.to_packed_sfen().unwrap();
+    /// assert_eq!(Board::from_packed_sfen(&packed), Ok(board));
+    /// ```
+    pub fn to_packed_sfen(&self) -> Option<[u8; PACKED_SFEN_SIZE]> {
+        let mut stream: BitWriter = BitWriter {
+            data: [0u8; PACKED_SFEN_SIZE],
+            cursor: 0,
+        };
+
+        stream.write(self.side as u32, 1)?;
+        for color in [Color::White, Color::Black] {
+            if self.pieces_of(color, PieceType::King).count_bits() != 1 {
+                return None;
+            }
+            let king: Square = self.king_square(color);
+            stream.write(king.to_index() as u32, 7)?;
+        }
+
+        for rank in (0..8).rev() {
+            for file in 0..8 {
+                match self.piece_on(Square::from_index(rank * 8 + file)) {
+                    Some(piece) if piece.piece_type() == PieceType::King => {}
+                    Some(piece) => {
+                        stream.write(HUFFMAN_CODES[piece.piece_index()].1, 4)?;
+                        stream.write(piece.color() as u32, 1)?;
+                    }
+                    None => stream.write(0, 1)?,
+                }
+            }
+        }
+
+        for color in [Color::White, Color::Black] {
+            stream.write(self.castling.has_kingside(color) as u32, 1)?;
+            stream.write(self.castling.has_queenside(color) as u32, 1)?;
+        }
+
+        match self.enpassant_square {
+            Some(square) => {
+                stream.write(1, 1)?;
+                stream.write(square.to_index() as u32, 6)?;
+            }
+            None => stream.write(0, 1)?,
+        }
+
+        // The halfmove clock originally had 6 bits, so its 7th bit is stored last to stay
+        // compatible with older readers.
+        stream.write(self.fifty_move as u32, 6)?;
+        stream.write(self.full_move as u32, 16)?;
+        stream.write((self.fifty_move >> 6) as u32, 1)?;
+        Some(stream.data)
+    }
+
+    /// Decodes a position produced by [`Board::to_packed_sfen`] or by Stockfish.
+    ///
+    /// # Errors
+    /// Returns a [`BoardParseError`] if the bits hold an invalid square or piece code, the
+    /// pieces overflow the stream, or the state is rejected by [`BoardBuilder::build`].
+    pub fn from_packed_sfen(packed: &[u8; PACKED_SFEN_SIZE]) -> Result<Board, BoardParseError> {
+        let mut stream: BitReader = BitReader {
+            data: packed,
+            cursor: 0,
+        };
+
+        let side: Color = match stream.read(1)? {
+            0 => Color::White,
+            _ => Color::Black,
+        };
+
+        let mut builder: BoardBuilder = BoardBuilder::new().side(side);
+        let mut kings: [Square; 2] = [Square::A1; 2];
+        for color in [Color::White, Color::Black] {
+            kings[color as usize] = stream.read_square(7)?;
+            builder = builder.piece(kings[color as usize], Piece::new(PieceType::King, color));
+        }
+
+        for rank in (0..8).rev() {
+            for file in 0..8 {
+                let square: Square = Square::from_index(rank * 8 + file);
+                if kings.contains(&square) {
+                    continue;
+                }
+                if let Some(piece_type) = stream.read_piece_type()? {
+                    let color: Color = match stream.read(1)? {
+                        0 => Color::White,
+                        _ => Color::Black,
+                    };
+                    builder = builder.piece(square, Piece::new(piece_type, color));
+                }
+            }
+        }
+
+        let mut castling: CastleRights = CastleRights::null();
+        if stream.read(1)? != 0 {
+            castling.set_white_kingside();
+        }
+        if stream.read(1)? != 0 {
+            castling.set_white_queenside();
+        }
+        if stream.read(1)? != 0 {
+            castling.set_black_kingside();
+        }
+        if stream.read(1)? != 0 {
+            castling.set_black_queenside();
+        }
+
+        let enpassant: Option<Square> = match stream.read(1)? {
+            0 => None,
+            _ => Some(stream.read_square(6)?),
+        };
+
+        let fifty_move: u32 = stream.read(6)?;
+        let full_move: u32 = stream.read(16)?;
+        let fifty_move: u32 = fifty_move | stream.read(1)? << 6;
+
+        builder
+            .castling(castling)
+            .enpassant(enpassant)
+            .fifty_move(fifty_move as u8)
+            .full_move(full_move as u16)
+            .build()
+    }
+
+    /// Encodes a move of this position in Stockfish's 16-bit move format, with castling
+    /// given as the king moving onto its rook.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::kiwipete();
+    /// let castle: Move = board.find_move("e1g1").unwrap();
+    /// assert_eq!(board.from_stockfish_move(board.to_stockfish_move(castle)), Some(castle));
+    /// ```
+    pub fn to_stockfish_move(&self, mv: Move) -> u16 {
+        let src: u16 = mv.get_src().to_index() as u16;
+        let (dest, flag): (Square, u16) = match mv.get_type() {
+            MoveType::KingCastle => (self.castling.rook_square(self.side, KING_SIDE), SF_CASTLING),
+            MoveType::QueenCastle => (
+                self.castling.rook_square(self.side, QUEEN_SIDE),
+                SF_CASTLING,
+            ),
+            MoveType::EnPassant => (mv.get_dest(), SF_EN_PASSANT),
+            _ if mv.is_promotion() => {
+                let promotion: u16 = mv.get_prom(self.side).piece_index() as u16 - 1;
+                (mv.get_dest(), SF_PROMOTION | promotion << 12)
+            }
+            _ => (mv.get_dest(), 0),
+        };
+        flag | src << 6 | dest.to_index() as u16
+    }
+
+    /// Finds the legal move of this position matching a move in Stockfish's 16-bit format,
+    /// or returns `None` if there is no such move.
+    pub fn from_stockfish_move(&self, raw: u16) -> Option<Move> {
+        gen_moves::<AllMoves>(self)
+            .iter()
+            .copied()
+            .find(|&mv| self.to_stockfish_move(mv) == raw)
+    }
+}
+
+/// A Stockfish NNUE training record: a position with its search score, the move played,
+/// the game ply and the game result, laid out as Stockfish's `PackedSfenValue`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PackedSfenValue {
+    /// The position.
+    pub board: Board,
+
+    /// The search score, in centipawns from the side to move's perspective.
+    pub score: i16,
+
+    /// The move played from the position.
+    pub mv: Move,
+
+    /// The number of plies played in the game so far.
+    pub ply: u16,
+
+    /// The game result from the side to move's perspective: `1` for a win, `0` for a draw
+    /// and `-1` for a loss.
+    pub result: i8,
+}
+
+impl PackedSfenValue {
+    /// Encodes the record into Stockfish's 40-byte layout:
+    ///
+    /// ```ignore
+    /// bytes  0..32   position, as encoded by Board::to_packed_sfen
+    /// bytes 32..34   score (little endian)
+    /// bytes 34..36   move in Stockfish's format (little endian)
+    /// bytes 36..38   game ply (little endian)
+    /// byte   38      game result
+    /// byte   39      padding
+    /// ```
+    ///
+    /// Returns `None` if the position can't be encoded, see [`Board::to_packed_sfen`].
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let value: PackedSfenValue = PackedSfenValue {
+    ///     board,
+    ///     score: 25,
+    ///     mv: board.find_move("e2e4").unwrap(),
+    ///     ply: 0,
+    ///     result: 0,
+    /// };
+    /// assert_eq!(PackedSfenValue::from_bytes(&value.to_bytes().unwrap()), Ok(value));
+    /// ```
+    pub fn to_bytes(&self) -> Option<[u8; PACKED_SFEN_VALUE_SIZE]> {
+        let mut bytes: [u8; PACKED_SFEN_VALUE_SIZE] = [0u8; PACKED_SFEN_VALUE_SIZE];
+        bytes[0..32].copy_from_slice(&self.board.to_packed_sfen()?);
+        bytes[32..34].copy_from_slice(&self.score.to_le_bytes());
+        bytes[34..36].copy_from_slice(&self.board.to_stockfish_move(self.mv).to_le_bytes());
+        bytes[36..38].copy_from_slice(&self.ply.to_le_bytes());
+        bytes[38] = self.result as u8;
+        Some(bytes)
+    }
+
+    /// Decodes a record produced by [`PackedSfenValue::to_bytes`] or by Stockfish.
+    ///
+    /// # Errors
+    /// Returns [`PackedSfenError::InvalidPosition`] if the position can't be decoded, or
+    /// [`PackedSfenError::IllegalMove`] if the move is not legal in it.
+    pub fn from_bytes(bytes: &[u8; PACKED_SFEN_VALUE_SIZE]) -> Result<Self, PackedSfenError> {
+        let mut sfen: [u8; PACKED_SFEN_SIZE] = [0u8; PACKED_SFEN_SIZE];
+        sfen.copy_from_slice(&bytes[0..32]);
+        let board: Board =
+            Board::from_packed_sfen(&sfen).map_err(PackedSfenError::InvalidPosition)?;
+
+        let raw: u16 = u16::from_le_bytes([bytes[34], bytes[35]]);
+        let mv: Move = board
+            .from_stockfish_move(raw)
+            .ok_or(PackedSfenError::IllegalMove(raw))?;
+
+        Ok(PackedSfenValue {
+            board,
+            score: i16::from_le_bytes([bytes[32], bytes[33]]),
+            mv,
+            ply: u16::from_le_bytes([bytes[36], bytes[37]]),
+            result: bytes[38] as i8,
+        })
+    }
+}
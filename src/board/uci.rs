@@ -0,0 +1,90 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{Board, FenOptions, UciPositionError};
+
+/// Maximum number of fields taken from a FEN in a `position fen` command.
+const FEN_FIELDS: usize = 6;
+
+impl Board {
+    /// Parses a UCI `position` command into a [`Board`].
+    ///
+    /// Accepts `[position] startpos [moves ...]` and `[position] fen <fen> [moves ...]`.
+    /// The FEN may omit the halfmove clock and fullmove number, as some GUIs do.
+    ///
+    /// # Errors
+    /// Returns a [`UciPositionError`] carrying the index of the offending token.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::from_uci_position("position startpos moves e2e4 e7e5").unwrap();
+    /// assert_eq!(board.piece_on(Square::E5), Some(Piece::BP));
+    ///
+    /// let error = Board::from_uci_position("position startpos moves e2e4 e2e4").unwrap_err();
+    /// assert_eq!(error, UciPositionError::InvalidMove(4, UciMoveError::IllegalMove));
+    /// assert_eq!(error.token_index(), Some(4));
+    /// ```
+    pub fn from_uci_position(command: &str) -> Result<Board, UciPositionError> {
+        let mut tokens = command.split_ascii_whitespace().enumerate().peekable();
+
+        if let Some(&(_, "position")) = tokens.peek() {
+            tokens.next();
+        }
+
+        let board: Board = match tokens.next() {
+            Some((_, "startpos")) => Board::default(),
+            Some((index, "fen")) => {
+                // Slice the FEN fields out of the command so no allocation is needed.
+                let offset = |token: &str| token.as_ptr() as usize - command.as_ptr() as usize;
+                let (mut start, mut end) = (0, 0);
+                let mut fields: usize = 0;
+
+                while let Some(&(_, token)) = tokens.peek() {
+                    if token == "moves" || fields == FEN_FIELDS {
+                        break;
+                    }
+                    if fields == 0 {
+                        start = offset(token);
+                    }
+                    end = offset(token) + token.len();
+                    fields += 1;
+                    tokens.next();
+                }
+
+                Board::from_fen_with(&command[start..end], FenOptions::PERMISSIVE)
+                    .map_err(|err| UciPositionError::InvalidFen(index + 1, err))?
+            }
+            Some((index, _)) => return Err(UciPositionError::UnexpectedToken(index)),
+            None => return Err(UciPositionError::MissingPosition),
+        };
+
+        match tokens.next() {
+            None => return Ok(board),
+            Some((_, "moves")) => {}
+            Some((index, _)) => return Err(UciPositionError::UnexpectedToken(index)),
+        }
+
+        tokens.try_fold(board, |board, (index, uci)| {
+            board
+                .make_uci_move(uci)
+                .map_err(|err| UciPositionError::InvalidMove(index, err))
+        })
+    }
+}
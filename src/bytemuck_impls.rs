@@ -0,0 +1,71 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`bytemuck`] support for zero-copy reads, enabled by the `bytemuck` feature.
+//!
+//! [`BitBoard`] is plain old data. A [`Move`] is only valid when its type bits hold an
+//! assigned [`MoveType`](crate::MoveType) code, and a [`MoveList`] when its length does not
+//! exceed its capacity and its moves are valid, so both implement [`CheckedBitPattern`].
+//! [`Board`](crate::Board) contains enums and is not plain old data; store it with
+//! [`Board::to_packed`](crate::Board::to_packed), whose byte array is.
+
+use crate::{BitBoard, Move, MoveList, move_list::MAX_MOVES};
+use bytemuck::{CheckedBitPattern, NoUninit, Pod, Zeroable};
+use core::mem::size_of;
+
+// The all-zero move is the null move, a valid quiet move.
+unsafe impl Zeroable for Move {}
+
+// `Move` is a `repr(transparent)` `u16`.
+unsafe impl NoUninit for Move {}
+
+unsafe impl CheckedBitPattern for Move {
+    type Bits = u16;
+
+    #[inline]
+    fn is_valid_bit_pattern(bits: &u16) -> bool {
+        Move(*bits).has_valid_type()
+    }
+}
+
+unsafe impl Zeroable for BitBoard {}
+unsafe impl Pod for BitBoard {}
+
+// An all-zero list is an empty list.
+unsafe impl Zeroable for MoveList {}
+
+// `MoveList` is `repr(C)` and its move array exactly fills the space before `len`,
+// so it has no padding on any supported pointer width.
+unsafe impl NoUninit for MoveList {}
+
+unsafe impl CheckedBitPattern for MoveList {
+    type Bits = [u8; size_of::<MoveList>()];
+
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        const LEN_OFFSET: usize = MAX_MOVES * size_of::<Move>();
+        let mut len: [u8; size_of::<usize>()] = [0u8; size_of::<usize>()];
+        len.copy_from_slice(&bits[LEN_OFFSET..LEN_OFFSET + size_of::<usize>()]);
+        usize::from_ne_bytes(len) <= MAX_MOVES
+            && bits[..LEN_OFFSET]
+                .chunks_exact(size_of::<Move>())
+                .all(|mv: &[u8]| Move(u16::from_ne_bytes([mv[0], mv[1]])).has_valid_type())
+    }
+}
+
+const _: () = assert!(size_of::<MoveList>() == MAX_MOVES * size_of::<Move>() + size_of::<usize>());
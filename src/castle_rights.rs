@@ -18,21 +18,32 @@
 */
 
 use core::fmt;
+use core::ops::{BitOr, BitOrAssign, Sub, SubAssign};
 use core::str::FromStr;
 
-use crate::{BitBoard, CastleRightsParseError, Color, File, Square};
+use crate::{BitBoard, CastleRightsParseError, Color, File, Rank, Square};
 
 // This implementation is based on the approach used in Carp, which licensed under the GPLv3.
 // Source: https://github.com/dede1751/carp/blob/main/chess/src/castle.rs
 
 /// `CastleRights` represents the castling rights of both players (White and Black)
-/// using a bitmask stored in a `u8`. It tracks the availability of kingside and queenside
-/// castling rights for both sides.
+/// using a bitmask stored in a `u16`, together with the file of the rook each right
+/// castles with. It tracks the availability of kingside and queenside castling
+/// rights for both sides.
+///
+/// The low four bits hold the rights, followed by one 3-bit field per right storing
+/// its rook file relative to the standard one, so that the all-zero value means no
+/// rights with the H and A rook files. The rook files can be changed with
+/// [`CastleRights::with_rook_files`].
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Hash)]
-pub struct CastleRights(u8);
+pub struct CastleRights(u16);
 
 /// Implement the `FromStr` trait for `CastleRights`.
 /// This allows parsing a string into a `CastleRights` object.
+///
+/// Besides `KQkq`, the Shredder-FEN notation naming the rook file is accepted:
+/// a file right of the king (`H`, `g`, ...) is a kingside right and a file left of
+/// it (`A`, `b`, ...) a queenside right, uppercase for White.
 impl FromStr for CastleRights {
     type Err = CastleRightsParseError;
 
@@ -41,45 +52,65 @@ impl FromStr for CastleRights {
             return Ok(CastleRights::null());
         }
 
-        let mut rights: u8 = 0;
+        let mut rights: CastleRights = CastleRights::null();
 
         for ch in s.chars() {
-            match ch {
-                'K' => rights |= CASTLE_WK_MASK,
-                'Q' => rights |= CASTLE_WQ_MASK,
-                'k' => rights |= CASTLE_BK_MASK,
-                'q' => rights |= CASTLE_BQ_MASK,
+            let color: Color = if ch.is_ascii_uppercase() {
+                Color::White
+            } else {
+                Color::Black
+            };
+            let (side, file) = match ch.to_ascii_lowercase() {
+                'k' => (KING_SIDE, DEFAULT_ROOK_FILES[KING_SIDE]),
+                'q' => (QUEEN_SIDE, DEFAULT_ROOK_FILES[QUEEN_SIDE]),
+                file @ 'a'..='h' if file != KING_FILE.to_char() => {
+                    let file: File = File::from_index((file as u8 - b'a') as usize);
+                    let side: usize = if file > KING_FILE {
+                        KING_SIDE
+                    } else {
+                        QUEEN_SIDE
+                    };
+                    (side, file)
+                }
                 '-' => return Err(CastleRightsParseError::InvalidDashUsage),
-                c => return Err(CastleRightsParseError::InvalidChar(c)),
-            }
+                _ => return Err(CastleRightsParseError::InvalidChar(ch)),
+            };
+            rights.0 |= CASTLE_MASK[color as usize][side] as u16;
+            rights = rights.with_rook_file(color, side, file);
         }
 
-        Ok(CastleRights(rights))
+        Ok(rights)
     }
 }
 
 /// Implements the `fmt::Display` trait for the `CastleRights` struct,
 /// allowing castling rights to be displayed as a string.
+///
+/// Rights castling with the default rook files are written as `KQkq`, and
+/// any other as the rook file, following Shredder-FEN.
 impl fmt::Display for CastleRights {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut has_rights: bool = false;
 
-        if self.0 & CASTLE_WK_MASK != 0 {
-            write!(f, "K")?;
-            has_rights = true;
-        };
-        if self.0 & CASTLE_WQ_MASK != 0 {
-            write!(f, "Q")?;
-            has_rights = true;
-        };
-        if self.0 & CASTLE_BK_MASK != 0 {
-            write!(f, "k")?;
-            has_rights = true;
-        };
-        if self.0 & CASTLE_BQ_MASK != 0 {
-            write!(f, "q")?;
-            has_rights = true;
-        };
+        for color in [Color::White, Color::Black] {
+            for side in [KING_SIDE, QUEEN_SIDE] {
+                if self.0 & CASTLE_MASK[color as usize][side] as u16 == 0 {
+                    continue;
+                }
+
+                let file: File = self.rook_file(color, side);
+                let ch: char = match (file == DEFAULT_ROOK_FILES[side], side) {
+                    (true, KING_SIDE) => 'k',
+                    (true, _) => 'q',
+                    (false, _) => file.to_char(),
+                };
+                match color {
+                    Color::White => write!(f, "{}", ch.to_ascii_uppercase())?,
+                    Color::Black => write!(f, "{}", ch)?,
+                }
+                has_rights = true;
+            }
+        }
         if !has_rights {
             write!(f, "-")?;
         };
@@ -88,6 +119,17 @@ impl fmt::Display for CastleRights {
     }
 }
 
+/// The side of the board a castling right castles towards.
+#[repr(u8)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Hash)]
+pub enum CastleSide {
+    /// Castling towards the H file (`O-O`).
+    King,
+
+    /// Castling towards the A file (`O-O-O`).
+    Queen,
+}
+
 // Constants for castling bitmasks for both White and Black:
 /// `CASTLE_WK_MASK`: White kingside castling (bit 3)
 const CASTLE_WK_MASK: u8 = 0b1000;
@@ -98,19 +140,14 @@ const CASTLE_BK_MASK: u8 = 0b0010;
 /// `CASTLE_BQ_MASK`: Black queenside castling (bit 0)
 const CASTLE_BQ_MASK: u8 = 0b0001;
 
-// Arrays to simplify indexing for kingside and queenside castling rights
-// for both White and Black.
-const KINGSIDE_CASTLE: [u8; 2] = [CASTLE_WK_MASK, CASTLE_BK_MASK];
-const QUEENSIDE_CASTLE: [u8; 2] = [CASTLE_WQ_MASK, CASTLE_BQ_MASK];
+/// The bits holding the four castling rights.
+const FLAGS: u16 = 0b1111;
 
-// Masks to manipulate and remove specific castling rights.
-const ALL_CASTLE: u8 = 0b1111;
-const NOT_WK_RIGHTS: u8 = ALL_CASTLE ^ CASTLE_WK_MASK;
-const NOT_WQ_RIGHTS: u8 = ALL_CASTLE ^ CASTLE_WQ_MASK;
-const NOT_BK_RIGHTS: u8 = ALL_CASTLE ^ CASTLE_BK_MASK;
-const NOT_BQ_RIGHTS: u8 = ALL_CASTLE ^ CASTLE_BQ_MASK;
-const NOT_WHITE_RIGHTS: u8 = NOT_WK_RIGHTS & NOT_WQ_RIGHTS;
-const NOT_BLACK_RIGHTS: u8 = NOT_BK_RIGHTS & NOT_BQ_RIGHTS;
+// Array to simplify indexing the castling rights by color and side.
+const CASTLE_MASK: [[u8; 2]; 2] = [
+    [CASTLE_WK_MASK, CASTLE_WQ_MASK],
+    [CASTLE_BK_MASK, CASTLE_BQ_MASK],
+];
 
 /// Index representing the king-side castle in arrays.
 pub(crate) const KING_SIDE: usize = 0;
@@ -118,109 +155,28 @@ pub(crate) const KING_SIDE: usize = 0;
 /// Index representing the queen-side castle in arrays.
 pub(crate) const QUEEN_SIDE: usize = 1;
 
+/// The file the king starts on and castles from.
+const KING_FILE: File = File::E;
+
+/// The standard rook files, indexed by king-side (0) or queen-side (1).
+const DEFAULT_ROOK_FILES: [File; 2] = [File::H, File::A];
+
 /// Array defining the starting squares for castling moves for white and black.
 pub(crate) const SOURCE: [Square; 2] = [Square::E1, Square::E8];
 
-/// Array defining the intermediate squares crossed during castling.
-/// The first dimension represents the king-side (0) or queen-side (1).
-/// The second dimension represents white (0) or black (1).
-pub(crate) const MEDIUM: [[Square; 2]; 2] = [[Square::F1, Square::F8], [Square::D1, Square::D8]];
+/// The back ranks, the only squares whose moves can affect castling rights.
+const BACK_RANKS: u64 = BitBoard::RANK_1.0 | BitBoard::RANK_8.0;
 
 /// Array defining the destination squares for castling moves.
-/// The structure mirrors that of `MEDIUM`.
+/// The first dimension represents the king-side (0) or queen-side (1).
+/// The second dimension represents white (0) or black (1).
 pub(crate) const DESTINATION: [[Square; 2]; 2] =
     [[Square::G1, Square::G8], [Square::C1, Square::C8]];
 
-/// Array defining bitboard representations of squares that must be empty
-/// for castling to be valid. The first dimension represents the king-side (0)
-/// or queen-side (1), and the second represents white (0) or black (1).
-pub(crate) const PRESENCE: [[BitBoard; 2]; 2] = [
-    [BitBoard(0x0000000000000060), BitBoard(0x6000000000000000)],
-    [BitBoard(0x000000000000000E), BitBoard(0x0E00000000000000)],
-];
-
-/// Array defining the castle rights mask for each square on the board.
-/// Each value specifies the valid castling rights for the corresponding square.
-const CASTLE_RIGHTS_MASK: [u8; Square::NUM_SQUARES] = [
-    NOT_WQ_RIGHTS,    //  A1
-    ALL_CASTLE,       //  B1
-    ALL_CASTLE,       //  C1
-    ALL_CASTLE,       //  D1
-    NOT_WHITE_RIGHTS, //  E1
-    ALL_CASTLE,       //  F1
-    ALL_CASTLE,       //  G1
-    NOT_WK_RIGHTS,    //  H1
-    ALL_CASTLE,       //  A2
-    ALL_CASTLE,       //  B2
-    ALL_CASTLE,       //  C2
-    ALL_CASTLE,       //  D2
-    ALL_CASTLE,       //  E2
-    ALL_CASTLE,       //  F2
-    ALL_CASTLE,       //  G2
-    ALL_CASTLE,       //  H2
-    ALL_CASTLE,       //  A3
-    ALL_CASTLE,       //  B3
-    ALL_CASTLE,       //  C3
-    ALL_CASTLE,       //  D3
-    ALL_CASTLE,       //  E3
-    ALL_CASTLE,       //  F3
-    ALL_CASTLE,       //  G3
-    ALL_CASTLE,       //  H3
-    ALL_CASTLE,       //  A4
-    ALL_CASTLE,       //  B4
-    ALL_CASTLE,       //  C4
-    ALL_CASTLE,       //  D4
-    ALL_CASTLE,       //  E4
-    ALL_CASTLE,       //  F4
-    ALL_CASTLE,       //  G4
-    ALL_CASTLE,       //  H4
-    ALL_CASTLE,       //  A5
-    ALL_CASTLE,       //  B5
-    ALL_CASTLE,       //  C5
-    ALL_CASTLE,       //  D5
-    ALL_CASTLE,       //  E5
-    ALL_CASTLE,       //  F5
-    ALL_CASTLE,       //  G5
-    ALL_CASTLE,       //  H5
-    ALL_CASTLE,       //  A6
-    ALL_CASTLE,       //  B6
-    ALL_CASTLE,       //  C6
-    ALL_CASTLE,       //  D6
-    ALL_CASTLE,       //  E6
-    ALL_CASTLE,       //  F6
-    ALL_CASTLE,       //  G6
-    ALL_CASTLE,       //  H6
-    ALL_CASTLE,       //  A7
-    ALL_CASTLE,       //  B7
-    ALL_CASTLE,       //  C7
-    ALL_CASTLE,       //  D7
-    ALL_CASTLE,       //  E7
-    ALL_CASTLE,       //  F7
-    ALL_CASTLE,       //  G7
-    ALL_CASTLE,       //  H7
-    NOT_BQ_RIGHTS,    //  A8
-    ALL_CASTLE,       //  B8
-    ALL_CASTLE,       //  C8
-    ALL_CASTLE,       //  D8
-    NOT_BLACK_RIGHTS, //  E8
-    ALL_CASTLE,       //  F8
-    ALL_CASTLE,       //  G8
-    NOT_BK_RIGHTS,    //  H8
-];
-
-/// Returns the starting and destination squares for a rook during a castling move
-/// based on the destination of the king (`dest`).
-///
-/// - Kingside castling (king moves to G-file): returns (H-file, F-file)
-/// - Queenside castling (king moves to C-file): returns (A-file, D-file)
-#[inline(always)]
-pub(crate) const fn get_rook_castling(dest: Square) -> (Square, Square) {
-    match dest.file() {
-        File::C => (dest.left().left(), dest.right()),
-        File::G => (dest.right(), dest.left()),
-        _ => unreachable!(),
-    }
-}
+/// Array defining the destination squares of the rook for castling moves.
+/// The structure mirrors that of `DESTINATION`.
+pub(crate) const ROOK_DESTINATION: [[Square; 2]; 2] =
+    [[Square::F1, Square::F8], [Square::D1, Square::D8]];
 
 impl CastleRights {
     /// Total number of castling rights for all players.
@@ -233,55 +189,323 @@ impl CastleRights {
     }
 
     /// Converts the castling rights to an index that can be used for array lookups.
+    ///
+    /// Only the four castling flags are encoded, not the rook files, which the [`Zobrist`]
+    /// hash keys separately.
+    ///
+    /// [`Zobrist`]: crate::Zobrist
     #[inline(always)]
     pub const fn to_index(self) -> usize {
-        self.0 as usize
+        (self.0 & FLAGS) as usize
+    }
+
+    /// Creates castling rights from an index produced by [`CastleRights::to_index`],
+    /// castling with the default rook files. Bits above the four castling flags are ignored.
+    #[inline(always)]
+    pub const fn from_index(index: usize) -> Self {
+        Self(index as u16 & FLAGS)
+    }
+
+    /// Returns `true` if some right may castle with a rook other than the standard one.
+    #[inline(always)]
+    pub(crate) const fn has_custom_rook_files(self) -> bool {
+        self.0 & !FLAGS != 0
     }
 
     /// Checks if kingside castling is available for a given color (`Color`).
     #[inline(always)]
     pub const fn has_kingside(self, color: Color) -> bool {
-        self.0 & KINGSIDE_CASTLE[color as usize] != 0
+        self.0 & CASTLE_MASK[color as usize][KING_SIDE] as u16 != 0
     }
 
     /// Checks if queenside castling is available for a given color (`Color`).
     #[inline(always)]
     pub const fn has_queenside(self, color: Color) -> bool {
-        self.0 & QUEENSIDE_CASTLE[color as usize] != 0
+        self.0 & CASTLE_MASK[color as usize][QUEEN_SIDE] as u16 != 0
+    }
+
+    /// Returns the file of the rook the given color castles kingside with.
+    #[inline(always)]
+    pub const fn kingside_rook_file(self, color: Color) -> File {
+        self.rook_file(color, KING_SIDE)
+    }
+
+    /// Returns the file of the rook the given color castles queenside with.
+    #[inline(always)]
+    pub const fn queenside_rook_file(self, color: Color) -> File {
+        self.rook_file(color, QUEEN_SIDE)
+    }
+
+    /// Returns the castling rights with the rook files of the given color replaced.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let rights: CastleRights = "KQkq".parse::<CastleRights>().unwrap();
+    /// let rights: CastleRights = rights.with_rook_files(Color::White, File::G, File::B);
+    /// assert_eq!(rights.kingside_rook_file(Color::White), File::G);
+    /// assert_eq!(rights.to_string(), "GBkq");
+    /// assert_eq!("GBkq".parse(), Ok(rights));
+    /// ```
+    #[inline(always)]
+    pub const fn with_rook_files(self, color: Color, kingside: File, queenside: File) -> Self {
+        self.with_rook_file(color, KING_SIDE, kingside)
+            .with_rook_file(color, QUEEN_SIDE, queenside)
+    }
+
+    /// Returns the bit offset of the rook file field of a right.
+    #[inline(always)]
+    const fn file_shift(color: usize, side: usize) -> u16 {
+        4 + 3 * (color * 2 + side) as u16
+    }
+
+    /// Returns the file of the rook the given color castles with on `side`.
+    #[inline(always)]
+    const fn rook_file(self, color: Color, side: usize) -> File {
+        let offset: u16 = (self.0 >> Self::file_shift(color as usize, side)) & 0b111;
+        File::from_index(offset as usize ^ DEFAULT_ROOK_FILES[side] as usize)
+    }
+
+    /// Returns the castling rights with the rook file of one right replaced.
+    #[inline(always)]
+    const fn with_rook_file(self, color: Color, side: usize, file: File) -> Self {
+        let shift: u16 = Self::file_shift(color as usize, side);
+        let offset: u16 = (file as u16) ^ DEFAULT_ROOK_FILES[side] as u16;
+        Self((self.0 & !(0b111 << shift)) | (offset << shift))
+    }
+
+    /// Returns the starting square of the rook the given color castles with on `side`.
+    #[inline(always)]
+    pub(crate) const fn rook_square(self, color: Color, side: usize) -> Square {
+        Square::from_file_rank(self.rook_file(color, side), Rank::One.relative(color))
+    }
+
+    /// Returns the castling rights with one right added, castling with the rook on `file`.
+    #[inline(always)]
+    pub(crate) const fn with(self, color: Color, side: usize, file: File) -> Self {
+        Self(self.0 | CASTLE_MASK[color as usize][side] as u16).with_rook_file(color, side, file)
+    }
+
+    /// Returns the castling rights with one right removed, resetting its rook file so
+    /// that equal rights always compare equal.
+    #[inline(always)]
+    pub(crate) const fn without(self, color: Color, side: usize) -> Self {
+        let shift: u16 = Self::file_shift(color as usize, side);
+        Self(self.0 & !(CASTLE_MASK[color as usize][side] as u16 | (0b111 << shift)))
+    }
+
+    /// Checks if castling towards `side` is available for a given color (`Color`).
+    #[inline(always)]
+    pub const fn has(self, color: Color, side: CastleSide) -> bool {
+        self.0 & CASTLE_MASK[color as usize][side as usize] as u16 != 0
+    }
+
+    /// Grants the given color the right to castle towards `side`.
+    ///
+    /// A right that is already granted keeps its rook file; a new one castles with the
+    /// standard rook unless a file was set with [`CastleRights::with_rook_files`].
+    #[inline(always)]
+    pub const fn grant(&mut self, color: Color, side: CastleSide) {
+        self.0 |= CASTLE_MASK[color as usize][side as usize] as u16;
+    }
+
+    /// Revokes the right of the given color to castle towards `side`, resetting its rook file.
+    #[inline(always)]
+    pub const fn revoke(&mut self, color: Color, side: CastleSide) {
+        *self = self.without(color, side as usize);
+    }
+
+    /// Returns the castling rights with kingside castling granted to the given color.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let rights: CastleRights = CastleRights::null()
+    ///     .with_kingside(Color::White)
+    ///     .with_queenside(Color::Black);
+    /// assert_eq!(rights.to_string(), "Kq");
+    /// ```
+    #[inline(always)]
+    pub const fn with_kingside(mut self, color: Color) -> Self {
+        self.grant(color, CastleSide::King);
+        self
+    }
+
+    /// Returns the castling rights with queenside castling granted to the given color.
+    #[inline(always)]
+    pub const fn with_queenside(mut self, color: Color) -> Self {
+        self.grant(color, CastleSide::Queen);
+        self
+    }
+
+    /// Returns an iterator over the granted rights as `(Color, CastleSide)` pairs, in FEN
+    /// order (`KQkq`).
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let rights: CastleRights = "Kq".parse().unwrap();
+    /// let granted: Vec<(Color, CastleSide)> = rights.iter().collect();
+    /// assert_eq!(
+    ///     granted,
+    ///     [(Color::White, CastleSide::King), (Color::Black, CastleSide::Queen)]
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn iter(self) -> CastleRightsIter {
+        CastleRightsIter {
+            rights: self,
+            index: 0,
+        }
     }
 
     /// Enables white kingside castling.
     #[inline(always)]
     pub const fn set_white_kingside(&mut self) {
-        self.0 |= CASTLE_WK_MASK;
+        self.0 |= CASTLE_WK_MASK as u16;
     }
 
     /// Enables white queenside castling.
     #[inline(always)]
     pub const fn set_white_queenside(&mut self) {
-        self.0 |= CASTLE_WQ_MASK;
+        self.0 |= CASTLE_WQ_MASK as u16;
     }
 
     /// Enables black kingside castling.
     #[inline(always)]
     pub const fn set_black_kingside(&mut self) {
-        self.0 |= CASTLE_BK_MASK;
+        self.0 |= CASTLE_BK_MASK as u16;
     }
 
     /// Enables black queenside castling.
     #[inline(always)]
     pub const fn set_black_queenside(&mut self) {
-        self.0 |= CASTLE_BQ_MASK;
+        self.0 |= CASTLE_BQ_MASK as u16;
+    }
+
+    /// Returns the castling rights with the White and Black rights exchanged.
+    #[inline(always)]
+    pub const fn swap_colors(self) -> CastleRights {
+        const WHITE_FILES: u16 = 0b111111 << 4;
+        const BLACK_FILES: u16 = 0b111111 << 10;
+        CastleRights(
+            ((self.0 & 0b1100) >> 2)
+                | ((self.0 & 0b0011) << 2)
+                | ((self.0 & WHITE_FILES) << 6)
+                | ((self.0 & BLACK_FILES) >> 6),
+        )
     }
 
     /// Updates the castling rights after a move from `src` to `dest`.
     ///
-    /// The castling rights are updated based on the move, potentially clearing the castling
-    /// rights if a rook or king has moved from its starting square.
+    /// The castling rights are updated based on the move, clearing a right when
+    /// its king or rook moves from, or is captured on, its starting square.
+    /// Cleared rights also lose their rook file.
     #[inline(always)]
     pub const fn update(self, src: Square, dest: Square) -> CastleRights {
-        CastleRights(
-            self.0 & CASTLE_RIGHTS_MASK[src.to_index()] & CASTLE_RIGHTS_MASK[dest.to_index()],
-        )
+        // Only moves touching a back rank can affect castling.
+        let touched: u64 = src.to_bitboard().0 | dest.to_bitboard().0;
+        if self.0 & FLAGS == 0 || touched & BACK_RANKS == 0 {
+            return self;
+        }
+
+        const COLORS: [Color; 2] = [Color::White, Color::Black];
+        let mut rights: CastleRights = self;
+        let mut color: usize = 0;
+        while color < 2 {
+            let mut side: usize = 0;
+            while side < 2 {
+                let king: Square = SOURCE[color];
+                let rook: Square = self.rook_square(COLORS[color], side);
+                if touched & (king.to_bitboard().0 | rook.to_bitboard().0) != 0 {
+                    rights = rights.without(COLORS[color], side);
+                }
+                side += 1;
+            }
+            color += 1;
+        }
+        rights
+    }
+}
+
+/// Iterator over the granted rights of a [`CastleRights`], created by [`CastleRights::iter`].
+#[derive(Clone, Debug)]
+pub struct CastleRightsIter {
+    rights: CastleRights,
+    index: usize,
+}
+
+impl Iterator for CastleRightsIter {
+    type Item = (Color, CastleSide);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        const RIGHTS: [(Color, CastleSide); 4] = [
+            (Color::White, CastleSide::King),
+            (Color::White, CastleSide::Queen),
+            (Color::Black, CastleSide::King),
+            (Color::Black, CastleSide::Queen),
+        ];
+
+        while let Some(&(color, side)) = RIGHTS.get(self.index) {
+            self.index += 1;
+            if self.rights.has(color, side) {
+                return Some((color, side));
+            }
+        }
+        None
+    }
+}
+
+impl IntoIterator for CastleRights {
+    type Item = (Color, CastleSide);
+    type IntoIter = CastleRightsIter;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Combines two sets of castling rights. Rights held by both keep the rook file of the
+/// left-hand side.
+impl BitOr for CastleRights {
+    type Output = CastleRights;
+
+    fn bitor(self, rhs: CastleRights) -> CastleRights {
+        let mut rights: CastleRights = self;
+        for (color, side) in rhs {
+            if !rights.has(color, side) {
+                let file: File = rhs.rook_file(color, side as usize);
+                rights = rights.with(color, side as usize, file);
+            }
+        }
+        rights
+    }
+}
+
+impl BitOrAssign for CastleRights {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: CastleRights) {
+        *self = *self | rhs;
+    }
+}
+
+/// Removes the rights granted in `rhs`.
+impl Sub for CastleRights {
+    type Output = CastleRights;
+
+    fn sub(self, rhs: CastleRights) -> CastleRights {
+        let mut rights: CastleRights = self;
+        for (color, side) in rhs {
+            rights.revoke(color, side);
+        }
+        rights
+    }
+}
+
+impl SubAssign for CastleRights {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: CastleRights) {
+        *self = *self - rhs;
     }
 }
@@ -0,0 +1,230 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`chess`] crate interop, enabled by the `chess` feature.
+//!
+//! [`Square`], [`Color`] and [`PieceType`] convert losslessly in both directions, and a
+//! [`Board`] converts to and from a [`chess::Board`]. The chess crate only supports standard
+//! castling and does not track the move counters, which become `0 1` on the way back.
+//!
+//! A [`Move`] converts directly to a [`ChessMove`], since both encode castling by the king's
+//! destination square; the reverse needs the position and goes through
+//! [`Board::from_chess_move`].
+
+use chess::{ChessMove, Error};
+
+use crate::{
+    AllMoves, Board, BoardBuilder, BoardParseError, CastleRights, Color, File, Move, Piece,
+    PieceType, Rank, Square, gen_moves,
+};
+
+impl From<Square> for chess::Square {
+    #[inline]
+    fn from(square: Square) -> Self {
+        chess::ALL_SQUARES[square.to_index()]
+    }
+}
+
+impl From<chess::Square> for Square {
+    #[inline]
+    fn from(square: chess::Square) -> Self {
+        Square::from_index(square.to_index())
+    }
+}
+
+impl From<Color> for chess::Color {
+    #[inline]
+    fn from(color: Color) -> Self {
+        match color {
+            Color::White => chess::Color::White,
+            Color::Black => chess::Color::Black,
+        }
+    }
+}
+
+impl From<chess::Color> for Color {
+    #[inline]
+    fn from(color: chess::Color) -> Self {
+        match color {
+            chess::Color::White => Color::White,
+            chess::Color::Black => Color::Black,
+        }
+    }
+}
+
+impl From<PieceType> for chess::Piece {
+    #[inline]
+    fn from(piece_type: PieceType) -> Self {
+        match piece_type {
+            PieceType::Pawn => chess::Piece::Pawn,
+            PieceType::Knight => chess::Piece::Knight,
+            PieceType::Bishop => chess::Piece::Bishop,
+            PieceType::Rook => chess::Piece::Rook,
+            PieceType::Queen => chess::Piece::Queen,
+            PieceType::King => chess::Piece::King,
+        }
+    }
+}
+
+impl From<chess::Piece> for PieceType {
+    #[inline]
+    fn from(piece: chess::Piece) -> Self {
+        match piece {
+            chess::Piece::Pawn => PieceType::Pawn,
+            chess::Piece::Knight => PieceType::Knight,
+            chess::Piece::Bishop => PieceType::Bishop,
+            chess::Piece::Rook => PieceType::Rook,
+            chess::Piece::Queen => PieceType::Queen,
+            chess::Piece::King => PieceType::King,
+        }
+    }
+}
+
+impl TryFrom<&Board> for chess::Board {
+    type Error = Error;
+
+    /// Converts the board into a [`chess::Board`], dropping the move counters.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidBoard`] if a side may castle with a rook off the A or H file,
+    /// or if the chess crate rejects the position.
+    fn try_from(board: &Board) -> Result<Self, Self::Error> {
+        let mut builder: chess::BoardBuilder = chess::BoardBuilder::new();
+        for index in 0..Square::NUM_SQUARES {
+            let square: Square = Square::from_index(index);
+            if let Some(piece) = board.piece_on(square) {
+                builder.piece(
+                    square.into(),
+                    piece.piece_type().into(),
+                    piece.color().into(),
+                );
+            }
+        }
+
+        let castling: CastleRights = board.castling_rights();
+        for color in [Color::White, Color::Black] {
+            let kingside: bool = castling.has_kingside(color);
+            let queenside: bool = castling.has_queenside(color);
+            if (kingside && castling.kingside_rook_file(color) != File::H)
+                || (queenside && castling.queenside_rook_file(color) != File::A)
+            {
+                return Err(Error::InvalidBoard);
+            }
+            let rights: chess::CastleRights = match (kingside, queenside) {
+                (true, true) => chess::CastleRights::Both,
+                (true, false) => chess::CastleRights::KingSide,
+                (false, true) => chess::CastleRights::QueenSide,
+                (false, false) => chess::CastleRights::NoRights,
+            };
+            builder.castle_rights(color.into(), rights);
+        }
+
+        builder.side_to_move(board.side().into()).en_passant(
+            board
+                .enpassant_square
+                .map(|square| chess::File::from_index(square.file().to_index())),
+        );
+        chess::Board::try_from(&builder)
+    }
+}
+
+impl TryFrom<&chess::Board> for Board {
+    type Error = BoardParseError;
+
+    /// Converts a [`chess::Board`] into a [`Board`] with the move counters set to `0 1`.
+    ///
+    /// # Errors
+    /// Returns a [`BoardParseError`] if the position is rejected by [`BoardBuilder::build`].
+    fn try_from(board: &chess::Board) -> Result<Self, Self::Error> {
+        let mut builder: BoardBuilder = BoardBuilder::new();
+        for square in chess::ALL_SQUARES {
+            if let (Some(piece), Some(color)) = (board.piece_on(square), board.color_on(square)) {
+                builder = builder.piece(square.into(), Piece::new(piece.into(), color.into()));
+            }
+        }
+
+        let mut castling: CastleRights = CastleRights::null();
+        for color in [Color::White, Color::Black] {
+            let rights: chess::CastleRights = board.castle_rights(color.into());
+            match (color, rights.has_kingside()) {
+                (Color::White, true) => castling.set_white_kingside(),
+                (Color::Black, true) => castling.set_black_kingside(),
+                _ => {}
+            }
+            match (color, rights.has_queenside()) {
+                (Color::White, true) => castling.set_white_queenside(),
+                (Color::Black, true) => castling.set_black_queenside(),
+                _ => {}
+            }
+        }
+
+        // The chess crate stores the square of the pawn that can be captured en passant,
+        // while the board stores the square behind it.
+        let side: Color = board.side_to_move().into();
+        let enpassant: Option<Square> = board.en_passant().map(|pawn| {
+            Square::from_file_rank(Square::from(pawn).file(), Rank::Three.relative(!side))
+        });
+
+        builder
+            .side(side)
+            .castling(castling)
+            .enpassant(enpassant)
+            .build()
+    }
+}
+
+impl From<Move> for ChessMove {
+    /// Converts the move to a [`ChessMove`].
+    ///
+    /// Drops, which the chess crate cannot represent, convert to a move from and to the drop
+    /// square.
+    #[inline]
+    fn from(mv: Move) -> Self {
+        let dest: chess::Square = mv.get_dest().into();
+        #[cfg(feature = "variants")]
+        if mv.is_drop() {
+            return ChessMove::new(dest, dest, None);
+        }
+        ChessMove::new(
+            mv.get_src().into(),
+            dest,
+            mv.is_promotion()
+                .then(|| mv.get_prom(Color::White).piece_type().into()),
+        )
+    }
+}
+
+impl Board {
+    /// Finds the legal move of this position matching a [`ChessMove`], or returns `None` if
+    /// there is no such move.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let mv: chess::ChessMove = chess::ChessMove::new(chess::Square::G1, chess::Square::F3, None);
+    /// assert_eq!(board.from_chess_move(mv), Some(board.find_move("g1f3").unwrap()));
+    /// ```
+    pub fn from_chess_move(&self, mv: ChessMove) -> Option<Move> {
+        gen_moves::<AllMoves>(self)
+            .iter()
+            .copied()
+            .find(|&candidate| ChessMove::from(candidate) == mv)
+    }
+}
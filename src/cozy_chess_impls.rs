@@ -0,0 +1,245 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`cozy_chess`] interop, enabled by the `cozy-chess` feature.
+//!
+//! [`Square`], [`File`], [`Color`] and [`PieceType`] convert losslessly in both directions, and
+//! a [`Board`] converts to and from a [`cozy_chess::Board`], including Chess960 rook files.
+//!
+//! cozy-chess encodes castling as the king capturing its own rook, so a [`Move`] converts
+//! through [`Board::to_cozy_move`] and back through [`Board::from_cozy_move`].
+
+use cozy_chess::BoardBuilderError;
+
+use crate::castle_rights::{KING_SIDE, QUEEN_SIDE};
+use crate::{
+    AllMoves, Board, BoardBuilder, BoardParseError, CastleRights, CastleRightsParseError, Color,
+    File, Move, MoveType, PieceType, Square, gen_moves,
+};
+
+impl From<Square> for cozy_chess::Square {
+    #[inline]
+    fn from(square: Square) -> Self {
+        cozy_chess::Square::index(square.to_index())
+    }
+}
+
+impl From<cozy_chess::Square> for Square {
+    #[inline]
+    fn from(square: cozy_chess::Square) -> Self {
+        Square::from_index(square as usize)
+    }
+}
+
+impl From<File> for cozy_chess::File {
+    #[inline]
+    fn from(file: File) -> Self {
+        cozy_chess::File::index(file.to_index())
+    }
+}
+
+impl From<cozy_chess::File> for File {
+    #[inline]
+    fn from(file: cozy_chess::File) -> Self {
+        File::from_index(file as usize)
+    }
+}
+
+impl From<Color> for cozy_chess::Color {
+    #[inline]
+    fn from(color: Color) -> Self {
+        match color {
+            Color::White => cozy_chess::Color::White,
+            Color::Black => cozy_chess::Color::Black,
+        }
+    }
+}
+
+impl From<cozy_chess::Color> for Color {
+    #[inline]
+    fn from(color: cozy_chess::Color) -> Self {
+        match color {
+            cozy_chess::Color::White => Color::White,
+            cozy_chess::Color::Black => Color::Black,
+        }
+    }
+}
+
+impl From<PieceType> for cozy_chess::Piece {
+    #[inline]
+    fn from(piece_type: PieceType) -> Self {
+        match piece_type {
+            PieceType::Pawn => cozy_chess::Piece::Pawn,
+            PieceType::Knight => cozy_chess::Piece::Knight,
+            PieceType::Bishop => cozy_chess::Piece::Bishop,
+            PieceType::Rook => cozy_chess::Piece::Rook,
+            PieceType::Queen => cozy_chess::Piece::Queen,
+            PieceType::King => cozy_chess::Piece::King,
+        }
+    }
+}
+
+impl From<cozy_chess::Piece> for PieceType {
+    #[inline]
+    fn from(piece: cozy_chess::Piece) -> Self {
+        match piece {
+            cozy_chess::Piece::Pawn => PieceType::Pawn,
+            cozy_chess::Piece::Knight => PieceType::Knight,
+            cozy_chess::Piece::Bishop => PieceType::Bishop,
+            cozy_chess::Piece::Rook => PieceType::Rook,
+            cozy_chess::Piece::Queen => PieceType::Queen,
+            cozy_chess::Piece::King => PieceType::King,
+        }
+    }
+}
+
+impl TryFrom<&Board> for cozy_chess::Board {
+    type Error = BoardBuilderError;
+
+    /// Converts the board into a [`cozy_chess::Board`].
+    ///
+    /// # Errors
+    /// Returns a [`BoardBuilderError`] if cozy-chess rejects the position, for example when the
+    /// side not to move is in check.
+    fn try_from(board: &Board) -> Result<Self, Self::Error> {
+        let mut builder: cozy_chess::BoardBuilder = cozy_chess::BoardBuilder::empty();
+        for index in 0..Square::NUM_SQUARES {
+            let square: Square = Square::from_index(index);
+            if let Some(piece) = board.piece_on(square) {
+                *builder.square_mut(square.into()) =
+                    Some((piece.piece_type().into(), piece.color().into()));
+            }
+        }
+
+        let castling: CastleRights = board.castling_rights();
+        for color in [Color::White, Color::Black] {
+            let rights: &mut cozy_chess::CastleRights = builder.castle_rights_mut(color.into());
+            rights.short = castling
+                .has_kingside(color)
+                .then(|| castling.kingside_rook_file(color).into());
+            rights.long = castling
+                .has_queenside(color)
+                .then(|| castling.queenside_rook_file(color).into());
+        }
+
+        builder.side_to_move = board.side().into();
+        builder.en_passant = board.enpassant_square.map(cozy_chess::Square::from);
+        builder.halfmove_clock = board.fifty_move();
+        builder.fullmove_number = board.full_move();
+        builder.build()
+    }
+}
+
+impl TryFrom<&cozy_chess::Board> for Board {
+    type Error = BoardParseError;
+
+    /// Converts a [`cozy_chess::Board`] into a [`Board`].
+    ///
+    /// # Errors
+    /// Returns a [`BoardParseError`] if the halfmove clock exceeds 100, or
+    /// [`BoardParseError::InvalidCastlingRights`] if a side may castle with a king off the
+    /// e-file, which the board cannot represent.
+    fn try_from(board: &cozy_chess::Board) -> Result<Self, Self::Error> {
+        let source: cozy_chess::BoardBuilder = cozy_chess::BoardBuilder::from_board(board);
+        let mut builder: BoardBuilder = BoardBuilder::new();
+        for (index, entry) in source.board.iter().enumerate() {
+            if let Some((piece, color)) = *entry {
+                builder = builder.piece(
+                    Square::from_index(index),
+                    crate::Piece::new(piece.into(), color.into()),
+                );
+            }
+        }
+
+        let mut castling: CastleRights = CastleRights::null();
+        for color in [Color::White, Color::Black] {
+            let rights: &cozy_chess::CastleRights = source.castle_rights(color.into());
+            let rooks: [(usize, Option<cozy_chess::File>); 2] =
+                [(KING_SIDE, rights.short), (QUEEN_SIDE, rights.long)];
+            for (side, rook) in rooks {
+                let Some(rook) = rook else {
+                    continue;
+                };
+                let rook: File = rook.into();
+                if File::from(board.king(color.into()).file()) != File::E {
+                    let rook: char = rook.to_char();
+                    return Err(BoardParseError::InvalidCastlingRights(
+                        CastleRightsParseError::InvalidChar(match color {
+                            Color::White => rook.to_ascii_uppercase(),
+                            Color::Black => rook,
+                        }),
+                    ));
+                }
+                castling = castling.with(color, side, rook);
+            }
+        }
+
+        builder
+            .side(source.side_to_move.into())
+            .castling(castling)
+            .enpassant(source.en_passant.map(Square::from))
+            .fifty_move(source.halfmove_clock)
+            .full_move(source.fullmove_number)
+            .build()
+    }
+}
+
+impl Board {
+    /// Converts a move of this position to a [`cozy_chess::Move`], encoding castling as the
+    /// king moving onto its rook.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::kiwipete();
+    /// let castle: Move = board.find_move("e1g1").unwrap();
+    /// assert_eq!(board.to_cozy_move(castle).to_string(), "e1h1");
+    /// ```
+    pub fn to_cozy_move(&self, mv: Move) -> cozy_chess::Move {
+        let dest: Square = match mv.get_type() {
+            MoveType::KingCastle => self.castling_rights().rook_square(self.side(), KING_SIDE),
+            MoveType::QueenCastle => self.castling_rights().rook_square(self.side(), QUEEN_SIDE),
+            _ => mv.get_dest(),
+        };
+        cozy_chess::Move {
+            from: mv.get_src().into(),
+            to: dest.into(),
+            promotion: mv
+                .is_promotion()
+                .then(|| mv.get_prom(self.side()).piece_type().into()),
+        }
+    }
+
+    /// Finds the legal move of this position matching a [`cozy_chess::Move`], or returns
+    /// `None` if there is no such move.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::kiwipete();
+    /// let mv: Move = board.from_cozy_move("e1a1".parse().unwrap()).unwrap();
+    /// assert_eq!(mv, board.find_move("e1c1").unwrap());
+    /// ```
+    pub fn from_cozy_move(&self, mv: cozy_chess::Move) -> Option<Move> {
+        gen_moves::<AllMoves>(self)
+            .iter()
+            .copied()
+            .find(|&candidate| self.to_cozy_move(candidate) == mv)
+    }
+}
@@ -0,0 +1,118 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::ops::Deref;
+
+use crate::{Piece, Square};
+
+/// Maximum number of piece changes a single move can cause (castling moves two pieces).
+const MAX_DELTAS: usize = 4;
+
+/// Whether a piece was placed on or taken off a square.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum DeltaKind {
+    /// The piece was placed on the square.
+    Added,
+
+    /// The piece was removed from the square.
+    Removed,
+}
+
+/// A single piece placement change performed by a move.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct PieceDelta {
+    /// The piece that was added or removed.
+    pub piece: Piece,
+
+    /// The square the change happened on.
+    pub square: Square,
+
+    /// Whether the piece was added or removed.
+    pub kind: DeltaKind,
+}
+
+/// The piece changes performed by a move, in the order [`Board::make_move`](crate::Board::make_move)
+/// applies them.
+///
+/// Incremental evaluators and NNUE accumulators can replay these instead of diffing
+/// two boards or re-deriving the move semantics.
+///
+/// # Example
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let board = Board::default();
+/// let mv = Move::new(Square::G1, Square::F3, MoveType::Quiet);
+/// let (_, deltas) = board.make_move_with_deltas(mv);
+///
+/// assert_eq!(deltas.len(), 2);
+/// assert_eq!(deltas[0], PieceDelta { piece: Piece::WN, square: Square::G1, kind: DeltaKind::Removed });
+/// assert_eq!(deltas[1], PieceDelta { piece: Piece::WN, square: Square::F3, kind: DeltaKind::Added });
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct MoveDeltas {
+    deltas: [PieceDelta; MAX_DELTAS],
+    len: usize,
+}
+
+impl Deref for MoveDeltas {
+    type Target = [PieceDelta];
+
+    fn deref(&self) -> &Self::Target {
+        &self.deltas[..self.len]
+    }
+}
+
+impl MoveDeltas {
+    /// Creates an empty list of deltas.
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            deltas: [PieceDelta {
+                piece: Piece::WP,
+                square: Square::A1,
+                kind: DeltaKind::Added,
+            }; MAX_DELTAS],
+            len: 0,
+        }
+    }
+
+    /// Records a piece being placed on a square.
+    #[inline]
+    pub(crate) const fn added(&mut self, piece: Piece, square: Square) {
+        self.push(piece, square, DeltaKind::Added);
+    }
+
+    /// Records a piece being removed from a square.
+    #[inline]
+    pub(crate) const fn removed(&mut self, piece: Piece, square: Square) {
+        self.push(piece, square, DeltaKind::Removed);
+    }
+
+    #[inline]
+    const fn push(&mut self, piece: Piece, square: Square, kind: DeltaKind) {
+        self.deltas[self.len] = PieceDelta {
+            piece,
+            square,
+            kind,
+        };
+        self.len += 1;
+    }
+}
@@ -0,0 +1,236 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2025 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{BitBoard, Square};
+use core::mem::transmute;
+
+/// Precomputed bitboards for every [`Diagonal`], indexed by diagonal.
+const DIAGONAL_BITBOARDS: [BitBoard; Diagonal::NUM_DIAGONALS] = {
+    let mut table: [BitBoard; Diagonal::NUM_DIAGONALS] = [BitBoard::EMPTY; Diagonal::NUM_DIAGONALS];
+    let mut square: usize = 0;
+    while square < Square::NUM_SQUARES {
+        let index: usize = Square::from_index(square).diagonal().to_index();
+        table[index] = BitBoard(table[index].0 | 1 << square);
+        square += 1;
+    }
+    table
+};
+
+/// Precomputed bitboards for every [`AntiDiagonal`], indexed by anti-diagonal.
+const ANTI_DIAGONAL_BITBOARDS: [BitBoard; AntiDiagonal::NUM_ANTI_DIAGONALS] = {
+    let mut table: [BitBoard; AntiDiagonal::NUM_ANTI_DIAGONALS] =
+        [BitBoard::EMPTY; AntiDiagonal::NUM_ANTI_DIAGONALS];
+    let mut square: usize = 0;
+    while square < Square::NUM_SQUARES {
+        let index: usize = Square::from_index(square).anti_diagonal().to_index();
+        table[index] = BitBoard(table[index].0 | 1 << square);
+        square += 1;
+    }
+    table
+};
+
+/// Enum representing the diagonals of a chessboard running from the bottom-left towards the
+/// top-right (a1-h8 direction).
+/// Each diagonal is named after its lowest square, from the single-square `A8` diagonal to the
+/// single-square `H1` diagonal, with [`Diagonal::A1`] being the main diagonal.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Hash)]
+#[repr(u8)]
+pub enum Diagonal {
+    /// The diagonal starting on a8.
+    A8,
+
+    /// The diagonal starting on a7.
+    A7,
+
+    /// The diagonal starting on a6.
+    A6,
+
+    /// The diagonal starting on a5.
+    A5,
+
+    /// The diagonal starting on a4.
+    A4,
+
+    /// The diagonal starting on a3.
+    A3,
+
+    /// The diagonal starting on a2.
+    A2,
+
+    /// The main diagonal, starting on a1 (a1-h8).
+    A1,
+
+    /// The diagonal starting on b1.
+    B1,
+
+    /// The diagonal starting on c1.
+    C1,
+
+    /// The diagonal starting on d1.
+    D1,
+
+    /// The diagonal starting on e1.
+    E1,
+
+    /// The diagonal starting on f1.
+    F1,
+
+    /// The diagonal starting on g1.
+    G1,
+
+    /// The diagonal starting on h1.
+    H1,
+}
+
+impl Diagonal {
+    /// Total number of diagonals (15 in standard chess).
+    pub const NUM_DIAGONALS: usize = 15;
+
+    /// Array containing all possible diagonals (A8 to H1).
+    pub const ALL: [Self; Self::NUM_DIAGONALS] = [
+        Self::A8,
+        Self::A7,
+        Self::A6,
+        Self::A5,
+        Self::A4,
+        Self::A3,
+        Self::A2,
+        Self::A1,
+        Self::B1,
+        Self::C1,
+        Self::D1,
+        Self::E1,
+        Self::F1,
+        Self::G1,
+        Self::H1,
+    ];
+
+    /// Converts an index (0-14) to the corresponding `Diagonal`, wrapping out of range values.
+    #[inline(always)]
+    pub const fn from_index(index: usize) -> Diagonal {
+        unsafe { transmute((index % Self::NUM_DIAGONALS) as u8) }
+    }
+
+    /// Converts a `Diagonal` into its corresponding index (0 for A8, 14 for H1).
+    #[inline(always)]
+    pub const fn to_index(self) -> usize {
+        self as usize
+    }
+
+    /// Gets the Bitboard of the diagonal.
+    #[inline(always)]
+    pub const fn to_bitboard(self) -> BitBoard {
+        DIAGONAL_BITBOARDS[self.to_index()]
+    }
+}
+
+/// Enum representing the anti-diagonals of a chessboard running from the bottom-right towards
+/// the top-left (h1-a8 direction).
+/// Each anti-diagonal is named after its lowest square, from the single-square `A1` anti-diagonal
+/// to the single-square `H8` anti-diagonal, with [`AntiDiagonal::H1`] being the main anti-diagonal.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Hash)]
+#[repr(u8)]
+pub enum AntiDiagonal {
+    /// The anti-diagonal starting on a1.
+    A1,
+
+    /// The anti-diagonal starting on b1.
+    B1,
+
+    /// The anti-diagonal starting on c1.
+    C1,
+
+    /// The anti-diagonal starting on d1.
+    D1,
+
+    /// The anti-diagonal starting on e1.
+    E1,
+
+    /// The anti-diagonal starting on f1.
+    F1,
+
+    /// The anti-diagonal starting on g1.
+    G1,
+
+    /// The main anti-diagonal, starting on h1 (h1-a8).
+    H1,
+
+    /// The anti-diagonal starting on h2.
+    H2,
+
+    /// The anti-diagonal starting on h3.
+    H3,
+
+    /// The anti-diagonal starting on h4.
+    H4,
+
+    /// The anti-diagonal starting on h5.
+    H5,
+
+    /// The anti-diagonal starting on h6.
+    H6,
+
+    /// The anti-diagonal starting on h7.
+    H7,
+
+    /// The anti-diagonal starting on h8.
+    H8,
+}
+
+impl AntiDiagonal {
+    /// Total number of anti-diagonals (15 in standard chess).
+    pub const NUM_ANTI_DIAGONALS: usize = 15;
+
+    /// Array containing all possible anti-diagonals (A1 to H8).
+    pub const ALL: [Self; Self::NUM_ANTI_DIAGONALS] = [
+        Self::A1,
+        Self::B1,
+        Self::C1,
+        Self::D1,
+        Self::E1,
+        Self::F1,
+        Self::G1,
+        Self::H1,
+        Self::H2,
+        Self::H3,
+        Self::H4,
+        Self::H5,
+        Self::H6,
+        Self::H7,
+        Self::H8,
+    ];
+
+    /// Converts an index (0-14) to the corresponding `AntiDiagonal`, wrapping out of range values.
+    #[inline(always)]
+    pub const fn from_index(index: usize) -> AntiDiagonal {
+        unsafe { transmute((index % Self::NUM_ANTI_DIAGONALS) as u8) }
+    }
+
+    /// Converts an `AntiDiagonal` into its corresponding index (0 for A1, 14 for H8).
+    #[inline(always)]
+    pub const fn to_index(self) -> usize {
+        self as usize
+    }
+
+    /// Gets the Bitboard of the anti-diagonal.
+    #[inline(always)]
+    pub const fn to_bitboard(self) -> BitBoard {
+        ANTI_DIAGONAL_BITBOARDS[self.to_index()]
+    }
+}
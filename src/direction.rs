@@ -0,0 +1,154 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2025 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt;
+use core::mem::transmute;
+use core::ops::Neg;
+
+/// Enum representing the eight compass directions on a chessboard.
+///
+/// Directions are given from White's point of view: `North` points towards rank 8
+/// and `East` towards file H. They are used to shift bitboards and look up rays
+/// without relying on hand-rolled bit twiddling.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Hash)]
+#[repr(u8)]
+pub enum Direction {
+    /// Towards rank 8.
+    North,
+
+    /// Towards rank 8 and file H.
+    NorthEast,
+
+    /// Towards file H.
+    East,
+
+    /// Towards rank 1 and file H.
+    SouthEast,
+
+    /// Towards rank 1.
+    South,
+
+    /// Towards rank 1 and file A.
+    SouthWest,
+
+    /// Towards file A.
+    West,
+
+    /// Towards rank 8 and file A.
+    NorthWest,
+}
+
+/// Implementing `Display` for `Direction` to print its compass abbreviation (N, NE, ..., NW).
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::North => write!(f, "N"),
+            Self::NorthEast => write!(f, "NE"),
+            Self::East => write!(f, "E"),
+            Self::SouthEast => write!(f, "SE"),
+            Self::South => write!(f, "S"),
+            Self::SouthWest => write!(f, "SW"),
+            Self::West => write!(f, "W"),
+            Self::NorthWest => write!(f, "NW"),
+        }
+    }
+}
+
+/// Negating a `Direction` returns the opposite one.
+impl Neg for Direction {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
+impl Direction {
+    /// Total number of directions.
+    pub const NUM_DIRECTIONS: usize = 8;
+
+    /// Array containing all the directions, clockwise starting from `North`.
+    pub const ALL: [Self; Self::NUM_DIRECTIONS] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// The four orthogonal directions, along which rooks move.
+    pub const ORTHOGONAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The four diagonal directions, along which bishops move.
+    pub const DIAGONAL: [Self; 4] = [
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    /// Converts an index (0-7) to the corresponding `Direction`.
+    #[inline(always)]
+    pub const fn from_index(index: usize) -> Direction {
+        unsafe { transmute(index as u8 & 7) }
+    }
+
+    /// Converts a `Direction` into its corresponding index (0 for North, 7 for NorthWest).
+    #[inline(always)]
+    pub const fn to_index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the opposite direction.
+    #[inline(always)]
+    pub const fn opposite(self) -> Self {
+        unsafe { transmute((self as u8 + 4) & 7) }
+    }
+
+    /// Returns the change in square index produced by one step in this direction.
+    #[inline(always)]
+    pub const fn offset(self) -> i8 {
+        match self {
+            Self::North => 8,
+            Self::NorthEast => 9,
+            Self::East => 1,
+            Self::SouthEast => -7,
+            Self::South => -8,
+            Self::SouthWest => -9,
+            Self::West => -1,
+            Self::NorthWest => 7,
+        }
+    }
+
+    /// Returns `true` if the direction runs along a rank or a file.
+    #[inline(always)]
+    pub const fn is_orthogonal(self) -> bool {
+        self as u8 & 1 == 0
+    }
+
+    /// Returns `true` if the direction runs along a diagonal.
+    #[inline(always)]
+    pub const fn is_diagonal(self) -> bool {
+        self as u8 & 1 == 1
+    }
+}
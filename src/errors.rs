@@ -17,8 +17,11 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
+use core::error::Error;
 use core::fmt;
 
+use crate::Color;
+
 /// Errors that can occur while parsing castling rights from a FEN string.
 ///
 /// This error type is returned when converting a string slice into
@@ -49,6 +52,8 @@ impl fmt::Display for CastleRightsParseError {
     }
 }
 
+impl Error for CastleRightsParseError {}
+
 /// Errors that can occur while parsing a FEN string into a [`Board`].
 ///
 /// `BoardParseError` represents all possible failures that may happen during
@@ -109,6 +114,15 @@ pub enum BoardParseError {
 
     /// The fullmove number is zero or negative.
     FullmoveMustBePositive,
+
+    /// A side does not have exactly one king on the board.
+    InvalidKingCount,
+
+    /// The crazyhouse pocket contains an invalid piece or is not closed by `]`.
+    InvalidPocket,
+
+    /// The three-check counters are not of the form `3+3` or `+0+0`, or exceed three.
+    InvalidCheckCount,
 }
 
 impl fmt::Display for BoardParseError {
@@ -163,10 +177,93 @@ impl fmt::Display for BoardParseError {
             BoardParseError::FullmoveMustBePositive => {
                 f.write_str("Fullmove number must be greater than zero")
             }
+
+            BoardParseError::InvalidKingCount => {
+                f.write_str("Each side must have exactly one king on the board")
+            }
+
+            BoardParseError::InvalidPocket => f.write_str("Invalid crazyhouse pocket"),
+
+            BoardParseError::InvalidCheckCount => f.write_str("Invalid three-check counters"),
+        }
+    }
+}
+
+impl Error for BoardParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BoardParseError::InvalidPiece(err) => Some(err),
+            BoardParseError::InvalidCastlingRights(err) => Some(err),
+            BoardParseError::InvalidEnPassantSquare(err) => Some(err),
+            _ => None,
         }
     }
 }
 
+/// Violations of the structural invariants of a chess position, reported by [`Board::validate`].
+///
+/// These describe positions that can be represented by a [`Board`] (for example after manual
+/// edits or permissive FEN parsing) but could never arise in a legal game.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BoardValidationError {
+    /// The piece bitboards, side bitboards and piece map disagree with each other.
+    InconsistentBitboards,
+
+    /// The given side does not have exactly one king.
+    InvalidKingCount(Color),
+
+    /// A pawn stands on the first or eighth rank.
+    PawnOnBackRank,
+
+    /// The side to move is attacked by more than two pieces at once.
+    TooManyCheckers,
+
+    /// The side that just moved has its king in check.
+    OpponentInCheck,
+
+    /// A castling right is set although the king or rook is not on its original square.
+    InconsistentCastlingRights,
+
+    /// The en passant square could not result from the last double pawn push.
+    InvalidEnPassant,
+}
+
+impl fmt::Display for BoardValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardValidationError::InconsistentBitboards => {
+                f.write_str("The bitboards and the piece map are inconsistent")
+            }
+
+            BoardValidationError::InvalidKingCount(color) => {
+                write!(f, "Side '{}' does not have exactly one king", color)
+            }
+
+            BoardValidationError::PawnOnBackRank => {
+                f.write_str("A pawn stands on the first or eighth rank")
+            }
+
+            BoardValidationError::TooManyCheckers => {
+                f.write_str("The side to move is checked by more than two pieces")
+            }
+
+            BoardValidationError::OpponentInCheck => {
+                f.write_str("The side not to move is in check")
+            }
+
+            BoardValidationError::InconsistentCastlingRights => {
+                f.write_str("Castling rights do not match the king and rook placement")
+            }
+
+            BoardValidationError::InvalidEnPassant => {
+                f.write_str("The en passant square is not plausible for the position")
+            }
+        }
+    }
+}
+
+impl Error for BoardValidationError {}
+
 /// Errors that can occur when parsing a chess piece from a character.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PieceParseError {
@@ -184,6 +281,8 @@ impl fmt::Display for PieceParseError {
     }
 }
 
+impl Error for PieceParseError {}
+
 /// Errors that can occur when parsing a square from algebraic notation.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum SquareParseError {
@@ -204,3 +303,235 @@ impl fmt::Display for SquareParseError {
         }
     }
 }
+
+impl Error for SquareParseError {}
+
+/// Errors that can occur when resolving a move given in UCI notation.
+///
+/// This error type is returned by [`Board::find_move`](crate::Board::find_move)
+/// and [`Board::make_uci_move`](crate::Board::make_uci_move), and separates
+/// strings that are not UCI moves at all from well-formed moves that are not
+/// legal in the current position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UciMoveError {
+    /// The move string is not 4 or 5 characters long.
+    InvalidLength,
+
+    /// The source or destination square is not a valid square name.
+    InvalidSquare(SquareParseError),
+
+    /// The promotion suffix is not one of `n`, `b`, `r` or `q`.
+    InvalidPromotion(char),
+
+    /// The move is well-formed but not legal in the current position.
+    IllegalMove,
+
+    /// The piece of a crazyhouse drop is not one of `P`, `N`, `B`, `R` or `Q`.
+    #[cfg(feature = "variants")]
+    InvalidDropPiece(char),
+}
+
+impl fmt::Display for UciMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UciMoveError::InvalidLength => {
+                f.write_str("Invalid UCI move length (expected 4 or 5 characters)")
+            }
+            UciMoveError::InvalidSquare(err) => write!(f, "Invalid UCI move square: {}", err),
+            UciMoveError::InvalidPromotion(c) => {
+                write!(f, "Invalid UCI promotion character '{}'", c)
+            }
+            UciMoveError::IllegalMove => f.write_str("Illegal UCI move from the current board"),
+            #[cfg(feature = "variants")]
+            UciMoveError::InvalidDropPiece(c) => {
+                write!(f, "Invalid UCI drop piece character '{}'", c)
+            }
+        }
+    }
+}
+
+impl Error for UciMoveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            UciMoveError::InvalidSquare(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can occur when parsing a UCI `position` command.
+///
+/// Token indices refer to the whitespace-separated tokens of the input string,
+/// starting at `0` and including the leading `position` keyword when present.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UciPositionError {
+    /// Neither `startpos` nor `fen` was given.
+    MissingPosition,
+
+    /// A token appears where it is not allowed.
+    UnexpectedToken(usize),
+
+    /// The FEN starting at the given token is invalid.
+    InvalidFen(usize, BoardParseError),
+
+    /// The move at the given token cannot be played.
+    InvalidMove(usize, UciMoveError),
+}
+
+impl UciPositionError {
+    /// Returns the index of the offending token, if any.
+    pub const fn token_index(&self) -> Option<usize> {
+        match self {
+            UciPositionError::MissingPosition => None,
+            UciPositionError::UnexpectedToken(index)
+            | UciPositionError::InvalidFen(index, _)
+            | UciPositionError::InvalidMove(index, _) => Some(*index),
+        }
+    }
+}
+
+impl fmt::Display for UciPositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UciPositionError::MissingPosition => {
+                f.write_str("Missing 'startpos' or 'fen' in position command")
+            }
+            UciPositionError::UnexpectedToken(index) => {
+                write!(f, "Unexpected token at index {}", index)
+            }
+            UciPositionError::InvalidFen(index, err) => {
+                write!(f, "Invalid FEN at token {}: {}", index, err)
+            }
+            UciPositionError::InvalidMove(index, err) => {
+                write!(f, "Invalid move at token {}: {}", index, err)
+            }
+        }
+    }
+}
+
+impl Error for UciPositionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            UciPositionError::InvalidFen(_, err) => Some(err),
+            UciPositionError::InvalidMove(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can occur when parsing a move in Standard Algebraic Notation (SAN).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SanParseError {
+    /// The string is not valid SAN.
+    InvalidSyntax,
+
+    /// No legal move in the current position matches the SAN.
+    IllegalMove,
+
+    /// More than one legal move matches the SAN.
+    AmbiguousMove,
+}
+
+impl fmt::Display for SanParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SanParseError::InvalidSyntax => f.write_str("Invalid SAN syntax"),
+            SanParseError::IllegalMove => f.write_str("Illegal SAN move from the current board"),
+            SanParseError::AmbiguousMove => f.write_str("Ambiguous SAN move"),
+        }
+    }
+}
+
+impl Error for SanParseError {}
+
+/// Errors that can occur when parsing an EPD perft record with [`perft_epd`](crate::perft_epd).
+///
+/// Opcode indices refer to the `;`-separated operations following the position, starting at `0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PerftEpdError {
+    /// The position part of the record is not a valid FEN.
+    InvalidFen(BoardParseError),
+
+    /// The `D<depth>` opcode at the given index does not hold a valid depth.
+    InvalidDepth(usize),
+
+    /// The `D<depth>` opcode at the given index does not hold a valid node count.
+    InvalidNodeCount(usize),
+}
+
+impl fmt::Display for PerftEpdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PerftEpdError::InvalidFen(err) => write!(f, "Invalid EPD position: {}", err),
+            PerftEpdError::InvalidDepth(index) => {
+                write!(f, "Invalid perft depth in opcode {}", index)
+            }
+            PerftEpdError::InvalidNodeCount(index) => {
+                write!(f, "Invalid perft node count in opcode {}", index)
+            }
+        }
+    }
+}
+
+impl Error for PerftEpdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PerftEpdError::InvalidFen(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can occur when writing a FEN into a caller-provided buffer with
+/// [`Board::fen_into`](crate::Board::fen_into).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FenError {
+    /// The buffer cannot hold the FEN, which needs the given number of bytes.
+    BufferTooSmall(usize),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FenError::BufferTooSmall(required) => {
+                write!(f, "FEN buffer too small ({} bytes required)", required)
+            }
+        }
+    }
+}
+
+impl Error for FenError {}
+
+/// Errors that can occur when decoding a Stockfish training record with
+/// [`PackedSfenValue::from_bytes`](crate::PackedSfenValue::from_bytes).
+#[cfg(feature = "nnue")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PackedSfenError {
+    /// The packed position could not be decoded.
+    InvalidPosition(BoardParseError),
+
+    /// The stored move, in Stockfish's 16-bit format, is not legal in the position.
+    IllegalMove(u16),
+}
+
+#[cfg(feature = "nnue")]
+impl fmt::Display for PackedSfenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackedSfenError::InvalidPosition(err) => write!(f, "Invalid packed position: {}", err),
+            PackedSfenError::IllegalMove(raw) => {
+                write!(f, "Illegal move {:#06x} in packed record", raw)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "nnue")]
+impl Error for PackedSfenError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PackedSfenError::InvalidPosition(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt;
+
+use crate::{Move, Piece};
+
+const MOVE_MASK: u32 = 0x0000_FFFF;
+const PIECE_SHIFT: u32 = 16;
+const CAPTURED_SHIFT: u32 = 20;
+const NIBBLE_MASK: u32 = 0xF;
+const NO_PIECE: u32 = 0xF;
+
+/// A [`Move`] extended to 32 bits with the moving piece and the captured piece.
+///
+/// The move is encoded using the following bit layout:
+///
+/// ```ignore
+/// 0000 0000 0000 0000 1111 1111 1111 1111    move            0x0000FFFF
+/// 0000 0000 0000 1111 0000 0000 0000 0000    moving piece    0x000F0000
+/// 0000 0000 1111 0000 0000 0000 0000 0000    captured piece  0x00F00000
+/// ```
+///
+/// A captured piece of `0xF` means the move is not a capture. Keeping this information
+/// next to the move makes history heuristics, SEE caching and unmake logic cheaper, as
+/// it does not have to be derived from the board again. The conversion back to a [`Move`]
+/// is lossless.
+///
+/// # Examples
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let board = Board::kiwipete();
+/// let mv = board.find_move("e5f7").unwrap();
+/// let ext = board.ext_move(mv);
+///
+/// assert_eq!(ext.piece(), Piece::WN);
+/// assert_eq!(ext.captured(), Some(Piece::BP));
+/// assert_eq!(Move::from(ext), mv);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[repr(transparent)]
+pub struct ExtMove(pub u32);
+
+/// Displays the `ExtMove` like the underlying [`Move`].
+impl fmt::Display for ExtMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.mv().fmt(f)
+    }
+}
+
+impl From<ExtMove> for Move {
+    #[inline(always)]
+    fn from(ext: ExtMove) -> Self {
+        ext.mv()
+    }
+}
+
+impl ExtMove {
+    /// Creates an `ExtMove` from a [`Move`], the piece making it and the piece it captures, if any.
+    #[inline(always)]
+    pub const fn new(mv: Move, piece: Piece, captured: Option<Piece>) -> Self {
+        let captured: u32 = match captured {
+            Some(captured) => captured as u32,
+            None => NO_PIECE,
+        };
+        Self(mv.0 as u32 | (piece as u32) << PIECE_SHIFT | captured << CAPTURED_SHIFT)
+    }
+
+    /// Returns the underlying [`Move`].
+    #[inline(always)]
+    pub const fn mv(self) -> Move {
+        Move((self.0 & MOVE_MASK) as u16)
+    }
+
+    /// Returns the piece making the move.
+    ///
+    /// The encoding produced by [`ExtMove::new`] guarantees a valid piece index.
+    #[inline(always)]
+    pub const fn piece(self) -> Piece {
+        unsafe { core::mem::transmute(((self.0 >> PIECE_SHIFT) & NIBBLE_MASK) as u8) }
+    }
+
+    /// Returns the piece captured by the move, or `None` if it is not a capture.
+    ///
+    /// For en passant captures this is the captured pawn.
+    #[inline(always)]
+    pub const fn captured(self) -> Option<Piece> {
+        match (self.0 >> CAPTURED_SHIFT) & NIBBLE_MASK {
+            NO_PIECE => None,
+            index => Some(unsafe { core::mem::transmute::<u8, Piece>(index as u8) }),
+        }
+    }
+}
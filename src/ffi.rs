@@ -0,0 +1,162 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2025 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! C bindings for the move generator, enabled by the `ffi` feature.
+//!
+//! Boards are handed out as opaque heap pointers, and moves cross the boundary in their packed
+//! 16-bit [`Move`] encoding. Build a C library with
+//! `cargo rustc --release --lib --features ffi --crate-type cdylib` (or `staticlib`), and declare
+//! the functions on the C side as:
+//!
+//! ```c
+//! typedef struct LauraBoard LauraBoard;
+//!
+//! LauraBoard *laura_board_new(void);
+//! LauraBoard *laura_board_from_fen(const char *fen);
+//! LauraBoard *laura_board_clone(const LauraBoard *board);
+//! void laura_board_free(LauraBoard *board);
+//! size_t laura_board_gen_moves(const LauraBoard *board, uint16_t *moves, size_t capacity);
+//! bool laura_board_make_move(LauraBoard *board, uint16_t mv);
+//! uint64_t laura_board_perft(const LauraBoard *board, uint32_t depth);
+//! ```
+
+use alloc::boxed::Box;
+use core::ffi::{CStr, c_char};
+use core::ptr::null_mut;
+use core::str::FromStr;
+
+use crate::{AllMoves, Board, Move, MoveList, gen_moves, perft};
+
+/// Allocates a new board holding the standard starting position.
+///
+/// The board must be released with [`laura_board_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn laura_board_new() -> *mut Board {
+    Box::into_raw(Box::new(Board::default()))
+}
+
+/// Parses a FEN string into a newly allocated board.
+///
+/// Returns a null pointer if `fen` is null, is not valid UTF-8 or is not a valid FEN.
+/// The board must be released with [`laura_board_free`].
+///
+/// # Safety
+///
+/// `fen` must be null or point to a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn laura_board_from_fen(fen: *const c_char) -> *mut Board {
+    if fen.is_null() {
+        return null_mut();
+    }
+    match unsafe { CStr::from_ptr(fen) }
+        .to_str()
+        .ok()
+        .and_then(|fen| Board::from_str(fen).ok())
+    {
+        Some(board) => Box::into_raw(Box::new(board)),
+        None => null_mut(),
+    }
+}
+
+/// Allocates a copy of a board, or returns a null pointer if `board` is null.
+///
+/// The copy must be released with [`laura_board_free`].
+///
+/// # Safety
+///
+/// `board` must be null or a pointer returned by this module and not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn laura_board_clone(board: *const Board) -> *mut Board {
+    match unsafe { board.as_ref() } {
+        Some(board) => Box::into_raw(Box::new(*board)),
+        None => null_mut(),
+    }
+}
+
+/// Releases a board allocated by this module. Passing a null pointer is a no-op.
+///
+/// # Safety
+///
+/// `board` must be null or a pointer returned by this module and not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn laura_board_free(board: *mut Board) {
+    if !board.is_null() {
+        drop(unsafe { Box::from_raw(board) });
+    }
+}
+
+/// Generates the legal moves of a board into a caller buffer of packed 16-bit moves.
+///
+/// Writes at most `capacity` moves and returns the total number of legal moves, so a return
+/// value larger than `capacity` means the buffer was too small. Returns 0 if `board` is null.
+///
+/// # Safety
+///
+/// `board` must be null or a valid board pointer, and `moves` must be valid for writes of
+/// `capacity` `u16` values (it may be null when `capacity` is 0).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn laura_board_gen_moves(
+    board: *const Board,
+    moves: *mut u16,
+    capacity: usize,
+) -> usize {
+    let Some(board) = (unsafe { board.as_ref() }) else {
+        return 0;
+    };
+    let move_list: MoveList = gen_moves::<AllMoves>(board);
+    for (index, mv) in move_list.iter().take(capacity).enumerate() {
+        unsafe { moves.add(index).write(mv.0) };
+    }
+    move_list.len()
+}
+
+/// Plays a packed 16-bit move on a board in place.
+///
+/// Returns `false`, leaving the board untouched, if `board` is null or the move is not legal.
+///
+/// # Safety
+///
+/// `board` must be null or a valid board pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn laura_board_make_move(board: *mut Board, mv: u16) -> bool {
+    let Some(board) = (unsafe { board.as_mut() }) else {
+        return false;
+    };
+    let mv: Move = Move(mv);
+    if !gen_moves::<AllMoves>(board).contains(&mv) {
+        return false;
+    }
+    *board = board.make_move(mv);
+    true
+}
+
+/// Counts the leaf nodes of the legal move tree of a board up to `depth`.
+///
+/// Returns 0 if `board` is null.
+///
+/// # Safety
+///
+/// `board` must be null or a valid board pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn laura_board_perft(board: *const Board, depth: u32) -> u64 {
+    match unsafe { board.as_ref() } {
+        Some(board) => perft(board, depth as usize),
+        None => 0,
+    }
+}
@@ -17,7 +17,7 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
-use crate::BitBoard;
+use crate::{BitBoard, Rank, Square};
 use core::fmt;
 use core::mem::transmute;
 
@@ -125,6 +125,22 @@ impl File {
         FILE_BITBOARDS[self.to_index()]
     }
 
+    /// Gets the Bitboard of the files directly adjacent to this one, without wrapping
+    /// (file A only has file B as a neighbour, file H only has file G).
+    #[inline(always)]
+    pub const fn adjacent(self) -> BitBoard {
+        let file: BitBoard = self.to_bitboard();
+        BitBoard(((file.0 & !BitBoard::FILE_A.0) >> 1) | ((file.0 & !BitBoard::FILE_H.0) << 1))
+    }
+
+    /// Returns an iterator over the squares of the file, from rank 1 to rank 8.
+    #[inline]
+    pub fn squares(self) -> impl Iterator<Item = Square> {
+        Rank::ALL
+            .into_iter()
+            .map(move |rank| Square::from_file_rank(self, rank))
+    }
+
     /// Returns the corresponding character for the `File`.
     #[inline(always)]
     pub const fn to_char(&self) -> char {
@@ -0,0 +1,50 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2025 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::Square;
+
+/// Precomputed Chebyshev distances between every pair of squares on the chessboard.
+/// Each entry holds the number of king moves needed to go from one square to the other.
+static SQUARE_DISTANCE: [[u8; Square::NUM_SQUARES]; Square::NUM_SQUARES] = {
+    let mut table: [[u8; Square::NUM_SQUARES]; Square::NUM_SQUARES] =
+        [[0; Square::NUM_SQUARES]; Square::NUM_SQUARES];
+    let mut src: usize = 0;
+    while src < Square::NUM_SQUARES {
+        let mut dest: usize = 0;
+        while dest < Square::NUM_SQUARES {
+            table[src][dest] = Square::from_index(src).chebyshev_distance(Square::from_index(dest));
+            dest += 1;
+        }
+        src += 1;
+    }
+    table
+};
+
+/// Retrieves the precomputed Chebyshev distance between two squares.
+///
+/// Equivalent to [`Square::chebyshev_distance`], but served from a lookup table,
+/// which is handy for king-tropism and endgame heuristics evaluated in hot loops.
+#[inline(always)]
+pub fn get_distance(src: Square, dest: Square) -> u8 {
+    unsafe {
+        *SQUARE_DISTANCE
+            .get_unchecked(src.to_index())
+            .get_unchecked(dest.to_index())
+    }
+}
@@ -19,7 +19,8 @@
 
 use core::mem::transmute;
 
-use crate::{BitBoard, File, Rank, Square};
+use super::masks::{MaskTable, mask_table};
+use crate::{BitBoard, Color, File, Rank, Square};
 
 /// The possible relative moves a king can make on a chessboard.
 /// Each tuple represents the change in rank (row) and file (column) for each direction the king can move.
@@ -35,6 +36,14 @@ const KING_DELTAS: [(i8, i8); 8] = [
     (1, 1),
 ];
 
+/// The king zones: the king ring (the king square and its neighbours) extended by one
+/// more rank towards the opponent's side.
+static KING_ZONE_MASKS: MaskTable = mask_table((0, 1), (-1, 2));
+
+/// The pawn-shield masks: the squares one and two ranks in front of a king
+/// on its own and adjacent files.
+static PAWN_SHIELD_MASKS: MaskTable = mask_table((0, 1), (1, 2));
+
 /// Precomputed bitboards representing the possible king attacks from every square on the chessboard.
 /// Each element in the array corresponds to one square on the board (indexed by `Square`),
 /// and the bitboard marks all squares that are attacked by a king from that square.
@@ -115,6 +124,34 @@ pub fn get_king_attacks(square: Square) -> BitBoard {
     unsafe { *KING_ATTACKS.get_unchecked(square.to_index()) }
 }
 
+/// Retrieves the king zone for a king of the given [`Color`] on a specific square.
+///
+/// The zone holds the king square, its surrounding ring, and the three squares one rank further
+/// towards the opponent, which is the usual area counted by king-safety evaluation.
+#[inline(always)]
+pub fn king_zone(color: Color, square: Square) -> BitBoard {
+    unsafe {
+        BitBoard(
+            *KING_ZONE_MASKS
+                .get_unchecked(color as usize)
+                .get_unchecked(square.to_index()),
+        )
+    }
+}
+
+/// Retrieves the pawn-shield mask for a king of the given [`Color`] on a specific square:
+/// the squares one and two ranks in front of the king on its own and adjacent files.
+#[inline(always)]
+pub fn pawn_shield_mask(color: Color, king_square: Square) -> BitBoard {
+    unsafe {
+        BitBoard(
+            *PAWN_SHIELD_MASKS
+                .get_unchecked(color as usize)
+                .get_unchecked(king_square.to_index()),
+        )
+    }
+}
+
 /// Generates the bitboard representing all the squares a king can attack from the given square.
 ///
 /// The function computes the king's potential moves by iterating through the possible relative moves
@@ -0,0 +1,139 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2025 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{BitBoard, Color, Square};
+
+/// A table of color-indexed masks, indexed by color (0 for White, 1 for Black) and square.
+pub(crate) type MaskTable = [[u64; Square::NUM_SQUARES]; 2];
+
+/// Builds a [`MaskTable`] holding, for every color and square, the target squares whose file
+/// distance lies in `files` and whose rank offset lies in `ranks`.
+///
+/// Rank offsets are oriented from the color's perspective, so they grow towards the opponent's
+/// side of the board for both colors.
+pub(crate) const fn mask_table(files: (i8, i8), ranks: (i8, i8)) -> MaskTable {
+    let mut table: MaskTable = [[0; Square::NUM_SQUARES]; 2];
+    let mut color: usize = 0;
+    while color < 2 {
+        let mut origin: usize = 0;
+        while origin < Square::NUM_SQUARES {
+            let mut mask: u64 = 0;
+            let mut target: usize = 0;
+            while target < Square::NUM_SQUARES {
+                let file_distance: i8 = ((target % 8) as i8 - (origin % 8) as i8).abs();
+                let mut rank_offset: i8 = (target / 8) as i8 - (origin / 8) as i8;
+                if color == 1 {
+                    rank_offset = -rank_offset;
+                }
+                if file_distance >= files.0
+                    && file_distance <= files.1
+                    && rank_offset >= ranks.0
+                    && rank_offset <= ranks.1
+                {
+                    mask |= 1 << target;
+                }
+                target += 1;
+            }
+            table[color][origin] = mask;
+            origin += 1;
+        }
+        color += 1;
+    }
+    table
+}
+
+/// The front spans: the squares on the same file strictly in front of a square.
+static FRONT_SPAN_MASKS: MaskTable = mask_table((0, 0), (1, 7));
+
+/// The rear spans: the squares on the same file strictly behind a square.
+static REAR_SPAN_MASKS: MaskTable = mask_table((0, 0), (-7, -1));
+
+/// The passed-pawn masks: the squares in front of a pawn on its own and adjacent files,
+/// which must hold no enemy pawns for the pawn to be passed.
+static PASSED_PAWN_MASKS: MaskTable = mask_table((0, 1), (1, 7));
+
+/// The outpost masks: the squares on the adjacent files in front of a square, from which
+/// enemy pawns could ever advance to attack it.
+static OUTPOST_MASKS: MaskTable = mask_table((1, 1), (1, 7));
+
+/// Retrieves the squares on the same file strictly in front of the given square,
+/// from the perspective of the given [`Color`].
+#[inline(always)]
+pub fn front_span(color: Color, square: Square) -> BitBoard {
+    unsafe {
+        BitBoard(
+            *FRONT_SPAN_MASKS
+                .get_unchecked(color as usize)
+                .get_unchecked(square.to_index()),
+        )
+    }
+}
+
+/// Retrieves the squares on the same file strictly behind the given square,
+/// from the perspective of the given [`Color`].
+#[inline(always)]
+pub fn rear_span(color: Color, square: Square) -> BitBoard {
+    unsafe {
+        BitBoard(
+            *REAR_SPAN_MASKS
+                .get_unchecked(color as usize)
+                .get_unchecked(square.to_index()),
+        )
+    }
+}
+
+/// Retrieves the squares in front of a pawn of the given [`Color`] on its own and adjacent files.
+///
+/// A pawn is passed when this mask holds no enemy pawns.
+#[inline(always)]
+pub fn passed_pawn_mask(color: Color, square: Square) -> BitBoard {
+    unsafe {
+        BitBoard(
+            *PASSED_PAWN_MASKS
+                .get_unchecked(color as usize)
+                .get_unchecked(square.to_index()),
+        )
+    }
+}
+
+/// Retrieves the squares on the adjacent files in front of the given square, from the perspective
+/// of the given [`Color`], from which enemy pawns could ever attack it.
+///
+/// A square is an outpost when this mask holds no enemy pawns, so knight and bishop outposts
+/// can be tested with a single AND.
+#[inline(always)]
+pub fn outpost_mask(color: Color, square: Square) -> BitBoard {
+    unsafe {
+        BitBoard(
+            *OUTPOST_MASKS
+                .get_unchecked(color as usize)
+                .get_unchecked(square.to_index()),
+        )
+    }
+}
+
+/// Retrieves the squares a pawn of the given [`Color`] on the given square could ever attack
+/// while advancing: the squares in front of it on the adjacent files.
+///
+/// This is the same set as [`outpost_mask`], seen from the attacking pawn instead of the
+/// attacked square.
+#[inline(always)]
+pub fn pawn_attack_span(color: Color, square: Square) -> BitBoard {
+    outpost_mask(color, square)
+}
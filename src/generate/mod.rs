@@ -18,11 +18,47 @@
 */
 #![allow(dead_code)]
 
-#[cfg(not(feature = "bmi2"))]
+#[cfg(not(any(
+    feature = "small-tables",
+    feature = "no-tables",
+    all(feature = "bmi2", target_arch = "x86_64", target_feature = "bmi2")
+)))]
 pub mod black_magics;
+pub mod distance;
+#[cfg(all(feature = "small-tables", not(feature = "no-tables")))]
+pub mod kindergarten;
 pub mod king;
 pub mod knight;
+#[cfg(feature = "no-tables")]
+pub mod kogge_stone;
+pub mod masks;
 pub mod pawn;
-#[cfg(feature = "bmi2")]
+#[cfg(all(
+    feature = "bmi2",
+    target_arch = "x86_64",
+    target_feature = "bmi2",
+    not(any(feature = "small-tables", feature = "no-tables"))
+))]
 pub mod pext;
 pub mod rays;
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod sliders;
+
+// Enabling `bmi2` on a target without BMI2 support falls back to the black magic backend, so
+// downstream crates enabling the feature unconditionally still build on ARM and wasm. The use of
+// a deprecated item surfaces the fallback as a compiler warning. `small-tables` and `no-tables`
+// take precedence over `bmi2` on every target, so there is no fallback to report with them.
+#[cfg(all(
+    feature = "bmi2",
+    not(all(target_arch = "x86_64", target_feature = "bmi2")),
+    not(any(feature = "small-tables", feature = "no-tables"))
+))]
+mod bmi2_fallback {
+    #[deprecated(
+        note = "the `bmi2` feature requires an x86_64 target with BMI2 enabled (e.g. RUSTFLAGS=\"-C target-cpu=native\"); falling back to black magic bitboards"
+    )]
+    const BMI2_UNSUPPORTED: () = ();
+
+    const _: () = BMI2_UNSUPPORTED;
+}
@@ -17,17 +17,13 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
+use super::sliders::{BISHOP_SLIDER, ROOK_SLIDER, Slider};
 use crate::{BitBoard, Square};
 
 // This implementation of PEXT bitboards is based on the work developed in Cozy-Chess, licensed under the MIT License.
 // Copyright (c) 2021 analog-hors
 // Source: https://github.com/analog-hors/cozy-chess/blob/master/types/src/sliders/pext.rs
 
-// Includes pre-generated files containing the slider attack bitboards and the PEXT (Parallel Bit Extraction)
-// data. These files are created at build time and are dynamically included into the current module at compile-time.
-include!(concat!(env!("OUT_DIR"), "/sliders_attacks.rs"));
-include!(concat!(env!("OUT_DIR"), "/pext_data.rs"));
-
 /// Executes the PEXT (Parallel Bit Extraction) operation on two 64-bit integers. This function uses the x86_64
 /// architecture's intrinsic to perform the PEXT operation, which extracts specific bits from one integer according
 /// to a mask and returns them in the result. This is used to efficiently compute attack bitboards based on blockers.
@@ -40,6 +36,7 @@ fn pext(a: u64, mask: u64) -> u64 {
 
 /// Represents a single PEXT entry for a slider piece's attack data. This structure is used to store the necessary
 /// information for performing a PEXT operation to compute the attack bitboard of a slider piece (rook or bishop).
+#[derive(Clone, Copy)]
 struct PextEntry {
     offset: usize,
     mask: BitBoard,
@@ -50,13 +47,87 @@ struct PextEntry {
 ///
 /// The data is divided into two arrays, one for rooks and one for bishops, with each entry containing an `offset` and `mask`.
 /// Additionally, the total size of the table is stored.
-#[allow(dead_code)]
 struct PextIndexData {
     rook_data: [PextEntry; Square::NUM_SQUARES],
     bishop_data: [PextEntry; Square::NUM_SQUARES],
     table_size: usize,
 }
 
+/// The total size of the attack table shared by rooks and bishops.
+const TABLE_SIZE: usize = 107648;
+
+/// Computes the PEXT entries of a slider for every square, laying out its attack sets one after
+/// another from the given offset, and returns them together with the offset that follows them.
+const fn pext_entries(
+    slider: Slider,
+    mut offset: usize,
+) -> ([PextEntry; Square::NUM_SQUARES], usize) {
+    let mut entries: [PextEntry; Square::NUM_SQUARES] = [PextEntry {
+        offset: 0,
+        mask: BitBoard::EMPTY,
+    }; Square::NUM_SQUARES];
+    let mut square: usize = 0;
+    while square < Square::NUM_SQUARES {
+        let mask: u64 = slider.relevant_blockers(square);
+        entries[square] = PextEntry {
+            offset,
+            mask: BitBoard(mask),
+        };
+        offset += 1 << mask.count_ones();
+        square += 1;
+    }
+    (entries, offset)
+}
+
+/// The PEXT data for rooks and bishops, evaluated at compile time. Rook attacks come first in
+/// the attack table, followed by bishop attacks.
+const PEXT_DATA: &PextIndexData = &{
+    let (rook_data, rook_end) = pext_entries(ROOK_SLIDER, 0);
+    let (bishop_data, table_size) = pext_entries(BISHOP_SLIDER, rook_end);
+    assert!(table_size == TABLE_SIZE);
+    PextIndexData {
+        rook_data,
+        bishop_data,
+        table_size,
+    }
+};
+
+/// Stores the moves of a slider from every square for every subset of its relevant blockers.
+///
+/// Subsets are enumerated in increasing order with the Carry-Rippler trick, which is exactly the
+/// order of their PEXT indices, so no PEXT instruction is needed at compile time.
+const fn fill_attacks(
+    table: &mut [u64; TABLE_SIZE],
+    entries: &[PextEntry; Square::NUM_SQUARES],
+    slider: Slider,
+) {
+    let mut square: usize = 0;
+    while square < Square::NUM_SQUARES {
+        let entry: &PextEntry = &entries[square];
+        let mut index: usize = entry.offset;
+        let mut blockers: u64 = 0;
+        loop {
+            table[index] = slider.moves(square, blockers);
+            index += 1;
+            blockers = blockers.wrapping_sub(entry.mask.0) & entry.mask.0;
+            if blockers == 0 {
+                break;
+            }
+        }
+        square += 1;
+    }
+}
+
+/// The slider attack bitboards for every square and blocker configuration of rooks and bishops,
+/// evaluated at compile time and indexed through the PEXT data.
+#[allow(long_running_const_eval)]
+static SLIDER_ATTACKS: [u64; TABLE_SIZE] = {
+    let mut table: [u64; TABLE_SIZE] = [0; TABLE_SIZE];
+    fill_attacks(&mut table, &PEXT_DATA.rook_data, ROOK_SLIDER);
+    fill_attacks(&mut table, &PEXT_DATA.bishop_data, BISHOP_SLIDER);
+    table
+};
+
 /// Computes the index of the attack bitboard for a slider piece (rook or bishop) based on the blocker positions
 /// using the PEXT data.
 #[inline]
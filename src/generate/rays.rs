@@ -17,12 +17,99 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
-use crate::{BitBoard, Square};
+use crate::{BitBoard, Direction, Square};
 
-// Include precomputed table of between Bitboards for rooks, bishops and queens.
-// These tables are generated during the build process and stored in
-// the specified output directory.
-include!(concat!(env!("OUT_DIR"), "/between_array.rs"));
+/// Returns the unit step `(rank_delta, file_delta)` leading from `start` towards `end` if both
+/// squares lie on a common rank, file or diagonal, or `None` if they are not aligned or are the same.
+const fn alignment_step(start: usize, end: usize) -> Option<(i8, i8)> {
+    let rank_delta: i8 = (end / 8) as i8 - (start / 8) as i8;
+    let file_delta: i8 = (end % 8) as i8 - (start % 8) as i8;
+    if start == end || (rank_delta != 0 && file_delta != 0 && rank_delta.abs() != file_delta.abs())
+    {
+        return None;
+    }
+    Some((rank_delta.signum(), file_delta.signum()))
+}
+
+/// Precomputed table of the squares between any two aligned squares, indexed by source and
+/// destination square. Each entry excludes the source square and includes the destination.
+static BETWEEN_ARRAY: [[u64; Square::NUM_SQUARES]; Square::NUM_SQUARES] = {
+    let mut table: [[u64; Square::NUM_SQUARES]; Square::NUM_SQUARES] =
+        [[0; Square::NUM_SQUARES]; Square::NUM_SQUARES];
+    let mut start: usize = 0;
+    while start < Square::NUM_SQUARES {
+        let mut end: usize = 0;
+        while end < Square::NUM_SQUARES {
+            if let Some((dr, df)) = alignment_step(start, end) {
+                let step: i8 = dr * 8 + df;
+                let mut square: i8 = start as i8 + step;
+                let mut between: u64 = 1 << end;
+                while square != end as i8 {
+                    between |= 1 << square;
+                    square += step;
+                }
+                table[start][end] = between;
+            }
+            end += 1;
+        }
+        start += 1;
+    }
+    table
+};
+
+/// Precomputed table of the full lines crossing any two aligned squares, indexed by source and
+/// destination square. Each entry runs from edge to edge and includes both squares.
+static LINE_ARRAY: [[u64; Square::NUM_SQUARES]; Square::NUM_SQUARES] = {
+    let mut table: [[u64; Square::NUM_SQUARES]; Square::NUM_SQUARES] =
+        [[0; Square::NUM_SQUARES]; Square::NUM_SQUARES];
+    let mut start: usize = 0;
+    while start < Square::NUM_SQUARES {
+        let mut end: usize = 0;
+        while end < Square::NUM_SQUARES {
+            if let Some((dr, df)) = alignment_step(start, end) {
+                let mut line: u64 = 1 << start;
+                let mut sign: i8 = -1;
+                while sign <= 1 {
+                    let mut rank: i8 = (start / 8) as i8 + sign * dr;
+                    let mut file: i8 = (start % 8) as i8 + sign * df;
+                    while rank >= 0 && rank < 8 && file >= 0 && file < 8 {
+                        line |= 1 << (rank * 8 + file);
+                        rank += sign * dr;
+                        file += sign * df;
+                    }
+                    sign += 2;
+                }
+                table[start][end] = line;
+            }
+            end += 1;
+        }
+        start += 1;
+    }
+    table
+};
+
+/// Precomputed rays in each [`Direction`], indexed by direction and square.
+/// Each ray starts next to the origin square and runs to the edge of the board.
+static DIRECTION_RAYS: [[BitBoard; Square::NUM_SQUARES]; Direction::NUM_DIRECTIONS] = {
+    let mut table: [[BitBoard; Square::NUM_SQUARES]; Direction::NUM_DIRECTIONS] =
+        [[BitBoard::EMPTY; Square::NUM_SQUARES]; Direction::NUM_DIRECTIONS];
+    let mut direction: usize = 0;
+    while direction < Direction::NUM_DIRECTIONS {
+        let mut square: usize = 0;
+        while square < Square::NUM_SQUARES {
+            let mut ray: BitBoard = BitBoard::EMPTY;
+            let mut step: BitBoard = BitBoard(1 << square).shift(Direction::from_index(direction));
+            while !step.is_empty() {
+                ray = BitBoard(ray.0 | step.0);
+                step = step.shift(Direction::from_index(direction));
+            }
+            table[direction][square] = ray;
+            square += 1;
+        }
+        direction += 1;
+    }
+    table
+};
 
 /// Precomputed rays for bishops, indexed by square.
 /// This constant holds the BitBoards representing the rays a bishop can attack from each square.
@@ -175,6 +262,41 @@ pub fn get_between(src: Square, dest: Square) -> BitBoard {
     }
 }
 
+/// Retrieves the BitBoard representing the full line (rank, file, or diagonal) crossing the
+/// source and destination squares, edge to edge and including both squares.
+///
+/// Returns an empty BitBoard if the squares are not aligned or are the same.
+#[inline]
+pub fn get_line(src: Square, dest: Square) -> BitBoard {
+    unsafe {
+        BitBoard(
+            *LINE_ARRAY
+                .get_unchecked(src as usize)
+                .get_unchecked(dest as usize),
+        )
+    }
+}
+
+/// Returns `true` if the three squares lie on the same rank, file, or diagonal.
+///
+/// Built on the precomputed line table, so it is a single lookup and bit test. Useful to check
+/// whether a pinned piece moving from `a` to `b` stays on the pin ray towards the king on `c`.
+#[inline(always)]
+pub fn aligned(a: Square, b: Square, c: Square) -> bool {
+    get_line(a, b).get_square(c)
+}
+
+/// Retrieves the BitBoard representing the ray leaving a square in the given [`Direction`],
+/// up to the edge of the board and excluding the square itself.
+#[inline(always)]
+pub fn get_ray(square: Square, direction: Direction) -> BitBoard {
+    unsafe {
+        *DIRECTION_RAYS
+            .get_unchecked(direction.to_index())
+            .get_unchecked(square.to_index())
+    }
+}
+
 /// Retrieves the BitBoard representing the rays a bishop can attack from a given square.
 #[inline(always)]
 pub fn get_bishop_rays(square: Square) -> BitBoard {
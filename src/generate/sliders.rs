@@ -17,15 +17,14 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::mem::transmute;
-
-use super::types::{BitBoard, File, Rank, Square};
-
 /// Represents a sliding piece (like a rook or bishop) on a chessboard, which moves
 /// in specific directions defined by a set of delta pairs. Each delta pair defines
 /// a direction in which the piece can move.
+///
+/// Every method is a `const fn`, so the slider attack tables of the magic and PEXT backends
+/// are evaluated entirely at compile time.
 #[derive(Clone, Copy, Debug)]
-pub struct Slider {
+pub(crate) struct Slider {
     /// Array of `(rank_delta, file_delta)` tuples indicating directions for sliding moves.
     deltas: [(i8, i8); 4],
 }
@@ -37,30 +36,24 @@ impl Slider {
     /// This method iterates through each possible direction defined by `deltas`, and
     /// adds each reachable square along that direction to the resulting moves until
     /// a blocker is encountered or the board edge is reached.
-    pub fn moves(&self, square: Square, blockers: BitBoard) -> BitBoard {
-        let mut moves: BitBoard = BitBoard::EMPTY;
-        let rank: i8 = square.rank() as i8;
-        let file: i8 = square.file() as i8;
-
-        for &(dr, df) in &self.deltas {
-            let mut new_rank: i8 = rank + dr;
-            let mut new_file: i8 = file + df;
-
-            while (0..8).contains(&new_rank) && (0..8).contains(&new_file) {
-                let new_square: Square = Square::from_file_rank(
-                    unsafe { transmute::<u8, File>(new_file as u8) },
-                    unsafe { transmute::<u8, Rank>(new_rank as u8) },
-                );
-                let target_bitboard: BitBoard = new_square.to_bitboard();
-                moves |= target_bitboard;
-
-                if target_bitboard & blockers != BitBoard::EMPTY {
+    pub(crate) const fn moves(&self, square: usize, blockers: u64) -> u64 {
+        let mut moves: u64 = 0;
+        let mut direction: usize = 0;
+        while direction < self.deltas.len() {
+            let (dr, df) = self.deltas[direction];
+            let mut rank: i8 = (square / 8) as i8 + dr;
+            let mut file: i8 = (square % 8) as i8 + df;
+
+            while on_board(rank, file) {
+                let target: u64 = 1 << (rank * 8 + file);
+                moves |= target;
+                if target & blockers != 0 {
                     break;
                 }
-
-                new_rank += dr;
-                new_file += df;
+                rank += dr;
+                file += df;
             }
+            direction += 1;
         }
 
         moves
@@ -68,44 +61,38 @@ impl Slider {
 
     /// Generates a bitboard with all relevant blockers for move generation in each direction
     /// of the slider from the starting square, omitting blockers beyond the edge of the board.
-    pub fn relevant_blockers(&self, square: Square) -> BitBoard {
-        let mut blockers: BitBoard = BitBoard::EMPTY;
-        let rank: i8 = square.rank() as i8;
-        let file: i8 = square.file() as i8;
-
-        for &(dr, df) in &self.deltas {
-            let mut new_rank: i8 = rank + dr;
-            let mut new_file: i8 = file + df;
-
-            while (0..8).contains(&new_rank) && (0..8).contains(&new_file) {
-                let new_square: Square = Square::from_file_rank(
-                    unsafe { transmute::<u8, File>(new_file as u8) },
-                    unsafe { transmute::<u8, Rank>(new_rank as u8) },
-                );
-
-                let next_rank = new_rank + dr;
-                let next_file = new_file + df;
-                if !(0..8).contains(&next_rank) || !(0..8).contains(&next_file) {
-                    break;
-                }
-
-                blockers |= new_square.to_bitboard();
-
-                new_rank += dr;
-                new_file += df;
+    pub(crate) const fn relevant_blockers(&self, square: usize) -> u64 {
+        let mut blockers: u64 = 0;
+        let mut direction: usize = 0;
+        while direction < self.deltas.len() {
+            let (dr, df) = self.deltas[direction];
+            let mut rank: i8 = (square / 8) as i8 + dr;
+            let mut file: i8 = (square % 8) as i8 + df;
+
+            while on_board(rank + dr, file + df) {
+                blockers |= 1 << (rank * 8 + file);
+                rank += dr;
+                file += df;
             }
+            direction += 1;
         }
 
         blockers
     }
 }
 
+/// Returns `true` if the given rank and file both lie on the board.
+#[inline(always)]
+const fn on_board(rank: i8, file: i8) -> bool {
+    rank >= 0 && rank < 8 && file >= 0 && file < 8
+}
+
 /// Constant `Slider` instance representing a rook, which can move vertically or horizontally.
-pub const ROOK_SLIDER: Slider = Slider {
+pub(crate) const ROOK_SLIDER: Slider = Slider {
     deltas: [(1, 0), (0, -1), (-1, 0), (0, 1)],
 };
 
 /// Constant `Slider` instance representing a bishop, which can move diagonally.
-pub const BISHOP_SLIDER: Slider = Slider {
+pub(crate) const BISHOP_SLIDER: Slider = Slider {
     deltas: [(1, 1), (1, -1), (-1, -1), (-1, 1)],
 };
@@ -0,0 +1,60 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`heapless`] interop, enabled by the `heapless` feature.
+//!
+//! A [`Board`] can be written into a fixed-capacity [`heapless::String`], giving `no_std` users
+//! an owned FEN whose capacity is chosen by the caller.
+
+use heapless::String;
+
+use crate::{Board, FenError, MAX_FEN_LENGTH};
+
+impl Board {
+    /// Returns the board's FEN as a [`heapless::String`] of capacity `N`.
+    ///
+    /// A capacity of [`MAX_FEN_LENGTH`] holds the FEN of any board, which is what
+    /// [`Board::to_heapless_fen`] uses.
+    ///
+    /// # Errors
+    /// Returns [`FenError::BufferTooSmall`] with the length of the FEN if it exceeds `N` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let fen: heapless::String<64> = board.to_heapless_fen_with().unwrap();
+    /// assert_eq!(fen, "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+    /// assert!(board.to_heapless_fen_with::<32>().is_err());
+    /// ```
+    pub fn to_heapless_fen_with<const N: usize>(&self) -> Result<String<N>, FenError> {
+        let mut fen: String<N> = String::new();
+        self.write_fen(&mut fen)
+            .map_err(|_| FenError::BufferTooSmall(self.fen_len()))?;
+        Ok(fen)
+    }
+
+    /// Returns the board's FEN as a [`heapless::String`] large enough for any position.
+    #[inline]
+    pub fn to_heapless_fen(&self) -> String<MAX_FEN_LENGTH> {
+        // MAX_FEN_LENGTH bounds the FEN of every board.
+        self.to_heapless_fen_with()
+            .unwrap_or_else(|_| unreachable!("FEN longer than MAX_FEN_LENGTH"))
+    }
+}
@@ -0,0 +1,128 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{Board, Color, Move, PieceType, Square, san::check_suffix};
+use core::fmt;
+
+/// Converts a given move to its Long Algebraic Notation (LAN) representation.
+///
+/// LAN always names the source square, separates it from the destination with
+/// `-` or `x`, and keeps the SAN piece letters, promotion and check suffixes.
+///
+/// # Examples
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let board = Board::default();
+/// let mv = Move::new(Square::G1, Square::F3, MoveType::Quiet);
+///
+/// assert_eq!(board.to_lan(mv), "Ng1-f3");
+/// ```
+pub fn to_lan(mv: Move, board: &Board) -> LanBuffered<'_> {
+    LanBuffered { mv, board }
+}
+
+/// A wrapper that holds a move and a borrow of the corresponding board state for LAN rendering.
+///
+/// Like [`SanBuffered`](crate::SanBuffered), it implements `Display` and `PartialEq<&str>`
+/// to easily print or compare the LAN representation.
+///
+/// # Examples
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let board = Board::default();
+/// let mv = Move::new(Square::E2, Square::E4, MoveType::DoublePawn);
+/// let lan = to_lan(mv, &board);
+///
+/// println!("{}", lan); // Outputs: "e2-e4"
+/// assert_eq!(lan, "e2-e4");
+/// ```
+#[derive(Debug)]
+pub struct LanBuffered<'a> {
+    mv: Move,
+    board: &'a Board,
+}
+
+impl PartialEq<&str> for LanBuffered<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        let mut buffer: [u8; 16] = [0u8; 16];
+        let lan_str: &str = self.render_lan(&mut buffer);
+        lan_str == *other
+    }
+}
+
+impl fmt::Display for LanBuffered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer: [u8; 16] = [0u8; 16];
+        let lan_str: &str = self.render_lan(&mut buffer);
+        write!(f, "{}", lan_str)
+    }
+}
+
+impl LanBuffered<'_> {
+    /// Renders the move in Long Algebraic Notation (LAN) and writes it into the provided buffer.
+    fn render_lan<'a>(&self, buffer: &'a mut [u8; 16]) -> &'a str {
+        let mut idx: usize = 0;
+
+        let new_board: Board = self.board.make_move(self.mv);
+        let src: Square = self.mv.get_src();
+        let dest: Square = self.mv.get_dest();
+        let piece_type: PieceType = self.board.piece_on(src).unwrap().piece_type();
+
+        if self.mv.is_castle() {
+            let castle: &[u8] = if self.mv.is_king_castle() {
+                b"O-O"
+            } else {
+                b"O-O-O"
+            };
+            buffer[idx..idx + castle.len()].copy_from_slice(castle);
+            idx += castle.len();
+        } else {
+            if piece_type != PieceType::Pawn {
+                buffer[idx] = piece_type.to_char() as u8;
+                idx += 1;
+            }
+
+            buffer[idx..idx + 2].copy_from_slice(src.to_str().as_bytes());
+            idx += 2;
+
+            buffer[idx] = if self.mv.is_capture() { b'x' } else { b'-' };
+            idx += 1;
+
+            buffer[idx..idx + 2].copy_from_slice(dest.to_str().as_bytes());
+            idx += 2;
+
+            if self.mv.is_promotion() {
+                buffer[idx] = b'=';
+                idx += 1;
+                buffer[idx] = self.mv.get_prom(Color::White).piece_type().to_char() as u8;
+                idx += 1;
+            }
+        }
+
+        let suffix: &[u8] = check_suffix(&new_board);
+        buffer[idx..idx + suffix.len()].copy_from_slice(suffix);
+        idx += suffix.len();
+
+        unsafe { core::str::from_utf8_unchecked(&buffer[..idx]) }
+    }
+}
@@ -20,38 +20,111 @@
 #![deny(missing_docs)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+// Linking `std` provides the panic handler a standalone `cdylib` or `staticlib` build needs.
+#[cfg(feature = "ffi")]
+extern crate std;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
 mod bitboard;
 mod board;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
 mod castle_rights;
+#[cfg(feature = "chess")]
+mod chess_impls;
 mod color;
+#[cfg(feature = "cozy-chess")]
+mod cozy_chess_impls;
+mod delta;
+mod diagonal;
+mod direction;
 mod errors;
+mod ext_move;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod file;
 mod generate;
+#[cfg(feature = "heapless")]
+mod heapless_impls;
+mod lan;
 mod macros;
 mod move_list;
 mod moves;
+mod perft;
 mod piece;
+mod polyglot;
+#[cfg(feature = "proptest")]
+mod proptest_strategies;
 mod rank;
 mod san;
+mod scored_move_list;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "shakmaty")]
+mod shakmaty_impls;
 mod square;
+#[cfg(feature = "variants")]
+mod variants;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 mod zobrist;
 
 pub use bitboard::*;
 pub use board::board::*;
+pub use board::builder::*;
+#[cfg(feature = "nnue")]
+pub use board::bullet::*;
+pub use board::listener::*;
 pub use board::movegen::*;
+#[cfg(feature = "nnue")]
+pub use board::nnue::*;
+pub use board::packed::*;
+pub use board::piece_map::*;
+pub use board::playout::*;
+#[cfg(feature = "nnue")]
+pub use board::sfen::*;
 pub use castle_rights::*;
 pub use color::*;
+pub use delta::*;
+pub use diagonal::*;
+pub use direction::*;
 pub use errors::*;
+pub use ext_move::*;
 pub use file::*;
-#[cfg(not(feature = "bmi2"))]
+#[cfg(not(any(
+    feature = "small-tables",
+    feature = "no-tables",
+    all(feature = "bmi2", target_arch = "x86_64", target_feature = "bmi2")
+)))]
 pub use generate::black_magics::*;
-#[cfg(feature = "bmi2")]
+#[cfg(all(feature = "small-tables", not(feature = "no-tables")))]
+pub use generate::kindergarten::*;
+#[cfg(feature = "no-tables")]
+pub use generate::kogge_stone::*;
+#[cfg(all(
+    feature = "bmi2",
+    target_arch = "x86_64",
+    target_feature = "bmi2",
+    not(any(feature = "small-tables", feature = "no-tables"))
+))]
 pub use generate::pext::*;
-pub use generate::{king::*, knight::*, pawn::*, rays::*};
+#[cfg(feature = "simd")]
+pub use generate::simd::*;
+pub use generate::{distance::*, king::*, knight::*, masks::*, pawn::*, rays::*};
+pub use lan::*;
 pub use move_list::*;
 pub use moves::*;
+pub use perft::*;
 pub use piece::*;
+#[cfg(feature = "proptest")]
+pub use proptest_strategies::*;
 pub use rank::*;
 pub use san::*;
+pub use scored_move_list::*;
 pub use square::*;
+#[cfg(feature = "variants")]
+pub use variants::{Variant, atomic::*, crazyhouse::*, horde::*, koth::*, three_check::*};
 pub use zobrist::*;
@@ -178,33 +178,30 @@ macro_rules! Call_Handler {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! Enumerate_Moves {
-    ($check:expr, $board:expr, $diagonal_pins:expr, $linear_pins:expr, $handler:expr) => {
-        enumerate_pawn_moves::<$check, M, F>(
+    ($check:expr, $board:expr, $ctx:expr, $rules:expr, $handler:expr) => {
+        enumerate_pawn_moves::<$check, COLOR, _, M, _>(
             $board,
             $board.allied_pawns(),
-            $diagonal_pins,
-            $linear_pins,
+            $ctx,
+            $rules,
             &mut $handler,
         );
-        enumerate_knight_moves::<$check, M, F>(
+        enumerate_knight_moves::<$check, M, _>(
             $board,
             $board.allied_knights(),
-            $diagonal_pins,
-            $linear_pins,
+            $ctx,
             &mut $handler,
         );
-        enumerate_bishop_moves::<$check, M, F>(
+        enumerate_bishop_moves::<$check, M, _>(
             $board,
             $board.allied_bishops() | $board.allied_queens(),
-            $diagonal_pins,
-            $linear_pins,
+            $ctx,
             &mut $handler,
         );
-        enumerate_rook_moves::<$check, M, F>(
+        enumerate_rook_moves::<$check, M, _>(
             $board,
             $board.allied_rooks() | $board.allied_queens(),
-            $diagonal_pins,
-            $linear_pins,
+            $ctx,
             &mut $handler,
         );
     };
@@ -17,6 +17,8 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::array::IntoIter;
 use core::fmt;
 use core::ops::{Deref, DerefMut};
@@ -24,11 +26,11 @@ use core::ops::{Deref, DerefMut};
 use crate::Move;
 
 #[cfg(target_pointer_width = "64")]
-const MAX_MOVES: usize = 252;
+pub(crate) const MAX_MOVES: usize = 252;
 #[cfg(target_pointer_width = "32")]
-const MAX_MOVES: usize = 254;
+pub(crate) const MAX_MOVES: usize = 254;
 #[cfg(target_pointer_width = "16")]
-const MAX_MOVES: usize = 255;
+pub(crate) const MAX_MOVES: usize = 255;
 
 // This implementation is based on the `MoveList` structure from Pleco,
 // an efficient chess library, licensed under the MIT License.
@@ -59,7 +61,10 @@ const MAX_MOVES: usize = 255;
 /// assert_eq!(move_list.len(), 1);
 /// assert_eq!(move_list[0], mv);
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Copy, Clone, Debug)]
+// `repr(C)` pins `len` after the move array, which the `bytemuck` impls rely on to validate
+// and read a list from raw bytes.
+#[repr(C)]
 pub struct MoveList {
     moves: [Move; MAX_MOVES],
     len: usize,
@@ -97,6 +102,65 @@ impl DerefMut for MoveList {
     }
 }
 
+impl FromIterator<Move> for MoveList {
+    /// Collects moves into a `MoveList`. Moves beyond the capacity are ignored, as with [`MoveList::push`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let board = Board::kiwipete();
+    /// let captures: MoveList = gen_moves::<AllMoves>(&board)
+    ///     .into_iter()
+    ///     .filter(|mv| mv.is_capture())
+    ///     .collect();
+    /// assert_eq!(captures.len(), 8);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Move>>(iter: I) -> Self {
+        let mut move_list: MoveList = MoveList::default();
+        move_list.extend(iter);
+        move_list
+    }
+}
+
+impl Extend<Move> for MoveList {
+    /// Appends moves to the `MoveList`. Moves beyond the capacity are ignored, as with [`MoveList::push`].
+    fn extend<I: IntoIterator<Item = Move>>(&mut self, iter: I) {
+        for mv in iter {
+            if self.len == MAX_MOVES {
+                break;
+            }
+            self.push(mv);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<MoveList> for Vec<Move> {
+    /// Copies the moves of a `MoveList` into a `Vec`.
+    fn from(move_list: MoveList) -> Self {
+        move_list.as_slice().to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&[Move]> for MoveList {
+    /// Copies moves into a `MoveList`. Moves beyond the capacity are ignored, as with [`MoveList::push`].
+    fn from(moves: &[Move]) -> Self {
+        moves.iter().copied().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<Move>> for MoveList {
+    /// Moves the contents of a `Vec` into a `MoveList`. Moves beyond the capacity are ignored,
+    /// as with [`MoveList::push`].
+    fn from(moves: Vec<Move>) -> Self {
+        moves.into_iter().collect()
+    }
+}
+
 impl Default for MoveList {
     /// Creates a new, empty `MoveList` with all moves initialized to `Move::null()`.
     ///
@@ -143,6 +207,26 @@ impl fmt::Display for MoveList {
     }
 }
 
+/// The moves of a [`MoveList`] in UCI notation separated by spaces, as in the `pv` field of a
+/// UCI `info` line, created by [`MoveList::display_uci`].
+#[derive(Copy, Clone, Debug)]
+pub struct UciMoves<'a> {
+    moves: &'a [Move],
+}
+
+impl fmt::Display for UciMoves<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf: [u8; 6] = [0u8; 6];
+        for (index, mv) in self.moves.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(mv.write_uci(&mut buf))?;
+        }
+        Ok(())
+    }
+}
+
 impl MoveList {
     /// Adds a [`Move`] to the `MoveList`.
     ///
@@ -237,6 +321,30 @@ impl MoveList {
         &self.moves[..self.len]
     }
 
+    /// Returns a [`Display`](fmt::Display) adapter writing the moves in UCI notation separated
+    /// by spaces, such as `e2e4 e7e5 g1f3`, for the `pv` field of a UCI `info` line.
+    ///
+    /// Castling is written as the king's destination square, like [`Move`]'s own `Display`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mut pv = MoveList::default();
+    /// pv.push(Move::new(Square::E2, Square::E4, MoveType::DoublePawn));
+    /// pv.push(Move::new(Square::E7, Square::E5, MoveType::DoublePawn));
+    /// pv.push(Move::new(Square::G1, Square::F3, MoveType::Quiet));
+    ///
+    /// assert_eq!(format!("info depth 3 pv {}", pv.display_uci()), "info depth 3 pv e2e4 e7e5 g1f3");
+    /// ```
+    #[inline]
+    pub fn display_uci(&self) -> UciMoves<'_> {
+        UciMoves {
+            moves: self.as_slice(),
+        }
+    }
+
     /// Returns a mutable slice containing the moves currently stored in the `MoveList`.
     ///
     /// Only the first `len` moves are included; unused slots beyond `len` are excluded.
@@ -352,4 +460,140 @@ impl MoveList {
     pub fn clear(&mut self) {
         self.len = 0;
     }
+
+    /// Shortens the `MoveList`, keeping the first `len` moves.
+    ///
+    /// Has no effect if `len` is greater than or equal to the current length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mut move_list = gen_moves::<AllMoves>(&Board::default());
+    /// move_list.truncate(5);
+    /// assert_eq!(move_list.len(), 5);
+    ///
+    /// move_list.truncate(10);
+    /// assert_eq!(move_list.len(), 5);
+    /// ```
+    #[inline(always)]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+        }
+    }
+
+    /// Removes the move at `index` and returns it, replacing it with the last move.
+    ///
+    /// This does not preserve the order of the list, but runs in constant time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mut move_list = MoveList::default();
+    /// let mv1 = Move::new(Square::E2, Square::E4, MoveType::DoublePawn);
+    /// let mv2 = Move::new(Square::D2, Square::D4, MoveType::DoublePawn);
+    /// let mv3 = Move::new(Square::G1, Square::F3, MoveType::Quiet);
+    /// move_list.extend([mv1, mv2, mv3]);
+    ///
+    /// assert_eq!(move_list.swap_remove(0), mv1);
+    /// assert_eq!(move_list.as_slice(), [mv3, mv2]);
+    /// ```
+    #[inline(always)]
+    pub fn swap_remove(&mut self, index: usize) -> Move {
+        assert!(
+            index < self.len,
+            "swap_remove index (is {index}) should be < len (is {})",
+            self.len
+        );
+        let mv: Move = self.moves[index];
+        self.len -= 1;
+        self.moves[index] = self.moves[self.len];
+        mv
+    }
+
+    /// Selects the best remaining move for a lazy selection sort.
+    ///
+    /// Finds the move with the highest score among the positions `start..len`, swaps it
+    /// (together with its score) into position `start` and returns it. Calling this with
+    /// `start = 0, 1, 2, ...` yields the moves in descending score order, while only paying
+    /// for the moves actually searched when a beta cutoff happens early.
+    ///
+    /// `scores[i]` is the score of the move at position `i`. Returns `None` once `start`
+    /// reaches the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scores` is shorter than the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let board = Board::kiwipete();
+    /// let mut moves = gen_moves::<AllMoves>(&board);
+    /// let mut scores: Vec<i32> = moves.iter().map(|mv| mv.is_capture() as i32).collect();
+    ///
+    /// let mut index = 0;
+    /// while let Some(mv) = moves.pick_best(&mut scores, index) {
+    ///     assert_eq!(mv.is_capture(), index < 8);
+    ///     index += 1;
+    /// }
+    /// assert_eq!(index, moves.len());
+    /// ```
+    pub fn pick_best(&mut self, scores: &mut [i32], start: usize) -> Option<Move> {
+        if start >= self.len {
+            return None;
+        }
+
+        let scores: &mut [i32] = &mut scores[..self.len];
+        let mut best: usize = start;
+        for index in start + 1..self.len {
+            if scores[index] > scores[best] {
+                best = index;
+            }
+        }
+
+        self.moves.swap(start, best);
+        scores.swap(start, best);
+        Some(self.moves[start])
+    }
+
+    /// Retains only the moves for which the predicate returns `true`, preserving their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let board = Board::kiwipete();
+    /// let mut move_list = gen_moves::<AllMoves>(&board);
+    /// let tt_move = board.find_move("e2a6").unwrap();
+    ///
+    /// move_list.retain(|mv| *mv != tt_move);
+    /// assert_eq!(move_list.len(), 47);
+    /// assert!(!move_list.contains(&tt_move));
+    /// ```
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&Move) -> bool,
+    {
+        let mut len: usize = 0;
+        for index in 0..self.len {
+            let mv: Move = self.moves[index];
+            if keep(&mv) {
+                self.moves[len] = mv;
+                len += 1;
+            }
+        }
+        self.len = len;
+    }
 }
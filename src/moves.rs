@@ -17,9 +17,10 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
-use crate::{Color, Piece, Square, piece::PROM_PIECES};
+use crate::{Color, Piece, PieceType, Square, UciMoveError, piece::PROM_PIECES};
 use core::fmt;
 use core::mem::transmute;
+use core::str::FromStr;
 
 /// Represents a single chess move as a compact 16-bit unsigned integer.
 ///
@@ -43,6 +44,7 @@ use core::mem::transmute;
 /// assert_eq!(mv.get_dest(), Square::E4);
 /// ```
 #[derive(Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Debug, Default, Hash)]
+#[repr(transparent)]
 pub struct Move(pub u16);
 
 /// Implements the `Display` trait for pretty-printing moves in algebraic notation.
@@ -52,6 +54,11 @@ pub struct Move(pub u16);
 /// and destination squares are displayed.
 impl fmt::Display for Move {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "variants")]
+        if self.is_drop() {
+            return write!(f, "{}@{}", self.get_drop_piece().to_char(), self.get_dest());
+        }
+
         if self.is_promotion() {
             write!(
                 f,
@@ -66,31 +73,53 @@ impl fmt::Display for Move {
     }
 }
 
-/// Allows comparing a `Move` against a string slice in algebraic notation.
+/// The UCI encoding of castling moves.
 ///
-/// This makes it easy to check if a move matches a specific string,  
-/// including handling promotion moves (e.g., "e7e8q").
-impl PartialEq<&str> for Move {
-    fn eq(&self, other: &&str) -> bool {
-        let mut move_str: [u8; 6] = [0u8; 6];
-        let mut pos: usize = 0;
-
-        let src: &str = self.get_src().to_str();
-        let dest: &str = self.get_dest().to_str();
+/// Standard chess GUIs send the king's destination square, while Chess960 GUIs such as
+/// Cute Chess and Banksia encode castling as the king capturing its own rook.
+/// [`Board::move_from_uci`](crate::Board::move_from_uci) accepts both forms.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Hash)]
+pub enum CastlingNotation {
+    /// The king's destination square, e.g. `e1g1`.
+    #[default]
+    KingDestination,
+
+    /// The castling rook's square, e.g. `e1h1`.
+    KingTakesRook,
+}
 
-        move_str[pos..pos + src.len()].copy_from_slice(src.as_bytes());
-        pos += src.len();
+/// A move formatted in UCI notation with a chosen [`CastlingNotation`],
+/// created by [`Board::to_uci`](crate::Board::to_uci).
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct UciMove {
+    mv: Move,
+    rook: Option<Square>,
+}
 
-        move_str[pos..pos + dest.len()].copy_from_slice(dest.as_bytes());
-        pos += dest.len();
+impl UciMove {
+    /// Creates the formatter; `rook` replaces the destination of a castling move.
+    pub(crate) const fn new(mv: Move, rook: Option<Square>) -> Self {
+        Self { mv, rook }
+    }
+}
 
-        if self.is_promotion() {
-            move_str[pos] = self.get_prom(Color::Black).to_char() as u8;
-            pos += 1;
+impl fmt::Display for UciMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rook {
+            Some(rook) => write!(f, "{}{}", self.mv.get_src(), rook),
+            None => write!(f, "{}", self.mv),
         }
+    }
+}
 
-        let move_as_str: &str = core::str::from_utf8(&move_str[..pos]).unwrap_or("");
-        move_as_str == *other
+/// Allows comparing a `Move` against a string slice in algebraic notation.
+///
+/// This makes it easy to check if a move matches a specific string,  
+/// including handling promotion moves (e.g., "e7e8q").
+impl PartialEq<&str> for Move {
+    fn eq(&self, other: &&str) -> bool {
+        let mut buf: [u8; 6] = [0u8; 6];
+        self.write_uci(&mut buf) == *other
     }
 }
 
@@ -100,12 +129,18 @@ const DEST_MASK: u16 = 0b00001111_11000000;
 const TYPE_MASK: u16 = 0b11110000_00000000;
 const PROM_MASK: u16 = 0b10000000_00000000;
 const CAP_MASK: u16 = 0b01000000_00000000;
+#[cfg(feature = "variants")]
+const DROP_TYPE: u16 = (MoveType::Drop as u16) << 12;
 
 /// Enum representing the different types of moves in chess, including promotions and special moves.
 ///
 /// <https://www.chessprogramming.org/Encoding_Moves>
+///
+/// The enum is `#[non_exhaustive]` because the `variants` feature adds [`MoveType::Drop`],
+/// so a `match` outside this crate needs a wildcard arm to compile with any feature set.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Hash)]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum MoveType {
     /// A standard, non-capturing move (e.g., moving a piece to an empty square).
     Quiet = 0b0000,
@@ -125,6 +160,12 @@ pub enum MoveType {
     /// En passant capture, a special pawn capture move.
     EnPassant = 0b0101,
 
+    /// A piece dropped from the pocket onto an empty square, as in crazyhouse.
+    ///
+    /// The source field of a drop holds the dropped [`PieceType`] instead of a square.
+    #[cfg(feature = "variants")]
+    Drop = 0b0111,
+
     /// Promotion to a Knight after a pawn reaches the last rank.
     PromotionKnight = 0b1000,
 
@@ -271,12 +312,36 @@ impl Move {
     ///
     /// This function uses `unsafe` and [`transmute`] to convert a 4-bit value into a [`MoveType`].  
     /// This is considered safe because the [`MoveType`] enum is expected to cover values 0 to 15,  
-    /// and the move encoding guarantees that only valid values are used.
+    /// and the move encoding guarantees that only valid values are used. Moves built from raw
+    /// bits must be checked with [`Move::has_valid_type`] first.
     #[inline(always)]
     pub const fn get_type(self) -> MoveType {
         unsafe { transmute((((self.0 & TYPE_MASK) >> 12) as u8) & 15) }
     }
 
+    /// Returns `true` if the type bits of the move hold an assigned [`MoveType`] code.
+    ///
+    /// Moves built with [`Move::new`] always do. A raw `u16` read from untrusted input must
+    /// be checked before calling [`Move::get_type`] on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// assert!(Move::new(Square::E2, Square::E4, MoveType::DoublePawn).has_valid_type());
+    /// assert!(!Move(0b0110 << 12).has_valid_type());
+    /// ```
+    #[inline(always)]
+    pub const fn has_valid_type(self) -> bool {
+        match self.flag() {
+            0b0110 => false,
+            #[cfg(not(feature = "variants"))]
+            0b0111 => false,
+            _ => true,
+        }
+    }
+
     /// Returns the promotion piece (if any) based on the color.
     ///
     /// If the move is a promotion, this function retrieves the promoted piece from the  
@@ -359,6 +424,11 @@ impl Move {
     /// ```
     #[inline(always)]
     pub const fn is_capture(self) -> bool {
+        #[cfg(feature = "variants")]
+        if self.0 & TYPE_MASK == DROP_TYPE {
+            // Drops are encoded as `0b0111`, which carries the capture bit.
+            return false;
+        }
         ((self.0 & CAP_MASK) >> 14) == 1
     }
 
@@ -456,6 +526,46 @@ impl Move {
         ((self.0 & TYPE_MASK) >> 12) == MoveType::EnPassant as u16
     }
 
+    /// Creates a drop of the given piece type onto `dest`, see [`MoveType::Drop`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mv = Move::new_drop(PieceType::Knight, Square::F3);
+    /// assert!(mv.is_drop());
+    /// assert!(!mv.is_capture());
+    /// assert_eq!(mv.get_drop_piece(), PieceType::Knight);
+    /// assert_eq!(mv.to_string(), "N@f3");
+    /// ```
+    #[cfg(feature = "variants")]
+    #[inline(always)]
+    pub const fn new_drop(piece_type: PieceType, dest: Square) -> Self {
+        Self(piece_type as u16 | ((dest as u16) << 6) | ((MoveType::Drop as u16) << 12))
+    }
+
+    /// Returns `true` if the move is a drop.
+    #[cfg(feature = "variants")]
+    #[inline(always)]
+    pub const fn is_drop(self) -> bool {
+        ((self.0 & TYPE_MASK) >> 12) == MoveType::Drop as u16
+    }
+
+    /// Returns the piece type dropped by a drop move.
+    ///
+    /// The result is only meaningful if [`Move::is_drop`] is `true`.
+    #[cfg(feature = "variants")]
+    #[inline(always)]
+    pub const fn get_drop_piece(self) -> PieceType {
+        let index: u8 = (self.0 & SRC_MASK) as u8;
+        if index < PieceType::KING as u8 {
+            unsafe { PieceType::from_index_unchecked(index) }
+        } else {
+            PieceType::Pawn
+        }
+    }
+
     /// Returns `true` if the move is a quiet move (no capture, promotion, castle or double pawn push).
     ///
     /// A quiet move is a standard, non-special move that simply moves a piece from its source square to the destination  
@@ -495,4 +605,79 @@ impl Move {
     pub const fn flag(self) -> u16 {
         self.0 >> 12
     }
+
+    /// Writes the move in UCI notation into `buf` and returns the written part as a string,
+    /// without allocating or going through [`Display`](fmt::Display).
+    ///
+    /// The output matches [`Display`](fmt::Display): castling is written as the king's
+    /// destination square and drops as `N@f3`. Use
+    /// [`Board::to_uci`](crate::Board::to_uci) for the king-takes-rook castling form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mut buf: [u8; 6] = [0u8; 6];
+    /// let mv: Move = Move::new(Square::B7, Square::B8, MoveType::PromotionQueen);
+    /// assert_eq!(mv.write_uci(&mut buf), "b7b8q");
+    /// ```
+    pub fn write_uci(self, buf: &mut [u8; 6]) -> &str {
+        #[cfg(feature = "variants")]
+        if self.is_drop() {
+            buf[0] = self.get_drop_piece().to_char() as u8;
+            buf[1] = b'@';
+            buf[2..4].copy_from_slice(self.get_dest().to_str().as_bytes());
+            return core::str::from_utf8(&buf[..4]).unwrap_or("");
+        }
+
+        buf[0..2].copy_from_slice(self.get_src().to_str().as_bytes());
+        buf[2..4].copy_from_slice(self.get_dest().to_str().as_bytes());
+        let len: usize = if self.is_promotion() {
+            buf[4] = self.get_prom(Color::Black).to_char() as u8;
+            5
+        } else {
+            4
+        };
+        core::str::from_utf8(&buf[..len]).unwrap_or("")
+    }
+
+    /// Parses a move in UCI notation into its source square, destination square
+    /// and optional promotion piece, without needing a [`Board`](crate::Board).
+    ///
+    /// The promotion suffix must be lowercase (`n`, `b`, `r` or `q`), as required by UCI.
+    /// To resolve the full [`MoveType`] from a position, use
+    /// [`Board::move_from_uci`](crate::Board::move_from_uci).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// assert_eq!(Move::parse_uci("e2e4"), Ok((Square::E2, Square::E4, None)));
+    /// assert_eq!(
+    ///     Move::parse_uci("b7b8n"),
+    ///     Ok((Square::B7, Square::B8, Some(PieceType::Knight)))
+    /// );
+    /// assert_eq!(Move::parse_uci("e2e4k"), Err(UciMoveError::InvalidPromotion('k')));
+    /// ```
+    pub fn parse_uci(uci: &str) -> Result<(Square, Square, Option<PieceType>), UciMoveError> {
+        if !uci.is_ascii() || !(4..=5).contains(&uci.len()) {
+            return Err(UciMoveError::InvalidLength);
+        }
+
+        let src: Square = Square::from_str(&uci[0..2]).map_err(UciMoveError::InvalidSquare)?;
+        let dest: Square = Square::from_str(&uci[2..4]).map_err(UciMoveError::InvalidSquare)?;
+
+        let prom: Option<PieceType> = match uci[4..].chars().next() {
+            None => None,
+            Some('n') => Some(PieceType::Knight),
+            Some('b') => Some(PieceType::Bishop),
+            Some('r') => Some(PieceType::Rook),
+            Some('q') => Some(PieceType::Queen),
+            Some(c) => return Err(UciMoveError::InvalidPromotion(c)),
+        };
+
+        Ok((src, dest, prom))
+    }
 }
@@ -0,0 +1,349 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::ops::AddAssign;
+
+use crate::{
+    AllMoves, BitBoard, Board, FenOptions, Move, PerftEpdError, count_legal_moves,
+    enumerate_legal_moves,
+};
+
+/// Counts the leaf nodes of the legal move tree of the given depth, a standard
+/// correctness and performance test for move generators.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// assert_eq!(perft(&Board::default(), 3), 8902);
+/// assert_eq!(perft(&Board::kiwipete(), 2), 2039);
+/// ```
+pub fn perft(board: &Board, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    // Bulk-count the leaves instead of enumerating them.
+    if depth == 1 {
+        return count_legal_moves::<AllMoves>(board) as u64;
+    }
+
+    let mut nodes: u64 = 0;
+    enumerate_legal_moves::<AllMoves, _>(board, |mv: Move| -> bool {
+        nodes += perft(&board.make_move(mv), depth - 1);
+        true
+    });
+    nodes
+}
+
+/// A breakdown of the leaf nodes of a move tree by move class, as tabulated on the
+/// Chess Programming Wiki perft results page.
+///
+/// Each counter refers to the move leading to a leaf: `captures` includes en passant
+/// captures, `discovered_checks` counts checks where the moved piece itself does not give check,
+/// and `checkmates` counts leaves where the side to move is mated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PerftStats {
+    /// The number of leaf nodes.
+    pub nodes: u64,
+    /// Leaves reached by a capture.
+    pub captures: u64,
+    /// Leaves reached by an en passant capture.
+    pub en_passants: u64,
+    /// Leaves reached by castling.
+    pub castles: u64,
+    /// Leaves reached by a promotion.
+    pub promotions: u64,
+    /// Leaves where the side to move is in check.
+    pub checks: u64,
+    /// Leaves where a check was discovered by the move.
+    pub discovered_checks: u64,
+    /// Leaves where the side to move is in double check.
+    pub double_checks: u64,
+    /// Leaves where the side to move is checkmated.
+    pub checkmates: u64,
+}
+
+impl AddAssign for PerftStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.nodes += rhs.nodes;
+        self.captures += rhs.captures;
+        self.en_passants += rhs.en_passants;
+        self.castles += rhs.castles;
+        self.promotions += rhs.promotions;
+        self.checks += rhs.checks;
+        self.discovered_checks += rhs.discovered_checks;
+        self.double_checks += rhs.double_checks;
+        self.checkmates += rhs.checkmates;
+    }
+}
+
+/// Walks the legal move tree like [`perft`], classifying every leaf into [`PerftStats`].
+///
+/// This is much slower than [`perft`], since every leaf move is made, but a mismatch in
+/// a single counter localizes a move generator bug to a specific move class.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let stats = perft_stats(&Board::kiwipete(), 2);
+/// assert_eq!(stats.nodes, 2039);
+/// assert_eq!(stats.captures, 351);
+/// assert_eq!(stats.en_passants, 1);
+/// assert_eq!(stats.castles, 91);
+/// assert_eq!(stats.checks, 3);
+/// ```
+pub fn perft_stats(board: &Board, depth: usize) -> PerftStats {
+    let mut stats: PerftStats = PerftStats::default();
+    if depth == 0 {
+        stats.nodes = 1;
+        return stats;
+    }
+
+    enumerate_legal_moves::<AllMoves, _>(board, |mv: Move| -> bool {
+        let child: Board = board.make_move(mv);
+        if depth > 1 {
+            stats += perft_stats(&child, depth - 1);
+            return true;
+        }
+
+        stats.nodes += 1;
+        stats.captures += mv.is_capture() as u64;
+        stats.en_passants += mv.is_enpassant() as u64;
+        stats.castles += mv.is_castle() as u64;
+        stats.promotions += mv.is_promotion() as u64;
+
+        let checkers: BitBoard = child.checkers;
+        if !checkers.is_empty() {
+            stats.checks += 1;
+            // A castling move only checks with the rook it moved.
+            if !mv.is_castle() && (checkers & mv.get_dest().to_bitboard()).is_empty() {
+                stats.discovered_checks += 1;
+            }
+            if checkers.count_bits() > 1 {
+                stats.double_checks += 1;
+            }
+            if count_legal_moves::<AllMoves>(&child) == 0 {
+                stats.checkmates += 1;
+            }
+        }
+        true
+    });
+    stats
+}
+
+/// The outcome of a single `D<depth> <nodes>` opcode of an EPD perft record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PerftEpdResult {
+    /// The depth searched.
+    pub depth: usize,
+    /// The node count given by the record.
+    pub expected: u64,
+    /// The node count computed by [`perft`].
+    pub nodes: u64,
+}
+
+impl PerftEpdResult {
+    /// Returns `true` if the computed node count matches the expected one.
+    #[inline(always)]
+    pub const fn passed(&self) -> bool {
+        self.nodes == self.expected
+    }
+}
+
+/// A lazy runner over the perft opcodes of an EPD record, created by [`perft_epd`].
+///
+/// Each call to [`next`](Iterator::next) runs [`perft`] for the next depth opcode,
+/// so deeper checks are only paid for when they are consumed.
+#[derive(Debug, Clone)]
+pub struct PerftEpd<'a> {
+    board: Board,
+    ops: core::str::Split<'a, char>,
+    max_depth: usize,
+}
+
+impl PerftEpd<'_> {
+    /// Returns the position of the record.
+    #[inline(always)]
+    pub const fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Skips the opcodes whose depth exceeds `max_depth`.
+    #[inline(always)]
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+impl Iterator for PerftEpd<'_> {
+    type Item = PerftEpdResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for op in self.ops.by_ref() {
+            // Opcodes were validated by `perft_epd`.
+            let Some(Ok((depth, expected))) = parse_depth_opcode(op, 0) else {
+                continue;
+            };
+            if depth <= self.max_depth {
+                let nodes: u64 = perft(&self.board, depth);
+                return Some(PerftEpdResult {
+                    depth,
+                    expected,
+                    nodes,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Parses an EPD perft record such as `<fen> ;D1 20 ;D2 400` and returns a runner
+/// that verifies each depth opcode with [`perft`].
+///
+/// The position may have four fields, as in EPD, or six, as in FEN. Opcodes other
+/// than `D<depth>` are ignored. All opcodes are validated before anything is searched.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let record = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - ;D1 20 ;D2 400 ;D3 8902";
+/// assert!(perft_epd(record).unwrap().all(|result| result.passed()));
+///
+/// let result = perft_epd("4k3/8/8/8/8/8/8/4K2R w K - ;D1 14").unwrap().next().unwrap();
+/// assert_eq!(result.nodes, 15);
+/// assert!(!result.passed());
+///
+/// assert_eq!(
+///     perft_epd("4k3/8/8/8/8/8/8/4K2R w K - ;D1 fifteen").unwrap_err(),
+///     PerftEpdError::InvalidNodeCount(0)
+/// );
+/// ```
+pub fn perft_epd(record: &str) -> Result<PerftEpd<'_>, PerftEpdError> {
+    let (position, ops) = record.split_once(';').unwrap_or((record, ""));
+    let board: Board = Board::from_fen_with(position, FenOptions::PERMISSIVE)
+        .map_err(PerftEpdError::InvalidFen)?;
+
+    for (index, op) in ops.split(';').enumerate() {
+        if let Some(Err(err)) = parse_depth_opcode(op, index) {
+            return Err(err);
+        }
+    }
+
+    Ok(PerftEpd {
+        board,
+        ops: ops.split(';'),
+        max_depth: usize::MAX,
+    })
+}
+
+/// Parses a `D<depth> <nodes>` opcode, returning `None` for any other opcode.
+fn parse_depth_opcode(op: &str, index: usize) -> Option<Result<(usize, u64), PerftEpdError>> {
+    let mut tokens: core::str::SplitWhitespace<'_> = op.split_whitespace();
+    let depth: &str = tokens.next()?.strip_prefix('D')?;
+    Some(
+        depth
+            .parse()
+            .map_err(|_| PerftEpdError::InvalidDepth(index))
+            .and_then(|depth| {
+                let nodes: u64 = tokens
+                    .next()
+                    .and_then(|nodes| nodes.parse().ok())
+                    .filter(|_| tokens.next().is_none())
+                    .ok_or(PerftEpdError::InvalidNodeCount(index))?;
+                Ok((depth, nodes))
+            }),
+    )
+}
+
+/// Counts the leaf nodes like [`perft`], caching subtree counts in a transposition table
+/// of about `hash_mb` megabytes indexed by the [`Zobrist`](crate::Zobrist) hash.
+///
+/// Transpositions are very frequent in the move tree, so this makes deep validation runs
+/// practical. Entries are verified against the full 64-bit hash and the remaining depth,
+/// and replaced unconditionally. With `hash_mb` set to `0` this is equivalent to [`perft`].
+///
+/// Available with the `alloc` feature.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let board = Board::kiwipete();
+/// assert_eq!(perft_hashed(&board, 3, 16), perft(&board, 3));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn perft_hashed(board: &Board, depth: usize, hash_mb: usize) -> u64 {
+    let entries: usize = (hash_mb << 20) / size_of::<PerftEntry>();
+    if entries == 0 {
+        return perft(board, depth);
+    }
+
+    // Round the entry count down to a power of two so the index is a mask.
+    let mut table: PerftTable = PerftTable {
+        entries: alloc::vec![PerftEntry::default(); 1 << entries.ilog2()],
+    };
+    table.perft(board, depth)
+}
+
+/// A transposition table entry storing the node count of a subtree.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Default)]
+struct PerftEntry {
+    key: u64,
+    depth: u64,
+    nodes: u64,
+}
+
+/// An always-replace transposition table used by [`perft_hashed`].
+#[cfg(feature = "alloc")]
+struct PerftTable {
+    entries: Vec<PerftEntry>,
+}
+
+#[cfg(feature = "alloc")]
+impl PerftTable {
+    fn perft(&mut self, board: &Board, depth: usize) -> u64 {
+        if depth <= 1 {
+            return perft(board, depth);
+        }
+
+        let key: u64 = board.zobrist().0;
+        let index: usize = key as usize & (self.entries.len() - 1);
+        let entry: PerftEntry = self.entries[index];
+        if entry.key == key && entry.depth == depth as u64 {
+            return entry.nodes;
+        }
+
+        let mut nodes: u64 = 0;
+        enumerate_legal_moves::<AllMoves, _>(board, |mv: Move| -> bool {
+            nodes += self.perft(&board.make_move(mv), depth - 1);
+            true
+        });
+
+        self.entries[index] = PerftEntry {
+            key,
+            depth: depth as u64,
+            nodes,
+        };
+        nodes
+    }
+}
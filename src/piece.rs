@@ -99,6 +99,19 @@ impl PieceType {
             Self::King => 'K',
         }
     }
+
+    /// Returns the Unicode figurine symbol for the `PieceType`, as used in figurine algebraic notation.
+    #[inline(always)]
+    pub const fn to_figurine(&self) -> char {
+        match self {
+            Self::Pawn => '♙',
+            Self::Knight => '♘',
+            Self::Bishop => '♗',
+            Self::Rook => '♖',
+            Self::Queen => '♕',
+            Self::King => '♔',
+        }
+    }
 }
 
 /// Enum representing all possible chess pieces, combining both color and piece type.
@@ -0,0 +1,81 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`proptest`] strategies for property tests, enabled by the `proptest` feature.
+
+use alloc::vec::Vec;
+
+use crate::{AllMoves, Board, Move, MoveList, gen_moves};
+use proptest::collection::vec;
+use proptest::prelude::{Strategy, any};
+use proptest::sample::{Index, select};
+
+/// The maximum length of the playout used by [`any_board`].
+const MAX_PLIES: usize = 256;
+
+/// A strategy generating legal positions by a random playout from the starting position.
+///
+/// Each ply picks one of the legal moves, and the playout stops early at checkmate,
+/// stalemate or the fifty-move limit. Failing cases shrink towards shorter playouts,
+/// and so towards positions closer to the starting one.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(board in any_board())| {
+///     prop_assert_eq!(board.validate(), Ok(()));
+/// });
+/// ```
+pub fn any_board() -> impl Strategy<Value = Board> {
+    vec(any::<Index>(), 0..=MAX_PLIES).prop_map(|choices: Vec<Index>| {
+        let mut board: Board = Board::default();
+        for choice in choices {
+            let moves: MoveList = gen_moves::<AllMoves>(&board);
+            if moves.is_empty() || board.fifty_move() >= 100 {
+                break;
+            }
+            board = board.make_move(*choice.get(&moves));
+        }
+        board
+    })
+}
+
+/// A strategy picking one of the legal moves of `board`.
+///
+/// # Panics
+/// Panics if `board` has no legal moves.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// use proptest::prelude::*;
+///
+/// let strategy = any_board()
+///     .prop_filter("no legal moves", |board| !gen_moves::<AllMoves>(board).is_empty())
+///     .prop_flat_map(|board| (Just(board), any_legal_move(&board)));
+///
+/// proptest!(|((board, mv) in strategy)| {
+///     prop_assert!(gen_moves::<AllMoves>(&board).contains(&mv));
+/// });
+/// ```
+pub fn any_legal_move(board: &Board) -> impl Strategy<Value = Move> + use<> {
+    select(Vec::from(gen_moves::<AllMoves>(board)))
+}
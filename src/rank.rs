@@ -17,7 +17,7 @@
     along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
 */
 
-use crate::BitBoard;
+use crate::{BitBoard, Color, File, Square};
 use core::fmt;
 use core::mem::transmute;
 
@@ -116,6 +116,24 @@ impl Rank {
         RANK_BITBOARDS[self.to_index()]
     }
 
+    /// Gets the rank as seen from the given side's perspective, so that
+    /// `Rank::One` is the back rank for both White and Black.
+    #[inline(always)]
+    pub const fn relative(self, color: Color) -> Self {
+        match color {
+            Color::White => self,
+            Color::Black => unsafe { transmute::<u8, Rank>(7 - self as u8) },
+        }
+    }
+
+    /// Returns an iterator over the squares of the rank, from file A to file H.
+    #[inline]
+    pub fn squares(self) -> impl Iterator<Item = Square> {
+        File::ALL
+            .into_iter()
+            .map(move |file| Square::from_file_rank(file, self))
+    }
+
     /// Returns the corresponding character for the `Rank`.
     #[inline(always)]
     pub const fn to_char(&self) -> char {
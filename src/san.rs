@@ -18,33 +18,76 @@
 */
 
 use crate::{
-    AllMoves, Board, Color, Move, Piece, PieceType, Square, enumerate_legal_moves, gen_moves,
+    AllMoves, Board, Color, File, Move, Piece, PieceType, Rank, SanParseError, Square,
+    enumerate_legal_moves, gen_moves,
 };
+use core::cell::OnceCell;
 use core::fmt;
+use core::str::FromStr;
 
 /// Converts a given move to its Standard Algebraic Notation (SAN) representation.
 ///
 /// This function creates a [`SanBuffered`] instance that formats the move according to  
 /// the rules of SAN notation. SAN notation is commonly used in chess notation to describe moves.
 ///
+/// Nothing is rendered here; the SAN string is built the first time the result is
+/// displayed or compared, and reused afterwards.
+///
 /// # Examples
 ///
 /// ```
 /// # use laura_core::*;
 ///
 /// let board = Board::default();
-/// let mv = Move::new(Square::A2, Square::A4, MoveType::Quiet);
+/// let mv = Move::new(Square::A2, Square::A4, MoveType::DoublePawn);
 ///
 /// assert_eq!(board.to_san(mv), "a4");
 /// ```
-pub fn to_san(mv: Move, board: &Board) -> SanBuffered {
-    SanBuffered { mv, board: *board }
+pub fn to_san(mv: Move, board: &Board) -> SanBuffered<'_> {
+    SanBuffered {
+        mv,
+        board,
+        options: SanOptions::DEFAULT,
+        rendered: OnceCell::new(),
+    }
 }
 
-/// A wrapper that holds a move and the corresponding board state for SAN rendering.
+/// Formatting options for [`SanBuffered`].
+///
+/// # Examples
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let board = Board::default();
+/// let mv = Move::new(Square::G1, Square::F3, MoveType::Quiet);
+/// let options = SanOptions { figurine: true, ..SanOptions::DEFAULT };
+///
+/// assert_eq!(board.to_san(mv).with_options(options), "♘f3");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct SanOptions {
+    /// Use Unicode figurine symbols (e.g. `♘`) instead of piece letters.
+    pub figurine: bool,
+
+    /// Render castling with zeros (`0-0`, `0-0-0`) instead of the letter `O`.
+    pub zero_castling: bool,
+}
+
+impl SanOptions {
+    /// Standard SAN output with piece letters.
+    pub const DEFAULT: Self = Self {
+        figurine: false,
+        zero_castling: false,
+    };
+}
+
+/// A wrapper that holds a move, a borrow of the corresponding board state and its rendered SAN.
 ///
 /// The `SanBuffered` struct provides an efficient way to render a move in Standard Algebraic Notation (SAN),
-/// using a precomputed board state to handle disambiguation, captures, promotions, and checks.
+/// using the borrowed board state to handle disambiguation, captures, promotions, and checks.
+/// The SAN string is rendered on first use and cached in an inline buffer, so repeated
+/// formatting and comparisons are free.
 ///
 /// It also implements `Display` and `PartialEq<&str>` to easily print or compare the SAN representation.
 ///
@@ -54,37 +97,76 @@ pub fn to_san(mv: Move, board: &Board) -> SanBuffered {
 /// # use laura_core::*;
 ///
 /// let board = Board::default();
-/// let mv = Move::new(Square::A2, Square::A4, MoveType::Quiet);
+/// let mv = Move::new(Square::A2, Square::A4, MoveType::DoublePawn);
 /// let san = to_san(mv, &board);
 ///
 /// println!("{}", san); // Outputs: "a4"
 /// assert_eq!(san, "a4");
 /// ```
 #[derive(Debug)]
-pub struct SanBuffered {
+pub struct SanBuffered<'a> {
     mv: Move,
-    board: Board,
+    board: &'a Board,
+    options: SanOptions,
+    rendered: OnceCell<([u8; 16], u8)>,
 }
 
-impl PartialEq<&str> for SanBuffered {
+impl PartialEq<&str> for SanBuffered<'_> {
     fn eq(&self, other: &&str) -> bool {
-        let mut buffer: [u8; 16] = [0u8; 16];
-        let san_str: &str = self.render_san(&mut buffer);
-        san_str == *other
+        self.as_str() == *other
     }
 }
 
-impl fmt::Display for SanBuffered {
+impl fmt::Display for SanBuffered<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut buffer: [u8; 16] = [0u8; 16];
-        let san_str: &str = self.render_san(&mut buffer);
-        write!(f, "{}", san_str)
+        f.write_str(self.as_str())
     }
 }
 
-impl SanBuffered {
+impl<'a> SanBuffered<'a> {
+    /// Returns this SAN with the given [`SanOptions`], rendered on first use.
+    #[inline]
+    pub fn with_options(mut self, options: SanOptions) -> Self {
+        self.options = options;
+        self.rendered = OnceCell::new();
+        self
+    }
+
+    /// Returns the SAN string, rendering and caching it on the first call.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        let (buffer, len) = self.rendered.get_or_init(|| {
+            let mut buffer: [u8; 16] = [0u8; 16];
+            let len: u8 = self.render_san(&mut buffer).len() as u8;
+            (buffer, len)
+        });
+        unsafe { core::str::from_utf8_unchecked(&buffer[..*len as usize]) }
+    }
+
+    /// Returns the move being rendered.
+    #[inline]
+    pub const fn mv(&self) -> Move {
+        self.mv
+    }
+
+    /// Returns the board the move is played from.
+    #[inline]
+    pub const fn board(&self) -> &'a Board {
+        self.board
+    }
+
+    /// Writes the symbol for a piece type into the buffer, returning the number of bytes written.
+    fn write_piece(&self, piece_type: PieceType, buffer: &mut [u8]) -> usize {
+        let symbol: char = if self.options.figurine {
+            piece_type.to_figurine()
+        } else {
+            piece_type.to_char()
+        };
+        symbol.encode_utf8(buffer).len()
+    }
+
     /// Renders the move in Standard Algebraic Notation (SAN) and writes it into the provided buffer.
-    fn render_san<'a>(&self, buffer: &'a mut [u8; 16]) -> &'a str {
+    fn render_san<'b>(&self, buffer: &'b mut [u8; 16]) -> &'b str {
         let mut idx: usize = 0;
 
         let new_board: Board = self.board.make_move(self.mv);
@@ -100,17 +182,17 @@ impl SanBuffered {
         };
 
         if piece_type == PieceType::King && self.mv.is_castle() {
-            if self.mv.is_king_castle() {
-                buffer[idx..idx + 3].copy_from_slice(b"O-O");
-                idx += 3;
-            } else {
-                buffer[idx..idx + 5].copy_from_slice(b"O-O-O");
-                idx += 5;
-            }
+            let castle: &[u8] = match (self.mv.is_king_castle(), self.options.zero_castling) {
+                (true, false) => b"O-O",
+                (false, false) => b"O-O-O",
+                (true, true) => b"0-0",
+                (false, true) => b"0-0-0",
+            };
+            buffer[idx..idx + castle.len()].copy_from_slice(castle);
+            idx += castle.len();
         } else {
             if piece_type != PieceType::Pawn {
-                buffer[idx] = piece_type.to_char() as u8;
-                idx += 1;
+                idx += self.write_piece(piece_type, &mut buffer[idx..]);
             }
 
             if piece_type == PieceType::Pawn {
@@ -123,7 +205,7 @@ impl SanBuffered {
                 let mut file_disambiguates: bool = true;
                 let mut rank_disambiguates: bool = true;
 
-                enumerate_legal_moves::<AllMoves, _>(&self.board, |candidate_mv| {
+                enumerate_legal_moves::<AllMoves, _>(self.board, |candidate_mv| {
                     if candidate_mv == self.mv {
                         return true;
                     }
@@ -177,22 +259,216 @@ impl SanBuffered {
             if let Some(p) = promotion {
                 buffer[idx] = b'=';
                 idx += 1;
-                buffer[idx] = p.piece_type().to_char() as u8;
-                idx += 1;
+                idx += self.write_piece(p.piece_type(), &mut buffer[idx..]);
             }
+        }
 
-            let check: bool = !new_board.checkers.is_empty();
-            let mate: bool = check && gen_moves::<AllMoves>(&new_board).is_empty();
+        let suffix: &[u8] = check_suffix(&new_board);
+        buffer[idx..idx + suffix.len()].copy_from_slice(suffix);
+        idx += suffix.len();
 
-            if mate {
-                buffer[idx] = b'#';
-                idx += 1;
-            } else if check {
-                buffer[idx] = b'+';
-                idx += 1;
+        unsafe { core::str::from_utf8_unchecked(&buffer[..idx]) }
+    }
+}
+
+/// Returns the check (`+`) or checkmate (`#`) suffix for the position reached after a move.
+pub(crate) fn check_suffix(new_board: &Board) -> &'static [u8] {
+    if new_board.checkers.is_empty() {
+        b""
+    } else if gen_moves::<AllMoves>(new_board).is_empty() {
+        b"#"
+    } else {
+        b"+"
+    }
+}
+
+/// A move annotation symbol as found in PGN move text.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum SanAnnotation {
+    /// A good move (`!`).
+    Good,
+
+    /// A mistake (`?`).
+    Mistake,
+
+    /// A brilliant move (`!!`).
+    Brilliant,
+
+    /// A blunder (`??`).
+    Blunder,
+
+    /// An interesting move (`!?`).
+    Interesting,
+
+    /// A dubious move (`?!`).
+    Dubious,
+}
+
+impl SanAnnotation {
+    /// Parses an annotation symbol such as `!?`.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "!" => Some(Self::Good),
+            "?" => Some(Self::Mistake),
+            "!!" => Some(Self::Brilliant),
+            "??" => Some(Self::Blunder),
+            "!?" => Some(Self::Interesting),
+            "?!" => Some(Self::Dubious),
+            _ => None,
+        }
+    }
+
+    /// Returns the annotation symbol.
+    pub const fn to_symbol(self) -> &'static str {
+        match self {
+            Self::Good => "!",
+            Self::Mistake => "?",
+            Self::Brilliant => "!!",
+            Self::Blunder => "??",
+            Self::Interesting => "!?",
+            Self::Dubious => "?!",
+        }
+    }
+}
+
+impl fmt::Display for SanAnnotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_symbol())
+    }
+}
+
+/// Parses a move in Standard Algebraic Notation (SAN) into the matching legal move.
+///
+/// Parsing is tolerant of the variations found in human and GUI produced PGN:
+/// `0-0` castling, missing or redundant `x`, `+` and `#` marks, over-specified
+/// disambiguation, promotions without `=`, and trailing `e.p.` markers.
+/// Annotations such as `!?` are accepted and returned separately.
+///
+/// # Examples
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let board = Board::default();
+/// let (mv, annotation) = parse_san("Nf3!?", &board).unwrap();
+///
+/// assert_eq!(mv, Move::new(Square::G1, Square::F3, MoveType::Quiet));
+/// assert_eq!(annotation, Some(SanAnnotation::Interesting));
+/// ```
+pub fn parse_san(san: &str, board: &Board) -> Result<(Move, Option<SanAnnotation>), SanParseError> {
+    let mut san: &str = san.trim();
+
+    let annotation_start: usize = san.trim_end_matches(['!', '?']).len();
+    let annotation: Option<SanAnnotation> = match &san[annotation_start..] {
+        "" => None,
+        symbol => Some(SanAnnotation::from_symbol(symbol).ok_or(SanParseError::InvalidSyntax)?),
+    };
+    san = &san[..annotation_start];
+
+    loop {
+        let stripped: &str = san
+            .trim_end()
+            .trim_end_matches(['+', '#'])
+            .trim_end_matches("e.p.");
+        if stripped.len() == san.len() {
+            break;
+        }
+        san = stripped;
+    }
+
+    let mv: Move = match san {
+        "O-O" | "0-0" => find_san_move(board, |mv| mv.is_king_castle())?,
+        "O-O-O" | "0-0-0" => find_san_move(board, |mv| mv.is_queen_castle())?,
+        _ => {
+            let mut body: &[u8] = san.as_bytes();
+
+            let promotion: Option<PieceType> = match body {
+                [.., b'=', piece] | [.., b'1'..=b'8', piece] if piece.is_ascii_uppercase() => {
+                    body = &body[..body.len() - 1];
+                    body = body.strip_suffix(b"=").unwrap_or(body);
+                    Some(san_piece_type(*piece).ok_or(SanParseError::InvalidSyntax)?)
+                }
+                _ => None,
+            };
+
+            if body.len() < 2 || !body.is_ascii() {
+                return Err(SanParseError::InvalidSyntax);
             }
+            let (prefix, dest) = body.split_at(body.len() - 2);
+            let dest: Square = Square::from_str(unsafe { core::str::from_utf8_unchecked(dest) })
+                .map_err(|_| SanParseError::InvalidSyntax)?;
+
+            let (piece_type, mut prefix) = match prefix {
+                [piece, rest @ ..] if piece.is_ascii_uppercase() => (
+                    san_piece_type(*piece).ok_or(SanParseError::InvalidSyntax)?,
+                    rest,
+                ),
+                _ => (PieceType::Pawn, prefix),
+            };
+            prefix = prefix.strip_suffix(b"x").unwrap_or(prefix);
+
+            let (src_file, src_rank) = match prefix {
+                [] => (None, None),
+                [file @ b'a'..=b'h'] => (Some(File::from_index((file - b'a') as usize)), None),
+                [rank @ b'1'..=b'8'] => (None, Some(Rank::from_index((rank - b'1') as usize))),
+                [file @ b'a'..=b'h', rank @ b'1'..=b'8'] => (
+                    Some(File::from_index((file - b'a') as usize)),
+                    Some(Rank::from_index((rank - b'1') as usize)),
+                ),
+                _ => return Err(SanParseError::InvalidSyntax),
+            };
+
+            find_san_move(board, |mv| {
+                let src: Square = mv.get_src();
+                !mv.is_castle()
+                    && mv.get_dest() == dest
+                    && board.piece_on(src).map(|piece| piece.piece_type()) == Some(piece_type)
+                    && src_file.is_none_or(|file| src.file() == file)
+                    && src_rank.is_none_or(|rank| src.rank() == rank)
+                    && match promotion {
+                        Some(prom) => {
+                            mv.is_promotion() && mv.get_prom(Color::White).piece_type() == prom
+                        }
+                        None => !mv.is_promotion(),
+                    }
+            })?
         }
+    };
 
-        unsafe { core::str::from_utf8_unchecked(&buffer[..idx]) }
+    Ok((mv, annotation))
+}
+
+/// Maps a SAN piece letter to its [`PieceType`].
+fn san_piece_type(letter: u8) -> Option<PieceType> {
+    match letter {
+        b'N' => Some(PieceType::Knight),
+        b'B' => Some(PieceType::Bishop),
+        b'R' => Some(PieceType::Rook),
+        b'Q' => Some(PieceType::Queen),
+        b'K' => Some(PieceType::King),
+        _ => None,
+    }
+}
+
+/// Returns the only legal move accepted by the filter.
+fn find_san_move<F>(board: &Board, filter: F) -> Result<Move, SanParseError>
+where
+    F: Fn(Move) -> bool,
+{
+    let mut found: Option<Move> = None;
+    let mut ambiguous: bool = false;
+
+    enumerate_legal_moves::<AllMoves, _>(board, |mv| {
+        if filter(mv) {
+            ambiguous |= found.is_some();
+            found = Some(mv);
+        }
+        true
+    });
+
+    match found {
+        _ if ambiguous => Err(SanParseError::AmbiguousMove),
+        Some(mv) => Ok(mv),
+        None => Err(SanParseError::IllegalMove),
     }
 }
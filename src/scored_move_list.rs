@@ -0,0 +1,221 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::ops::{Deref, DerefMut};
+
+use crate::Move;
+use crate::move_list::MAX_MOVES;
+
+/// A [`Move`] paired with a move-ordering score.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ScoredMove {
+    /// The move.
+    pub mv: Move,
+
+    /// The score of the move, higher is better.
+    pub score: i32,
+}
+
+/// A fixed-capacity container of [`ScoredMove`]s for move ordering.
+///
+/// `ScoredMoveList` holds up to the same number of moves as a [`MoveList`](crate::MoveList)
+/// and keeps each move next to its score, so ordering code does not need parallel arrays.
+/// Sorting and picking run in place without allocating.
+///
+/// # Example
+///
+/// ```
+/// # use laura_core::*;
+///
+/// let board = Board::kiwipete();
+/// let moves = gen_moves::<AllMoves>(&board);
+/// let mut scored = ScoredMoveList::from_moves(&moves, |mv| mv.is_capture() as i32);
+///
+/// scored.sort_unstable_by_score();
+/// assert!(scored[0].mv.is_capture());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ScoredMoveList {
+    moves: [ScoredMove; MAX_MOVES],
+    len: usize,
+}
+
+impl Default for ScoredMoveList {
+    /// Creates a new, empty `ScoredMoveList`.
+    #[inline]
+    fn default() -> Self {
+        ScoredMoveList {
+            moves: [ScoredMove {
+                mv: Move::null(),
+                score: 0,
+            }; MAX_MOVES],
+            len: 0,
+        }
+    }
+}
+
+impl Deref for ScoredMoveList {
+    type Target = [ScoredMove];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for ScoredMoveList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a> IntoIterator for &'a ScoredMoveList {
+    type Item = &'a ScoredMove;
+    type IntoIter = core::slice::Iter<'a, ScoredMove>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.moves[..self.len].iter()
+    }
+}
+
+impl ScoredMoveList {
+    /// Builds a `ScoredMoveList` from the given moves, scoring each one with `scorer`.
+    ///
+    /// Moves beyond the capacity of the list are ignored.
+    pub fn from_moves<F>(moves: &[Move], mut scorer: F) -> Self
+    where
+        F: FnMut(Move) -> i32,
+    {
+        let mut list: ScoredMoveList = ScoredMoveList::default();
+        for &mv in moves {
+            list.push(mv, scorer(mv));
+        }
+        list
+    }
+
+    /// Adds a [`Move`] with its score to the list.
+    ///
+    /// If the list is full, the move is silently ignored.
+    #[inline(always)]
+    pub fn push(&mut self, mv: Move, score: i32) {
+        if self.len < MAX_MOVES {
+            self.moves[self.len] = ScoredMove { mv, score };
+            self.len += 1;
+        }
+    }
+
+    /// Returns a slice containing the scored moves currently stored in the list.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[ScoredMove] {
+        &self.moves[..self.len]
+    }
+
+    /// Returns a mutable slice containing the scored moves currently stored in the list.
+    ///
+    /// Useful to adjust scores in place, for example after a history update.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [ScoredMove] {
+        &mut self.moves[..self.len]
+    }
+
+    /// Returns the number of moves currently stored in the list.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no moves.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Clears all moves from the list.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Sorts the moves by descending score using an insertion sort.
+    ///
+    /// Insertion sort is fast for the short lists found in move ordering and needs no
+    /// allocation. The order of moves with equal scores is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mut list = ScoredMoveList::default();
+    /// list.push(Move::new(Square::E2, Square::E3, MoveType::Quiet), 10);
+    /// list.push(Move::new(Square::E2, Square::E4, MoveType::DoublePawn), 30);
+    /// list.push(Move::new(Square::D2, Square::D3, MoveType::Quiet), 20);
+    ///
+    /// list.sort_unstable_by_score();
+    /// let scores: Vec<i32> = list.iter().map(|scored| scored.score).collect();
+    /// assert_eq!(scores, [30, 20, 10]);
+    /// ```
+    pub fn sort_unstable_by_score(&mut self) {
+        let moves: &mut [ScoredMove] = self.as_mut_slice();
+        for i in 1..moves.len() {
+            let current: ScoredMove = moves[i];
+            let mut j: usize = i;
+            while j > 0 && moves[j - 1].score < current.score {
+                moves[j] = moves[j - 1];
+                j -= 1;
+            }
+            moves[j] = current;
+        }
+    }
+
+    /// Removes and returns the move with the highest score, or `None` if the list is empty.
+    ///
+    /// The last move of the list takes the place of the removed one, so picking repeatedly
+    /// yields the moves in descending score order without sorting the whole list upfront.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use laura_core::*;
+    ///
+    /// let mut list = ScoredMoveList::default();
+    /// list.push(Move::new(Square::E2, Square::E3, MoveType::Quiet), 10);
+    /// list.push(Move::new(Square::E2, Square::E4, MoveType::DoublePawn), 30);
+    ///
+    /// assert_eq!(list.pick_max().map(|scored| scored.score), Some(30));
+    /// assert_eq!(list.pick_max().map(|scored| scored.score), Some(10));
+    /// assert_eq!(list.pick_max(), None);
+    /// ```
+    pub fn pick_max(&mut self) -> Option<ScoredMove> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut best: usize = 0;
+        for (index, scored) in self.as_slice().iter().enumerate().skip(1) {
+            if scored.score > self.moves[best].score {
+                best = index;
+            }
+        }
+
+        let picked: ScoredMove = self.moves[best];
+        self.len -= 1;
+        self.moves[best] = self.moves[self.len];
+        Some(picked)
+    }
+}
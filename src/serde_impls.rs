@@ -0,0 +1,129 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`serde`] support for the core types, enabled by the `serde` feature.
+//!
+//! Text-like types use their notation: [`Board`] as FEN, [`Square`] as `"e4"`,
+//! [`CastleRights`] as `"KQkq"` and [`Piece`] as a FEN character.
+//! [`Move`] is stored as its raw `u16`, since a UCI string cannot encode the
+//! move type without a board; values with an unassigned move type code are rejected.
+//! [`Zobrist`] is stored as its `u64` key.
+
+use crate::{Board, CastleRights, Move, Piece, Square, Zobrist};
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// Deserializes a type from a borrowed or owned string through its [`FromStr`] impl.
+struct FromStrVisitor<T>(PhantomData<T>, &'static str);
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.1)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        T::from_str(value).map_err(E::custom)
+    }
+}
+
+macro_rules! deserialize_from_str {
+    ($ty:ty, $expecting:literal) => {
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(FromStrVisitor(PhantomData, $expecting))
+            }
+        }
+    };
+}
+
+deserialize_from_str!(Board, "a FEN string");
+deserialize_from_str!(Square, "a square name such as \"e4\"");
+deserialize_from_str!(CastleRights, "castling rights such as \"KQkq\" or \"-\"");
+
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.to_fen())
+    }
+}
+
+impl Serialize for Square {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.to_str())
+    }
+}
+
+impl Serialize for CastleRights {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for Piece {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(self.to_char())
+    }
+}
+
+impl<'de> Deserialize<'de> for Piece {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let c: char = char::deserialize(deserializer)?;
+        Piece::try_from(c).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Move {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Move {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mv: Move = Move(u16::deserialize(deserializer)?);
+        if !mv.has_valid_type() {
+            return Err(de::Error::custom(format_args!(
+                "invalid move type code {:#06b} in move {:#06x}",
+                mv.flag(),
+                mv.0
+            )));
+        }
+        Ok(mv)
+    }
+}
+
+impl Serialize for Zobrist {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Zobrist {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Zobrist)
+    }
+}
@@ -0,0 +1,331 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! [`shakmaty`] interop, enabled by the `shakmaty` feature.
+//!
+//! [`Square`], [`Color`], [`PieceType`] and [`Piece`] convert losslessly in both directions.
+//! A [`Board`] converts into a [`shakmaty::Setup`] or a validated [`shakmaty::Chess`], and back
+//! from a [`shakmaty::Chess`]. Castling rights are exchanged as rook squares, so positions whose
+//! rooks start on other files round-trip as well.
+//!
+//! Converting a [`Move`] to a [`shakmaty::Move`] needs the moved and captured pieces, so it goes
+//! through [`Board::to_shakmaty_move`]; a move without a board converts to a
+//! [`shakmaty::uci::UciMove`].
+
+use core::num::NonZeroU32;
+
+use shakmaty::uci::UciMove;
+use shakmaty::{
+    Bitboard, CastlingMode, Chess, EnPassantMode, FromSetup, Position, PositionError, Role, Setup,
+};
+
+use crate::castle_rights::{KING_SIDE, QUEEN_SIDE};
+use crate::{
+    AllMoves, Board, BoardBuilder, BoardParseError, CastleRights, CastleRightsParseError, Color,
+    File, Move, MoveType, Piece, PieceType, Square, gen_moves,
+};
+
+impl From<Square> for shakmaty::Square {
+    #[inline]
+    fn from(square: Square) -> Self {
+        shakmaty::Square::new(square.to_index() as u32)
+    }
+}
+
+impl From<shakmaty::Square> for Square {
+    #[inline]
+    fn from(square: shakmaty::Square) -> Self {
+        Square::from_index(u32::from(square) as usize)
+    }
+}
+
+impl From<Color> for shakmaty::Color {
+    #[inline]
+    fn from(color: Color) -> Self {
+        match color {
+            Color::White => shakmaty::Color::White,
+            Color::Black => shakmaty::Color::Black,
+        }
+    }
+}
+
+impl From<shakmaty::Color> for Color {
+    #[inline]
+    fn from(color: shakmaty::Color) -> Self {
+        match color {
+            shakmaty::Color::White => Color::White,
+            shakmaty::Color::Black => Color::Black,
+        }
+    }
+}
+
+impl From<PieceType> for Role {
+    #[inline]
+    fn from(piece_type: PieceType) -> Self {
+        match piece_type {
+            PieceType::Pawn => Role::Pawn,
+            PieceType::Knight => Role::Knight,
+            PieceType::Bishop => Role::Bishop,
+            PieceType::Rook => Role::Rook,
+            PieceType::Queen => Role::Queen,
+            PieceType::King => Role::King,
+        }
+    }
+}
+
+impl From<Role> for PieceType {
+    #[inline]
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Pawn => PieceType::Pawn,
+            Role::Knight => PieceType::Knight,
+            Role::Bishop => PieceType::Bishop,
+            Role::Rook => PieceType::Rook,
+            Role::Queen => PieceType::Queen,
+            Role::King => PieceType::King,
+        }
+    }
+}
+
+impl From<Piece> for shakmaty::Piece {
+    #[inline]
+    fn from(piece: Piece) -> Self {
+        shakmaty::Piece {
+            color: piece.color().into(),
+            role: piece.piece_type().into(),
+        }
+    }
+}
+
+impl From<shakmaty::Piece> for Piece {
+    #[inline]
+    fn from(piece: shakmaty::Piece) -> Self {
+        Piece::new(piece.role.into(), piece.color.into())
+    }
+}
+
+impl From<&Board> for Setup {
+    fn from(board: &Board) -> Self {
+        let mut setup: Setup = Setup::empty();
+        for index in 0..Square::NUM_SQUARES {
+            let square: Square = Square::from_index(index);
+            if let Some(piece) = board.piece_on(square) {
+                setup.board.set_piece_at(square.into(), piece.into());
+            }
+        }
+
+        let castling: CastleRights = board.castling_rights();
+        for color in [Color::White, Color::Black] {
+            if castling.has_kingside(color) {
+                let rook: Square = castling.rook_square(color, KING_SIDE);
+                setup.castling_rights.add(shakmaty::Square::from(rook));
+            }
+            if castling.has_queenside(color) {
+                let rook: Square = castling.rook_square(color, QUEEN_SIDE);
+                setup.castling_rights.add(shakmaty::Square::from(rook));
+            }
+        }
+
+        setup.turn = board.side().into();
+        setup.ep_square = board.enpassant_square.map(shakmaty::Square::from);
+        setup.halfmoves = board.fifty_move() as u32;
+        setup.fullmoves = NonZeroU32::new(board.full_move() as u32).unwrap_or(NonZeroU32::MIN);
+        setup
+    }
+}
+
+impl TryFrom<&Board> for Chess {
+    type Error = PositionError<Chess>;
+
+    /// Converts the board into a validated [`Chess`] position, detecting whether its castling
+    /// rights need Chess960 castling.
+    fn try_from(board: &Board) -> Result<Self, Self::Error> {
+        let setup: Setup = Setup::from(board);
+        let mode: CastlingMode = CastlingMode::detect(&setup);
+        Chess::from_setup(setup, mode)
+    }
+}
+
+impl TryFrom<&Chess> for Board {
+    type Error = BoardParseError;
+
+    /// Converts a [`Chess`] position into a [`Board`].
+    ///
+    /// # Errors
+    /// Returns a [`BoardParseError`] if the move counters do not fit the board, or
+    /// [`BoardParseError::InvalidCastlingRights`] if a side may castle with a king off the
+    /// e-file, which the board cannot represent.
+    fn try_from(chess: &Chess) -> Result<Self, Self::Error> {
+        let mut builder: BoardBuilder = BoardBuilder::new();
+        for (square, piece) in chess.board().clone() {
+            builder = builder.piece(square.into(), piece.into());
+        }
+
+        let mut castling: CastleRights = CastleRights::null();
+        for color in [Color::White, Color::Black] {
+            let back_rank: shakmaty::Rank = match color {
+                Color::White => shakmaty::Rank::First,
+                Color::Black => shakmaty::Rank::Eighth,
+            };
+            let rooks: Bitboard =
+                chess.castles().castling_rights() & Bitboard::from_rank(back_rank);
+            if rooks.is_empty() {
+                continue;
+            }
+            let king: Square = match chess.board().king_of(color.into()) {
+                Some(king) if Square::from(king).file() == File::E => king.into(),
+                _ => {
+                    let rook: char = rooks.first().map_or('-', |rook| rook.file().char());
+                    return Err(BoardParseError::InvalidCastlingRights(
+                        CastleRightsParseError::InvalidChar(match color {
+                            Color::White => rook.to_ascii_uppercase(),
+                            Color::Black => rook,
+                        }),
+                    ));
+                }
+            };
+
+            for rook in rooks {
+                let rook: Square = rook.into();
+                let side: usize = if rook.file() > king.file() {
+                    KING_SIDE
+                } else {
+                    QUEEN_SIDE
+                };
+                castling = castling.with(color, side, rook.file());
+            }
+        }
+
+        let halfmoves: u8 =
+            u8::try_from(chess.halfmoves()).map_err(|_| BoardParseError::HalfmoveClockOverflow)?;
+        let fullmoves: u16 = u16::try_from(chess.fullmoves().get())
+            .map_err(|_| BoardParseError::InvalidFullmoveNumber)?;
+
+        builder
+            .side(chess.turn().into())
+            .castling(castling)
+            .enpassant(chess.ep_square(EnPassantMode::Always).map(Square::from))
+            .fifty_move(halfmoves)
+            .full_move(fullmoves)
+            .build()
+    }
+}
+
+impl From<Move> for UciMove {
+    /// Converts the move to a [`UciMove`], encoding castling by the king's destination square
+    /// and drops as [`UciMove::Put`].
+    #[inline]
+    fn from(mv: Move) -> Self {
+        #[cfg(feature = "variants")]
+        if mv.is_drop() {
+            return UciMove::Put {
+                role: mv.get_drop_piece().into(),
+                to: mv.get_dest().into(),
+            };
+        }
+        UciMove::Normal {
+            from: mv.get_src().into(),
+            to: mv.get_dest().into(),
+            promotion: mv
+                .is_promotion()
+                .then(|| mv.get_prom(Color::White).piece_type().into()),
+        }
+    }
+}
+
+impl Board {
+    /// Converts a move of this position to a [`shakmaty::Move`], filling in the moved and
+    /// captured pieces from the board. Castling moves are given by the king and rook squares.
+    ///
+    /// The move is assumed to be pseudo-legal in this position.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let board: Board = Board::default();
+    /// let mv: Move = board.find_move("g1f3").unwrap();
+    /// assert_eq!(
+    ///     board.to_shakmaty_move(mv),
+    ///     shakmaty::Move::Normal {
+    ///         role: shakmaty::Role::Knight,
+    ///         from: shakmaty::Square::G1,
+    ///         capture: None,
+    ///         to: shakmaty::Square::F3,
+    ///         promotion: None,
+    ///     }
+    /// );
+    /// ```
+    pub fn to_shakmaty_move(&self, mv: Move) -> shakmaty::Move {
+        let (src, dest): (Square, Square) = (mv.get_src(), mv.get_dest());
+        match mv.get_type() {
+            #[cfg(feature = "variants")]
+            MoveType::Drop => shakmaty::Move::Put {
+                role: mv.get_drop_piece().into(),
+                to: dest.into(),
+            },
+            MoveType::EnPassant => shakmaty::Move::EnPassant {
+                from: src.into(),
+                to: dest.into(),
+            },
+            MoveType::KingCastle | MoveType::QueenCastle => {
+                let side: usize = match mv.get_type() {
+                    MoveType::KingCastle => KING_SIDE,
+                    _ => QUEEN_SIDE,
+                };
+                shakmaty::Move::Castle {
+                    king: src.into(),
+                    rook: self.castling_rights().rook_square(self.side(), side).into(),
+                }
+            }
+            _ => shakmaty::Move::Normal {
+                role: self
+                    .piece_on(src)
+                    .map_or(Role::Pawn, |piece| piece.piece_type().into()),
+                from: src.into(),
+                capture: self.piece_on(dest).map(|piece| piece.piece_type().into()),
+                to: dest.into(),
+                promotion: mv
+                    .is_promotion()
+                    .then(|| mv.get_prom(self.side()).piece_type().into()),
+            },
+        }
+    }
+
+    /// Finds the legal move of this position matching a [`shakmaty::Move`], or returns `None`
+    /// if there is no such move.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// use shakmaty::Position;
+    ///
+    /// let board: Board = Board::kiwipete();
+    /// let chess: shakmaty::Chess = shakmaty::Chess::try_from(&board).unwrap();
+    /// for m in chess.legal_moves() {
+    ///     let mv: Move = board.from_shakmaty_move(&m).unwrap();
+    ///     assert_eq!(board.to_shakmaty_move(mv), m);
+    /// }
+    /// ```
+    pub fn from_shakmaty_move(&self, m: &shakmaty::Move) -> Option<Move> {
+        gen_moves::<AllMoves>(self)
+            .iter()
+            .copied()
+            .find(|&mv| self.to_shakmaty_move(mv) == *m)
+    }
+}
@@ -21,7 +21,9 @@ use core::fmt;
 use core::mem::transmute;
 use core::str::FromStr;
 
-use crate::{BitBoard, Color, File, Rank, SquareDocs, SquareParseError};
+use crate::{
+    AntiDiagonal, BitBoard, Color, Diagonal, Direction, File, Rank, SquareDocs, SquareParseError,
+};
 
 SquareDocs! {
     A1, B1, C1, D1, E1, F1, G1, H1,
@@ -114,6 +116,42 @@ impl Square {
         unsafe { transmute(self as u8 & 7) }
     }
 
+    /// Mirror the square vertically (A1 <-> A8), keeping its file.
+    #[inline(always)]
+    pub const fn flip(self) -> Self {
+        unsafe { transmute(self as u8 ^ 56) }
+    }
+
+    /// Mirror the square horizontally (A1 <-> H1), keeping its rank.
+    #[inline(always)]
+    pub const fn mirror_horizontal(self) -> Self {
+        unsafe { transmute(self as u8 ^ 7) }
+    }
+
+    /// Rotate the square 180 degrees around the center of the board (A1 <-> H8).
+    #[inline(always)]
+    pub const fn rotate_180(self) -> Self {
+        unsafe { transmute(self as u8 ^ 63) }
+    }
+
+    /// Get the rank of the square as seen from the given side's perspective.
+    #[inline(always)]
+    pub const fn relative_rank(self, color: Color) -> Rank {
+        self.rank().relative(color)
+    }
+
+    /// Get the diagonal (a1-h8 direction) the square lies on.
+    #[inline(always)]
+    pub const fn diagonal(self) -> Diagonal {
+        unsafe { transmute(7 + (self as u8 & 7) - (self as u8 >> 3)) }
+    }
+
+    /// Get the anti-diagonal (h1-a8 direction) the square lies on.
+    #[inline(always)]
+    pub const fn anti_diagonal(self) -> AntiDiagonal {
+        unsafe { transmute((self as u8 & 7) + (self as u8 >> 3)) }
+    }
+
     /// Get the square one rank down from original (towards rank 1).
     /// Wrap linear over the Square enum (H1.down() = H8)
     #[inline(always)]
@@ -180,6 +218,67 @@ impl Square {
         }
     }
 
+    /// Returns the absolute difference between the files of two squares (0-7).
+    #[inline(always)]
+    pub const fn file_distance(self, other: Square) -> u8 {
+        (self.file() as u8).abs_diff(other.file() as u8)
+    }
+
+    /// Returns the absolute difference between the ranks of two squares (0-7).
+    #[inline(always)]
+    pub const fn rank_distance(self, other: Square) -> u8 {
+        (self.rank() as u8).abs_diff(other.rank() as u8)
+    }
+
+    /// Returns the Chebyshev (king move) distance between two squares,
+    /// the larger of the file and rank distances.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// assert_eq!(Square::A1.chebyshev_distance(Square::C2), 2);
+    /// assert_eq!(Square::A1.chebyshev_distance(Square::H8), 7);
+    /// ```
+    #[inline(always)]
+    pub const fn chebyshev_distance(self, other: Square) -> u8 {
+        let file: u8 = self.file_distance(other);
+        let rank: u8 = self.rank_distance(other);
+        if file > rank { file } else { rank }
+    }
+
+    /// Returns the Manhattan (taxicab) distance between two squares,
+    /// the sum of the file and rank distances.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// assert_eq!(Square::A1.manhattan_distance(Square::C2), 3);
+    /// assert_eq!(Square::A1.manhattan_distance(Square::H8), 14);
+    /// ```
+    #[inline(always)]
+    pub const fn manhattan_distance(self, other: Square) -> u8 {
+        self.file_distance(other) + self.rank_distance(other)
+    }
+
+    /// Returns an iterator over the squares reached by repeatedly stepping in the given
+    /// [`Direction`], in walking order and excluding the starting square, until the board edge.
+    ///
+    /// # Example
+    /// ```
+    /// # use laura_core::*;
+    /// let mut walk = Square::C3.walk(Direction::SouthWest);
+    /// assert_eq!(walk.next(), Some(Square::B2));
+    /// assert_eq!(walk.next(), Some(Square::A1));
+    /// assert_eq!(walk.next(), None);
+    /// ```
+    #[inline]
+    pub const fn walk(self, direction: Direction) -> SquareWalk {
+        SquareWalk {
+            current: self.to_bitboard(),
+            direction,
+        }
+    }
+
     /// Returns the algebraic notation of the square.
     ///
     /// # Example
@@ -201,3 +300,20 @@ impl Square {
         "e8", "f8", "g8", "h8",
     ];
 }
+
+/// Iterator over the squares along a [`Direction`], created by [`Square::walk`].
+#[derive(Copy, Clone, Debug)]
+pub struct SquareWalk {
+    current: BitBoard,
+    direction: Direction,
+}
+
+impl Iterator for SquareWalk {
+    type Item = Square;
+
+    #[inline]
+    fn next(&mut self) -> Option<Square> {
+        self.current = self.current.shift(self.direction);
+        self.current.to_square()
+    }
+}
@@ -0,0 +1,217 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt;
+use core::str::FromStr;
+
+use super::{Variant, play_move};
+use crate::castle_rights::{DESTINATION, SOURCE};
+use crate::{
+    BitBoard, Board, BoardParseError, CastleRights, CastleSide, Color, FenOptions, Move, Square,
+    get_between, get_king_attacks,
+};
+
+/// An atomic chess position, following the Lichess rules.
+///
+/// Every capture causes an explosion on the destination square that removes the capturing
+/// piece, the captured piece and all pieces other than pawns on the surrounding squares.
+/// A side wins by exploding the enemy king, so:
+///
+/// - kings cannot capture, and no move may explode the mover's own king;
+/// - a move that explodes the enemy king is legal even if it leaves the own king attacked;
+/// - kings standing next to each other cannot be in check, since capturing one would
+///   explode the other.
+///
+/// The [`Board`] follows these check semantics, so `board.checkers` is empty while the kings
+/// touch, and one of the kings is missing once the game is over: the [`Board`] methods that
+/// need both kings must not be used on such a position.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let position: Atomic =
+///     "rnbqkbnr/ppp1pppp/8/3p4/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2".parse().unwrap();
+/// let position: Atomic = position.make_move(position.move_from_uci("d5e4").unwrap());
+/// assert_eq!(position.board.piece_on(Square::F3), None);
+/// assert_eq!(position.board.piece_on(Square::E4), None);
+///
+/// let position: Atomic = position.make_move(position.move_from_uci("d1e2").unwrap());
+/// let position: Atomic = position.make_move(position.move_from_uci("d8d2").unwrap());
+/// assert_eq!(position.winner(), Some(Color::Black));
+/// assert_eq!(Atomic::default().perft(4), 197326);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Atomic {
+    /// The pieces on the board, castling rights and move counters.
+    pub board: Board,
+}
+
+impl Default for Atomic {
+    /// The standard starting position.
+    fn default() -> Self {
+        Self::new(Board::default())
+    }
+}
+
+impl FromStr for Atomic {
+    type Err = BoardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Atomic::from_fen_with(s, FenOptions::STRICT)
+    }
+}
+
+/// Formats the position as a FEN string.
+impl fmt::Display for Atomic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.board.to_fen())
+    }
+}
+
+impl Atomic {
+    /// Creates an atomic position from a board, recomputing its checkers with
+    /// the atomic check rules.
+    pub fn new(mut board: Board) -> Self {
+        board.checkers = atomic_checkers(&board);
+        Self { board }
+    }
+
+    /// Parses a FEN string using the given [`FenOptions`].
+    pub fn from_fen_with(s: &str, options: FenOptions) -> Result<Self, BoardParseError> {
+        Board::from_fen_with(s, options).map(Self::new)
+    }
+
+    /// Returns the square of the king of `color`, if it has not exploded.
+    #[inline(always)]
+    fn king_of(&self, color: Color) -> Option<Square> {
+        (self.board.kings() & self.board.sides_bitboard[color as usize]).to_square()
+    }
+
+    /// Returns `true` if `square` is attacked by `color` under the atomic rules: a square
+    /// next to the attacking king is never attacked, as capturing on it would explode that king.
+    fn is_attacked(&self, square: Square, color: Color, blockers: BitBoard) -> bool {
+        match self.king_of(color) {
+            Some(king) if !get_king_attacks(square).get_square(king) => {
+                !self.board.attackers_of(color, square, blockers).is_empty()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Variant for Atomic {
+    const NAME: &'static str = "atomic";
+
+    #[inline(always)]
+    fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Executes a move, applying the explosion of captures, and returns the resulting position.
+    ///
+    /// Unlike [`Board::make_move`] the move does not need to be legal in standard chess.
+    fn make_move(&self, mv: Move) -> Atomic {
+        let mut board: Board = play_move(self, mv);
+
+        if mv.is_capture() {
+            // The capturing piece explodes along with every non-pawn piece around it.
+            let dest: Square = mv.get_dest();
+            let mut castling: CastleRights = board.castling;
+            let blast: BitBoard =
+                get_king_attacks(dest) & board.combined_bitboard() & !board.pawns();
+            board.remove_piece(dest);
+            for square in blast {
+                board.remove_piece(square);
+                castling = castling.update(square, square);
+            }
+            board.zobrist.swap_castle_hash(board.castling, castling);
+            board.castling = castling;
+        }
+
+        board.checkers = atomic_checkers(&board);
+        Atomic { board }
+    }
+
+    /// Atomic legality is decided by [`Variant::is_legal`] after the explosion.
+    #[inline(always)]
+    fn king_safety(&self) -> bool {
+        false
+    }
+
+    /// Castling is allowed if no square the king stands on, crosses or lands on is
+    /// attacked under the atomic rules.
+    fn castling_allowed(&self, castle_side: CastleSide) -> bool {
+        let side: Color = self.board.side;
+        let color: usize = side as usize;
+        let castle_side: usize = castle_side as usize;
+        let king: Square = SOURCE[color];
+        let king_dest: Square = DESTINATION[castle_side][color];
+        let rook: Square = self.board.castling.rook_square(side, castle_side);
+
+        let king_path: BitBoard =
+            get_between(king, king_dest) | king.to_bitboard() | king_dest.to_bitboard();
+        let blockers: BitBoard =
+            self.board.combined_bitboard() & !king.to_bitboard() & !rook.to_bitboard();
+
+        king_path
+            .into_iter()
+            .all(|square| !self.is_attacked(square, !side, blockers))
+    }
+
+    /// Returns `true` if a pseudo-legal move keeps the own king and either explodes the
+    /// enemy king or leaves the own king not attacked. Kings never capture, as the
+    /// explosion would take the capturing king with it.
+    fn is_legal(&self, mv: Move) -> bool {
+        let side: Color = self.board.side;
+        let next: Atomic = self.make_move(mv);
+        match (next.king_of(side), next.king_of(!side)) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(king), Some(_)) => !next.is_attacked(king, !side, next.board.combined_bitboard()),
+        }
+    }
+
+    /// Returns `true` if only the kings remain, possibly with a single knight or bishop:
+    /// a lone minor piece can neither mate nor explode the enemy king.
+    fn insufficient_material(&self) -> bool {
+        let board: &Board = &self.board;
+        (board.pawns() | board.rooks() | board.queens()).is_empty()
+            && (board.knights() | board.bishops()).count_bits() <= 1
+    }
+
+    /// Returns the side that exploded the enemy king, if the game is over.
+    fn winner(&self) -> Option<Color> {
+        [Color::White, Color::Black]
+            .into_iter()
+            .find(|&color| self.king_of(!color).is_none())
+    }
+}
+
+/// Computes the checkers of the side to move under the atomic rules: none when
+/// a king has exploded or the two kings stand next to each other.
+fn atomic_checkers(board: &Board) -> BitBoard {
+    let kings: BitBoard = board.kings();
+    match (
+        board.allied_king().to_square(),
+        board.enemy_king().to_square(),
+    ) {
+        (Some(king), Some(_)) if !get_king_attacks(king).intersects(kings) => board.checkers(),
+        _ => BitBoard::EMPTY,
+    }
+}
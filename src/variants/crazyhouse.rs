@@ -0,0 +1,532 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use super::Variant;
+use crate::zobrist::splitmix64;
+use crate::{
+    AllMoves, BitBoard, Board, BoardParseError, Color, FenOptions, Move, MoveFilter, Piece,
+    PieceType, Square, UciMoveError, Zobrist, count_legal_moves, enumerate_legal_moves,
+    get_between,
+};
+
+/// The piece types that can be held in a pocket, in the order they are written in FEN.
+const POCKET_PIECES: [PieceType; 5] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+    PieceType::Pawn,
+];
+
+/// Highest pocket count with its own hash key, enough for every pawn of a game.
+const MAX_POCKET_COUNT: usize = 16;
+
+/// Pocket hash keys indexed by color, piece type and count. A count of zero hashes to zero,
+/// so a crazyhouse position with empty pockets shares the key of its [`Board`].
+const KEY_POCKET: [[[u64; MAX_POCKET_COUNT + 1]; PieceType::KING]; 2] = pocket_keys();
+
+const fn pocket_keys() -> [[[u64; MAX_POCKET_COUNT + 1]; PieceType::KING]; 2] {
+    let mut state: u64 = 0x6372_617A_7968_6F75;
+    let mut keys: [[[u64; MAX_POCKET_COUNT + 1]; PieceType::KING]; 2] =
+        [[[0; MAX_POCKET_COUNT + 1]; PieceType::KING]; 2];
+
+    let mut color: usize = 0;
+    while color < 2 {
+        let mut piece: usize = 0;
+        while piece < PieceType::KING {
+            let mut count: usize = 1;
+            while count <= MAX_POCKET_COUNT {
+                keys[color][piece][count] = splitmix64(&mut state);
+                count += 1;
+            }
+            piece += 1;
+        }
+        color += 1;
+    }
+    keys
+}
+
+/// The pieces each side holds in hand in crazyhouse, indexed by color and piece type.
+///
+/// Kings are never held, so their count is always zero.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let mut pockets: Pockets = "QNp".parse().unwrap();
+/// assert_eq!(pockets.count(Color::White, PieceType::Knight), 1);
+/// assert!(pockets.remove(Color::Black, PieceType::Pawn));
+/// pockets.add(Color::White, PieceType::Knight);
+/// assert_eq!(pockets.to_string(), "QNN");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Pockets(pub [[u8; PieceType::KING]; 2]);
+
+impl Pockets {
+    /// Creates pockets holding no pieces.
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        Self([[0; PieceType::KING]; 2])
+    }
+
+    /// Returns the number of pieces of the given type held by `color`.
+    #[inline(always)]
+    pub const fn count(&self, color: Color, piece_type: PieceType) -> u8 {
+        match piece_type {
+            PieceType::King => 0,
+            _ => self.0[color as usize][piece_type as usize],
+        }
+    }
+
+    /// Returns `true` if neither side holds any piece.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::empty()
+    }
+
+    /// Adds a piece of the given type to the pocket of `color`.
+    ///
+    /// # Panics
+    /// Panics if `piece_type` is [`PieceType::King`].
+    #[inline(always)]
+    pub fn add(&mut self, color: Color, piece_type: PieceType) {
+        assert_ne!(
+            piece_type,
+            PieceType::King,
+            "kings cannot be held in a pocket"
+        );
+        let count: &mut u8 = &mut self.0[color as usize][piece_type as usize];
+        *count = count.saturating_add(1);
+    }
+
+    /// Removes a piece of the given type from the pocket of `color`,
+    /// returning `false` if there was none.
+    #[inline(always)]
+    pub fn remove(&mut self, color: Color, piece_type: PieceType) -> bool {
+        if self.count(color, piece_type) == 0 {
+            return false;
+        }
+        self.0[color as usize][piece_type as usize] -= 1;
+        true
+    }
+
+    /// Returns the [`Zobrist`] key of the pocket contents, to be combined with the board key.
+    pub fn zobrist(&self) -> Zobrist {
+        let mut key: u64 = 0;
+        for (color, counts) in self.0.iter().enumerate() {
+            for (piece, &count) in counts.iter().enumerate() {
+                key ^= KEY_POCKET[color][piece][(count as usize).min(MAX_POCKET_COUNT)];
+            }
+        }
+        Zobrist(key)
+    }
+}
+
+/// Formats the pockets as in the crazyhouse FEN pocket field, white pieces first,
+/// for example `QRbnp`.
+impl fmt::Display for Pockets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for color in [Color::White, Color::Black] {
+            for piece_type in POCKET_PIECES {
+                let piece: Piece = Piece::new(piece_type, color);
+                for _ in 0..self.count(color, piece_type) {
+                    f.write_char(piece.to_char())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the contents of a crazyhouse FEN pocket field, without the brackets.
+/// Uppercase letters are white pieces and lowercase letters black pieces.
+impl FromStr for Pockets {
+    type Err = BoardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pockets: Pockets = Pockets::empty();
+        for token in s.chars() {
+            match Piece::try_from(token) {
+                Ok(piece) if piece.piece_type() != PieceType::King => {
+                    pockets.add(piece.color(), piece.piece_type())
+                }
+                _ => return Err(BoardParseError::InvalidPocket),
+            }
+        }
+        Ok(pockets)
+    }
+}
+
+/// A crazyhouse position: a [`Board`] together with the pockets of both sides and
+/// the promoted pieces, which go back to the pocket as pawns when captured.
+///
+/// Captured pieces change sides and are held in the capturer's pocket, and instead of
+/// moving, a side may drop a held piece onto any empty square as a [`MoveType::Drop`] move.
+/// Pawns are never dropped on the first or last rank.
+///
+/// [`MoveType::Drop`]: crate::MoveType::Drop
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let position: Crazyhouse =
+///     "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R[] w KQkq - 2 3".parse().unwrap();
+/// let position: Crazyhouse = position.make_move(position.move_from_uci("f3e5").unwrap());
+/// assert_eq!(position.pockets.count(Color::White, PieceType::Pawn), 1);
+///
+/// let position: Crazyhouse = position.make_move(position.move_from_uci("c6e5").unwrap());
+/// let drop: Move = position.move_from_uci("P@d6").unwrap();
+/// assert_eq!(drop, Move::new_drop(PieceType::Pawn, Square::D6));
+/// assert_eq!(
+///     position.make_move(drop).to_string(),
+///     "r1bqkbnr/pppp1ppp/3P4/4n3/4P3/8/PPPP1PPP/RNBQKB1R[n] b KQkq - 0 4"
+/// );
+/// assert_eq!(Crazyhouse::default().perft(4), 197281);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Crazyhouse {
+    /// The pieces on the board, castling rights and move counters.
+    pub board: Board,
+
+    /// The pieces held in hand by each side.
+    pub pockets: Pockets,
+
+    /// The squares of pieces that were promoted from pawns.
+    pub promoted: BitBoard,
+
+    /// Whether a drop may deliver checkmate. Standard crazyhouse allows it; disabling it
+    /// gives the rule of some drop variants where a drop must never mate.
+    pub drop_mates: bool,
+}
+
+impl Default for Crazyhouse {
+    /// The standard starting position with empty pockets.
+    fn default() -> Self {
+        Self::new(Board::default())
+    }
+}
+
+impl FromStr for Crazyhouse {
+    type Err = BoardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Crazyhouse::from_fen_with(s, FenOptions::STRICT)
+    }
+}
+
+impl Crazyhouse {
+    /// Creates a crazyhouse position from a board, with empty pockets and no promoted pieces.
+    pub const fn new(board: Board) -> Self {
+        Self {
+            board,
+            pockets: Pockets::empty(),
+            promoted: BitBoard::EMPTY,
+            drop_mates: true,
+        }
+    }
+
+    /// Parses a crazyhouse FEN string using the given [`FenOptions`].
+    ///
+    /// The pocket is read either from a bracketed field after the piece placement, as in
+    /// `RNBQKBNR[Qp] w`, or from a ninth rank as in `RNBQKBNR/Qp w`. Promoted pieces are
+    /// marked with a `~` after their letter. A FEN without a pocket has empty pockets.
+    pub fn from_fen_with(s: &str, options: FenOptions) -> Result<Self, BoardParseError> {
+        let mut fen_iter: core::str::SplitWhitespace<'_> = s.split_whitespace();
+        let field: &str = fen_iter.next().ok_or(BoardParseError::FenTooShort)?;
+
+        let (placement, pocket) = if let Some((placement, pocket)) = field.split_once('[') {
+            let pocket: &str = pocket
+                .strip_suffix(']')
+                .ok_or(BoardParseError::InvalidPocket)?;
+            (placement, pocket)
+        } else if field.matches('/').count() == 8 {
+            field.rsplit_once('/').unwrap_or((field, ""))
+        } else {
+            (field, "")
+        };
+
+        // Strip the promotion markers, remembering the square of each marked piece.
+        let mut buffer: [u8; 128] = [0; 128];
+        let mut len: usize = 0;
+        let mut promoted: BitBoard = BitBoard::EMPTY;
+        let (mut file, mut rank) = (0usize, 7usize);
+        for byte in placement.bytes() {
+            match byte {
+                b'~' => {
+                    if file == 0 || file > 8 || !buffer[len - 1].is_ascii_alphabetic() {
+                        return Err(BoardParseError::InvalidBoardLayout);
+                    }
+                    promoted = promoted.set_square(Square::from_index(rank * 8 + file - 1));
+                    continue;
+                }
+                b'/' => {
+                    rank = rank
+                        .checked_sub(1)
+                        .ok_or(BoardParseError::InvalidBoardLayout)?;
+                    file = 0;
+                }
+                b'1'..=b'8' => file += (byte - b'0') as usize,
+                _ => file += 1,
+            }
+            if len == buffer.len() {
+                return Err(BoardParseError::InvalidBoardLayout);
+            }
+            buffer[len] = byte;
+            len += 1;
+        }
+        let placement: &str = core::str::from_utf8(&buffer[..len])
+            .map_err(|_| BoardParseError::InvalidBoardLayout)?;
+
+        let board: Board = Board::from_fen_fields(placement, fen_iter, options, None)?;
+        Ok(Self {
+            board,
+            pockets: pocket.parse()?,
+            promoted: promoted & board.combined_bitboard(),
+            drop_mates: true,
+        })
+    }
+
+    /// Returns the [`Zobrist`] key of the position, covering the board and the pockets.
+    #[inline(always)]
+    pub fn zobrist(&self) -> Zobrist {
+        Zobrist(self.board.zobrist.0 ^ self.pockets.zobrist().0)
+    }
+
+    /// The squares a piece can be dropped on: every empty square when not in check, the
+    /// squares between the king and the checker in single check, and none in double check.
+    fn drop_targets(&self) -> BitBoard {
+        let empty: BitBoard = !self.board.combined_bitboard();
+        match self.board.checkers.count_bits() {
+            0 => empty,
+            1 => {
+                let king: Square = self.board.allied_king_square();
+                let checker: Square = unsafe { self.board.checkers.to_square().unwrap_unchecked() };
+                get_between(king, checker) & empty
+            }
+            _ => BitBoard::EMPTY,
+        }
+    }
+
+    /// Returns `true` if the drop checkmates the opponent.
+    fn is_drop_mate(&self, mv: Move) -> bool {
+        let next: Crazyhouse = self.make_move(mv);
+        !next.board.checkers.is_empty() && !next.has_legal_moves()
+    }
+
+    /// Returns `true` if the side to move has a legal board move or drop.
+    fn has_legal_moves(&self) -> bool {
+        if count_legal_moves::<AllMoves>(&self.board) > 0 {
+            return true;
+        }
+
+        let side: Color = self.board.side;
+        let targets: BitBoard = self.drop_targets();
+        POCKET_PIECES.iter().any(|&piece_type| {
+            self.pockets.count(side, piece_type) > 0
+                && match piece_type {
+                    PieceType::Pawn => targets.intersects(!(BitBoard::RANK_1 | BitBoard::RANK_8)),
+                    _ => !targets.is_empty(),
+                }
+        })
+    }
+}
+
+impl Variant for Crazyhouse {
+    const NAME: &'static str = "crazyhouse";
+
+    #[inline(always)]
+    fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Enumerates all legal moves of the position, board moves first and then drops,
+    /// passing each one to the handler, which may return `false` to stop the enumeration.
+    /// Drops are quiet moves, so they are only generated when the filter `M` includes
+    /// quiet moves.
+    fn enumerate_moves<M, F>(&self, mut handler: F) -> bool
+    where
+        M: MoveFilter,
+        F: FnMut(Move) -> bool,
+    {
+        // The core generator keeps going after the handler returns `false`, so later board
+        // moves are dropped here instead.
+        let mut stopped: bool = false;
+        enumerate_legal_moves::<M, _>(&self.board, |mv: Move| -> bool {
+            stopped = stopped || !handler(mv);
+            !stopped
+        });
+        if stopped {
+            return false;
+        }
+        if !M::QUIETS {
+            return true;
+        }
+
+        let side: Color = self.board.side;
+        let targets: BitBoard = self.drop_targets();
+        for piece_type in POCKET_PIECES {
+            if self.pockets.count(side, piece_type) == 0 {
+                continue;
+            }
+
+            let squares: BitBoard = match piece_type {
+                PieceType::Pawn => targets & !(BitBoard::RANK_1 | BitBoard::RANK_8),
+                _ => targets,
+            };
+            for square in squares {
+                let mv: Move = Move::new_drop(piece_type, square);
+                if (self.drop_mates || !self.is_drop_mate(mv)) && !handler(mv) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Executes a board move or a drop, returning the resulting position.
+    ///
+    /// Captured pieces go to the pocket of the side to move, promoted pieces as pawns.
+    ///
+    /// # Panics
+    /// Panics if a drop is made with a piece that is not in the pocket of the side to move,
+    /// and in the cases [`Board::make_move`] panics for board moves.
+    fn make_move(&self, mv: Move) -> Crazyhouse {
+        let mut next: Crazyhouse = *self;
+        let side: Color = self.board.side;
+        let dest: Square = mv.get_dest();
+
+        if mv.is_drop() {
+            let piece_type: PieceType = mv.get_drop_piece();
+            assert!(
+                next.pockets.remove(side, piece_type),
+                "no {piece_type} to drop in the pocket"
+            );
+
+            let board: &mut Board = &mut next.board;
+            board.set_piece(Piece::new(piece_type, side), dest);
+
+            if let Some(square) = board.enpassant_square.take() {
+                board.zobrist.hash_enpassant(square);
+            }
+
+            board.fifty_move = if piece_type == PieceType::Pawn {
+                0
+            } else {
+                board.fifty_move + 1
+            };
+
+            if side == Color::Black {
+                board.full_move = board.full_move.saturating_add(1);
+            }
+
+            board.side = !side;
+            board.zobrist.hash_side();
+            board.checkers = board.checkers();
+            return next;
+        }
+
+        if mv.is_capture() {
+            let victim: PieceType = match self.board.piece_on(dest) {
+                Some(piece) if !self.promoted.get_square(dest) => piece.piece_type(),
+                _ => PieceType::Pawn,
+            };
+            next.pockets.add(side, victim);
+        }
+
+        let src: Square = mv.get_src();
+        next.promoted = self.promoted.pop_square(src).pop_square(dest);
+        if mv.is_promotion() || self.promoted.get_square(src) {
+            next.promoted = next.promoted.set_square(dest);
+        }
+
+        next.board = self.board.make_move(mv);
+        next
+    }
+
+    /// Finds the legal move matching a UCI move string, where drops are written
+    /// as the piece letter, `@` and the destination square, for example `N@f3`.
+    ///
+    /// # Errors
+    /// Returns a [`UciMoveError`] if the string is malformed or the move is not legal.
+    fn move_from_uci(&self, uci: &str) -> Result<Move, UciMoveError> {
+        let Some((piece, square)) = uci.split_once('@') else {
+            return self.board.move_from_uci(uci);
+        };
+
+        let mut chars: core::str::Chars<'_> = piece.chars();
+        let (Some(token), None) = (chars.next(), chars.next()) else {
+            return Err(UciMoveError::InvalidLength);
+        };
+        let piece_type: PieceType = match Piece::try_from(token.to_ascii_uppercase()) {
+            Ok(piece) if piece.piece_type() != PieceType::King => piece.piece_type(),
+            _ => return Err(UciMoveError::InvalidDropPiece(token)),
+        };
+        let dest: Square = square.parse().map_err(UciMoveError::InvalidSquare)?;
+
+        let mv: Move = Move::new_drop(piece_type, dest);
+        let mut legal: bool = false;
+        self.enumerate_moves::<AllMoves, _>(|candidate| {
+            legal |= candidate == mv;
+            true
+        });
+        if legal {
+            Ok(mv)
+        } else {
+            Err(UciMoveError::IllegalMove)
+        }
+    }
+}
+
+/// Formats the position as a crazyhouse FEN string, with the pocket in brackets after
+/// the piece placement and a `~` after every promoted piece.
+impl fmt::Display for Crazyhouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for rank in (0..8usize).rev() {
+            let mut empty_squares: usize = 0;
+            for file in 0..8usize {
+                let square: Square = Square::from_index(rank * 8 + file);
+                if let Some(piece) = self.board.piece_on(square) {
+                    if empty_squares > 0 {
+                        write!(f, "{}", empty_squares)?;
+                        empty_squares = 0;
+                    }
+                    f.write_char(piece.to_char())?;
+                    if self.promoted.get_square(square) {
+                        f.write_char('~')?;
+                    }
+                } else {
+                    empty_squares += 1;
+                }
+            }
+            if empty_squares > 0 {
+                write!(f, "{}", empty_squares)?;
+            }
+            if rank > 0 {
+                f.write_char('/')?;
+            }
+        }
+
+        write!(f, "[{}] ", self.pockets)?;
+        self.board.write_state(f)?;
+        write!(f, " {} {}", self.board.fifty_move, self.board.full_move)
+    }
+}
@@ -0,0 +1,141 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt;
+use core::str::FromStr;
+
+use super::{Variant, play_move};
+use crate::{BitBoard, Board, BoardParseError, BoardValidationError, Color, FenOptions, Move};
+
+/// A Horde position, following the Lichess rules: White plays a horde of pawns and no king
+/// against the regular black army.
+///
+/// Black wins by capturing every white piece and White wins by checkmating the black king.
+/// White pawns on the first rank may double push, without creating an en passant square.
+///
+/// Since White has no king, the [`Board`] methods that need the white king, such as
+/// [`Board::make_move`], must not be used on White's turn.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let position: Horde = Horde::default();
+/// assert_eq!(position.board.pawns().count_bits(), 44);
+///
+/// let position: Horde = position.make_move(position.move_from_uci("f5f6").unwrap());
+/// let position: Horde = position.make_move(position.move_from_uci("e7f6").unwrap());
+/// assert!(position.move_from_uci("e1e3").is_err());
+/// assert_eq!(position.validate(), Ok(()));
+/// assert_eq!(Horde::default().perft(3), 1274);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Horde {
+    /// The pieces on the board, castling rights and move counters.
+    pub board: Board,
+}
+
+impl Horde {
+    /// The FEN string of the Horde starting position.
+    pub const START_FEN: &str =
+        "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1";
+}
+
+impl Default for Horde {
+    /// The Horde starting position.
+    fn default() -> Self {
+        Horde::START_FEN.parse().unwrap()
+    }
+}
+
+impl FromStr for Horde {
+    type Err = BoardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Horde::from_fen_with(s, FenOptions::STRICT)
+    }
+}
+
+/// Formats the position as a FEN string.
+impl fmt::Display for Horde {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.board.to_fen())
+    }
+}
+
+impl Horde {
+    /// Parses a FEN string using the given [`FenOptions`]. White must have no king.
+    pub fn from_fen_with(s: &str, options: FenOptions) -> Result<Self, BoardParseError> {
+        let mut fen_iter: core::str::SplitWhitespace<'_> = s.split_whitespace();
+        let board_str: &str = fen_iter.next().ok_or(BoardParseError::FenTooShort)?;
+        let board: Board =
+            Board::from_fen_fields(board_str, fen_iter, options, Some(Color::White))?;
+        Ok(Self { board })
+    }
+
+    /// Verifies the invariants of the position like [`Board::validate`], except that White
+    /// has no king and may have pawns on the first rank.
+    ///
+    /// # Errors
+    /// Returns the first [`BoardValidationError`] found.
+    pub fn validate(&self) -> Result<(), BoardValidationError> {
+        self.board.validate_with(Some(Color::White))
+    }
+}
+
+impl Variant for Horde {
+    const NAME: &'static str = "horde";
+
+    #[inline(always)]
+    fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Executes a legal move and returns the resulting position.
+    fn make_move(&self, mv: Move) -> Horde {
+        let mut board: Board = play_move(self, mv);
+        board.checkers = match board.side {
+            Color::White => BitBoard::EMPTY,
+            Color::Black => board.checkers(),
+        };
+        Horde { board }
+    }
+
+    /// Without a king, every pseudo-legal move of the horde is legal.
+    #[inline(always)]
+    fn king_safety(&self) -> bool {
+        self.board.side == Color::Black
+    }
+
+    /// White pawns on the first rank may double push.
+    #[inline(always)]
+    fn double_push_squares(&self) -> BitBoard {
+        match self.board.side {
+            Color::White => BitBoard::RANK_1,
+            Color::Black => BitBoard::EMPTY,
+        }
+    }
+
+    /// Returns [`Color::Black`] once every white piece has been captured.
+    fn winner(&self) -> Option<Color> {
+        self.board
+            .white_bitboard()
+            .is_empty()
+            .then_some(Color::Black)
+    }
+}
@@ -0,0 +1,110 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt;
+use core::str::FromStr;
+
+use super::Variant;
+use crate::{Board, BoardParseError, Color, FenOptions, Move, UciMoveError};
+
+/// A King of the Hill position: standard chess where a side also wins by bringing its
+/// king to one of the four [`BitBoard::CENTER`] squares. Material is never insufficient,
+/// since a bare king can still walk to the center.
+///
+/// [`BitBoard::CENTER`]: crate::BitBoard::CENTER
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let position: KingOfTheHill = "8/8/8/8/8/3K4/8/7k w - - 0 1".parse().unwrap();
+/// assert_eq!(position.status(), GameResult::Unfinished);
+///
+/// let position: KingOfTheHill = position.make_move(position.move_from_uci("d3d4").unwrap());
+/// assert_eq!(position.status(), GameResult::WhiteWins);
+/// assert_eq!(position.perft(1), 0);
+/// assert_eq!(KingOfTheHill::default().perft(4), 197281);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct KingOfTheHill {
+    /// The pieces on the board, castling rights and move counters.
+    pub board: Board,
+}
+
+impl FromStr for KingOfTheHill {
+    type Err = BoardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KingOfTheHill::from_fen_with(s, FenOptions::STRICT)
+    }
+}
+
+/// Formats the position as a FEN string.
+impl fmt::Display for KingOfTheHill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.board.to_fen())
+    }
+}
+
+impl KingOfTheHill {
+    /// Parses a FEN string using the given [`FenOptions`].
+    pub fn from_fen_with(s: &str, options: FenOptions) -> Result<Self, BoardParseError> {
+        Board::from_fen_with(s, options).map(|board| Self { board })
+    }
+}
+
+impl Variant for KingOfTheHill {
+    const NAME: &'static str = "kingofthehill";
+
+    #[inline(always)]
+    fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Executes a legal move and returns the resulting position, see [`Board::make_move`].
+    fn make_move(&self, mv: Move) -> KingOfTheHill {
+        KingOfTheHill {
+            board: self.board.make_move(mv),
+        }
+    }
+
+    /// Finds the legal move matching a UCI move string, see [`Board::move_from_uci`].
+    ///
+    /// # Errors
+    /// Returns a [`UciMoveError`] if the string is malformed or the move is not legal,
+    /// which includes every move once a king has reached the center.
+    fn move_from_uci(&self, uci: &str) -> Result<Move, UciMoveError> {
+        let mv: Move = self.board.move_from_uci(uci)?;
+        if self.winner().is_some() {
+            return Err(UciMoveError::IllegalMove);
+        }
+        Ok(mv)
+    }
+
+    /// Returns the side whose king stands on a center square, which ends the game.
+    #[inline]
+    fn winner(&self) -> Option<Color> {
+        if self.board.king_in_center(Color::White) {
+            Some(Color::White)
+        } else if self.board.king_in_center(Color::Black) {
+            Some(Color::Black)
+        } else {
+            None
+        }
+    }
+}
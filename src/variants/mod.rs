@@ -0,0 +1,303 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::board::movegen::{MoveRules, castling_path_safe, enumerate_moves_with_rules};
+use crate::{
+    AllMoves, BitBoard, Board, BoardParseError, CastleSide, Color, GameResult, Move, MoveFilter,
+    MoveList, PieceType, Square, UciMoveError, enumerate_legal_moves,
+};
+
+pub mod atomic;
+pub mod crazyhouse;
+pub mod horde;
+pub mod koth;
+pub mod three_check;
+
+/// The rules of a chess variant: how its positions generate and make moves and when
+/// its games end.
+///
+/// [`Board`] implements the trait for standard chess by delegating to its own move
+/// generator, so code written against a `V: Variant` compiles to the same calls as code
+/// written against a [`Board`]. The moves of a variant come from the same generator, which
+/// consults the rule hooks of the trait: [`Variant::king_safety`],
+/// [`Variant::double_push_squares`], [`Variant::is_promotion_target`],
+/// [`Variant::castling_allowed`], [`Variant::is_legal`] and, to end the game,
+/// [`Variant::winner`] and [`Variant::insufficient_material`]. Each hook defaults to
+/// standard chess, so a variant only overrides the rules that differ and its move
+/// execution, and gets UCI move lookup, [`MoveList`] generation, perft and the game
+/// status on top.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// fn count<V: Variant>(fen: &str, depth: usize) -> u64 {
+///     fen.parse::<V>().unwrap().perft(depth)
+/// }
+///
+/// let fen: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+/// assert_eq!(count::<Board>(fen, 3), 8902);
+/// assert_eq!(count::<Crazyhouse>(fen, 3), 8902);
+/// assert_eq!(count::<Atomic>(fen, 3), 8902);
+/// ```
+pub trait Variant: Copy + Default + FromStr<Err = BoardParseError> + fmt::Display {
+    /// The name of the variant as used by the `UCI_Variant` option.
+    const NAME: &'static str;
+
+    /// Returns the pieces on the board, castling rights and move counters of the position.
+    fn board(&self) -> &Board;
+
+    /// Enumerates all legal moves of the position allowed by the filter `M`, passing each
+    /// one to the handler, which may return `false` to stop the enumeration.
+    ///
+    /// The moves come from the core move generator under the rule hooks of the variant,
+    /// and none are generated once [`Variant::winner`] has ended the game.
+    #[inline(always)]
+    fn enumerate_moves<M, F>(&self, handler: F) -> bool
+    where
+        M: MoveFilter,
+        F: FnMut(Move) -> bool,
+    {
+        self.winner().is_some()
+            || enumerate_moves_with_rules::<_, M, F>(self.board(), &VariantRules(self), handler)
+    }
+
+    /// Executes a legal move and returns the resulting position.
+    fn make_move(&self, mv: Move) -> Self;
+
+    /// Whether moves must not leave the own king attacked, as in standard chess. Without
+    /// king safety the generator ignores pins and checks, lets the king step onto attacked
+    /// squares, and leaves legality to [`Variant::is_legal`].
+    #[inline(always)]
+    fn king_safety(&self) -> bool {
+        true
+    }
+
+    /// The squares, besides the second rank, from which pawns of the side to move may
+    /// double push. These double pushes do not set an en passant square.
+    #[inline(always)]
+    fn double_push_squares(&self) -> BitBoard {
+        BitBoard::EMPTY
+    }
+
+    /// Whether pawns may promote to the given piece type. Standard chess allows the
+    /// knight, bishop, rook and queen.
+    #[inline(always)]
+    fn is_promotion_target(&self, piece_type: PieceType) -> bool {
+        !matches!(piece_type, PieceType::Pawn | PieceType::King)
+    }
+
+    /// Whether the side to move may castle on `side`, once it holds the castling right,
+    /// the path between its king and rook is empty and its king is not in check. Standard
+    /// chess forbids castling through or onto an attacked square.
+    #[inline(always)]
+    fn castling_allowed(&self, side: CastleSide) -> bool {
+        castling_path_safe(self.board(), side)
+    }
+
+    /// An extra legality filter applied to every generated move. Standard chess needs none.
+    #[inline(always)]
+    fn is_legal(&self, _mv: Move) -> bool {
+        true
+    }
+
+    /// Returns the side that has won by a rule of the variant, such as exploding the
+    /// enemy king, regardless of the moves left. Standard chess has no such rule.
+    #[inline(always)]
+    fn winner(&self) -> Option<Color> {
+        None
+    }
+
+    /// Returns `true` if neither side can win under the rules of the variant with the
+    /// material left on the board. The default never reports insufficient material.
+    #[inline(always)]
+    fn insufficient_material(&self) -> bool {
+        false
+    }
+
+    /// Returns the outcome of the position: a win by [`Variant::winner`], checkmate,
+    /// stalemate, the fifty-move rule or [`Variant::insufficient_material`], and
+    /// [`GameResult::Unfinished`] otherwise.
+    fn status(&self) -> GameResult {
+        match self.winner() {
+            Some(Color::White) => return GameResult::WhiteWins,
+            Some(Color::Black) => return GameResult::BlackWins,
+            None => {}
+        }
+
+        let board: &Board = self.board();
+        let mut has_moves: bool = false;
+        self.enumerate_moves::<AllMoves, _>(|_| {
+            has_moves = true;
+            false
+        });
+        if !has_moves {
+            return match (board.current_checkers().is_empty(), board.side) {
+                (true, _) => GameResult::Draw,
+                (false, Color::White) => GameResult::BlackWins,
+                (false, Color::Black) => GameResult::WhiteWins,
+            };
+        }
+
+        if board.fifty_move >= 100 || self.insufficient_material() {
+            GameResult::Draw
+        } else {
+            GameResult::Unfinished
+        }
+    }
+
+    /// Finds the legal move matching a UCI move string.
+    ///
+    /// # Errors
+    /// Returns a [`UciMoveError`] if the string is malformed or the move is not legal.
+    fn move_from_uci(&self, uci: &str) -> Result<Move, UciMoveError> {
+        let parsed: (Square, Square, Option<PieceType>) = Move::parse_uci(uci)?;
+        let side: Color = self.board().side;
+        let mut found: Option<Move> = None;
+        self.enumerate_moves::<AllMoves, _>(|mv| {
+            if matches_uci(mv, side, parsed) {
+                found = Some(mv);
+            }
+            found.is_none()
+        });
+        found.ok_or(UciMoveError::IllegalMove)
+    }
+
+    /// Generates the legal moves of the position into a [`MoveList`].
+    ///
+    /// Positions with more legal moves than a [`MoveList`] holds, such as crazyhouse
+    /// positions with full pockets, lose the extra moves: use [`Variant::enumerate_moves`]
+    /// to visit every move.
+    fn gen_moves<M: MoveFilter>(&self) -> MoveList {
+        let mut move_list: MoveList = MoveList::default();
+        self.enumerate_moves::<M, _>(|mv| -> bool {
+            move_list.push(mv);
+            true
+        });
+        move_list
+    }
+
+    /// Counts the leaf nodes of the legal move tree of the given depth, like [`crate::perft`].
+    fn perft(&self, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let mut nodes: u64 = 0;
+        self.enumerate_moves::<AllMoves, _>(|mv: Move| -> bool {
+            nodes += if depth == 1 {
+                1
+            } else {
+                self.make_move(mv).perft(depth - 1)
+            };
+            true
+        });
+        nodes
+    }
+}
+
+/// Standard chess, backed by the regular move generator.
+impl Variant for Board {
+    const NAME: &'static str = "chess";
+
+    #[inline(always)]
+    fn board(&self) -> &Board {
+        self
+    }
+
+    #[inline(always)]
+    fn enumerate_moves<M, F>(&self, handler: F) -> bool
+    where
+        M: MoveFilter,
+        F: FnMut(Move) -> bool,
+    {
+        enumerate_legal_moves::<M, F>(self, handler)
+    }
+
+    #[inline(always)]
+    fn make_move(&self, mv: Move) -> Board {
+        Board::make_move(self, mv)
+    }
+
+    #[inline(always)]
+    fn status(&self) -> GameResult {
+        Board::status(self)
+    }
+
+    #[inline(always)]
+    fn move_from_uci(&self, uci: &str) -> Result<Move, UciMoveError> {
+        Board::move_from_uci(self, uci)
+    }
+
+    #[inline(always)]
+    fn gen_moves<M: MoveFilter>(&self) -> MoveList {
+        crate::gen_moves::<M>(self)
+    }
+
+    #[inline(always)]
+    fn perft(&self, depth: usize) -> u64 {
+        crate::perft(self, depth)
+    }
+}
+
+/// Returns `true` if the move has the source, destination and promotion of a parsed UCI move.
+#[inline(always)]
+pub(crate) fn matches_uci(mv: Move, side: Color, uci: (Square, Square, Option<PieceType>)) -> bool {
+    let prom: Option<PieceType> = mv.is_promotion().then(|| mv.get_prom(side).piece_type());
+    (mv.get_src(), mv.get_dest(), prom) == uci
+}
+
+/// Adapts the rule hooks of a [`Variant`] to the core move generator and move maker.
+pub(crate) struct VariantRules<'a, V>(pub(crate) &'a V);
+
+impl<V: Variant> MoveRules for VariantRules<'_, V> {
+    #[inline(always)]
+    fn king_safety(&self) -> bool {
+        self.0.king_safety()
+    }
+
+    #[inline(always)]
+    fn double_push_squares(&self) -> BitBoard {
+        self.0.double_push_squares()
+    }
+
+    #[inline(always)]
+    fn is_promotion_target(&self, piece_type: PieceType) -> bool {
+        self.0.is_promotion_target(piece_type)
+    }
+
+    #[inline(always)]
+    fn castling_allowed(&self, _board: &Board, side: CastleSide) -> bool {
+        self.0.castling_allowed(side)
+    }
+
+    #[inline(always)]
+    fn is_legal(&self, mv: Move) -> bool {
+        self.0.is_legal(mv)
+    }
+}
+
+/// Applies a move to the board of a variant with [`Board::play_move_with`] under the rule
+/// hooks of the variant, leaving the checkers of the resulting board to the caller.
+#[inline(always)]
+pub(crate) fn play_move<V: Variant>(variant: &V, mv: Move) -> Board {
+    variant.board().play_move_with(&VariantRules(variant), mv).0
+}
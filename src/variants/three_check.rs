@@ -0,0 +1,226 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2026 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use super::Variant;
+use crate::zobrist::splitmix64;
+use crate::{Board, BoardParseError, Color, FenOptions, GameResult, Move, UciMoveError, Zobrist};
+
+/// Number of checks a side has to deliver to win.
+const CHECKS_TO_WIN: u8 = 3;
+
+/// Check counter hash keys indexed by color and checks given. No checks hashes to zero,
+/// so a three-check position without checks shares the key of its [`Board`].
+const KEY_CHECKS: [[u64; CHECKS_TO_WIN as usize + 1]; 2] = check_keys();
+
+const fn check_keys() -> [[u64; CHECKS_TO_WIN as usize + 1]; 2] {
+    let mut state: u64 = 0x7468_7265_6563_6863;
+    let mut keys: [[u64; CHECKS_TO_WIN as usize + 1]; 2] = [[0; CHECKS_TO_WIN as usize + 1]; 2];
+
+    let mut color: usize = 0;
+    while color < 2 {
+        let mut count: usize = 1;
+        while count <= CHECKS_TO_WIN as usize {
+            keys[color][count] = splitmix64(&mut state);
+            count += 1;
+        }
+        color += 1;
+    }
+    keys
+}
+
+/// A three-check position: standard chess where a side also wins by giving check
+/// for the third time.
+///
+/// In FEN the counters are read either as the remaining checks after the en passant
+/// field (`3+3`) or as the checks given after the move counters (`+0+0`), and are
+/// written in the first form. A FEN without counters starts with no checks given.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let position: ThreeCheck = "4k3/8/8/8/8/8/8/4K2R w - - 2+3 0 1".parse().unwrap();
+/// assert_eq!(position.checks_given(Color::White), 1);
+///
+/// let position: ThreeCheck = position.make_move(position.move_from_uci("h1h8").unwrap());
+/// assert_eq!(position.checks_given(Color::White), 2);
+/// assert_eq!(position.to_string(), "4k2R/8/8/8/8/8/8/4K3 b - - 1+3 1 1");
+/// assert_eq!(ThreeCheck::default().perft(4), 197281);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ThreeCheck {
+    /// The pieces on the board, castling rights and move counters.
+    pub board: Board,
+
+    /// The number of checks each side has given, indexed by [`Color`].
+    pub checks: [u8; 2],
+}
+
+impl FromStr for ThreeCheck {
+    type Err = BoardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ThreeCheck::from_fen_with(s, FenOptions::STRICT)
+    }
+}
+
+/// Formats the position as a FEN string with the remaining checks after the
+/// en passant field, for example `3+3`.
+impl fmt::Display for ThreeCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.board.write_placement(f)?;
+        f.write_char(' ')?;
+        self.board.write_state(f)?;
+        write!(
+            f,
+            " {}+{} {} {}",
+            self.remaining_checks(Color::White),
+            self.remaining_checks(Color::Black),
+            self.board.fifty_move,
+            self.board.full_move
+        )
+    }
+}
+
+impl ThreeCheck {
+    /// Parses a FEN string using the given [`FenOptions`].
+    ///
+    /// # Errors
+    /// Returns [`BoardParseError::InvalidCheckCount`] if the counters are malformed,
+    /// exceed three or appear more than once, and any error of [`Board::from_fen_with`].
+    pub fn from_fen_with(s: &str, options: FenOptions) -> Result<Self, BoardParseError> {
+        let mut fen_iter: core::str::SplitWhitespace<'_> = s.split_whitespace();
+        let board_str: &str = fen_iter.next().ok_or(BoardParseError::FenTooShort)?;
+
+        // The move counters never contain `+`, so this only separates the check field.
+        let mut check_fields = fen_iter.clone().filter(|field: &&str| field.contains('+'));
+        let checks: [u8; 2] = match (check_fields.next(), check_fields.next()) {
+            (None, _) => [0; 2],
+            (Some(field), None) => parse_checks(field).ok_or(BoardParseError::InvalidCheckCount)?,
+            _ => return Err(BoardParseError::InvalidCheckCount),
+        };
+
+        let fields = fen_iter.filter(|field: &&str| !field.contains('+'));
+        let board: Board = Board::from_fen_fields(board_str, fields, options, None)?;
+        Ok(Self { board, checks })
+    }
+
+    /// Returns the number of checks the given side has delivered.
+    #[inline(always)]
+    pub const fn checks_given(&self, color: Color) -> u8 {
+        self.checks[color as usize]
+    }
+
+    /// Returns the number of checks the given side still has to deliver to win.
+    #[inline(always)]
+    pub const fn remaining_checks(&self, color: Color) -> u8 {
+        CHECKS_TO_WIN.saturating_sub(self.checks[color as usize])
+    }
+
+    /// Returns the Zobrist key of the position, combining the key of the board with
+    /// the check counters.
+    pub fn zobrist(&self) -> Zobrist {
+        let white: usize = self.checks[0].min(CHECKS_TO_WIN) as usize;
+        let black: usize = self.checks[1].min(CHECKS_TO_WIN) as usize;
+        Zobrist(self.board.zobrist.0 ^ KEY_CHECKS[0][white] ^ KEY_CHECKS[1][black])
+    }
+}
+
+impl Variant for ThreeCheck {
+    const NAME: &'static str = "3check";
+
+    #[inline(always)]
+    fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Executes a legal move and returns the resulting position, counting a check
+    /// for the side that moved if it gives one. See [`Board::make_move`].
+    fn make_move(&self, mv: Move) -> ThreeCheck {
+        let board: Board = self.board.make_move(mv);
+        let mut checks: [u8; 2] = self.checks;
+        if !board.checkers.is_empty() {
+            let given: &mut u8 = &mut checks[self.board.side as usize];
+            *given = given.saturating_add(1);
+        }
+        ThreeCheck { board, checks }
+    }
+
+    /// Finds the legal move matching a UCI move string, see [`Board::move_from_uci`].
+    ///
+    /// # Errors
+    /// Returns a [`UciMoveError`] if the string is malformed or the move is not legal,
+    /// which includes every move once a side has given its third check.
+    fn move_from_uci(&self, uci: &str) -> Result<Move, UciMoveError> {
+        let mv: Move = self.board.move_from_uci(uci)?;
+        if self.winner().is_some() {
+            return Err(UciMoveError::IllegalMove);
+        }
+        Ok(mv)
+    }
+
+    /// Returns the side that has delivered its third check, if any.
+    #[inline]
+    fn winner(&self) -> Option<Color> {
+        if self.remaining_checks(Color::White) == 0 {
+            Some(Color::White)
+        } else if self.remaining_checks(Color::Black) == 0 {
+            Some(Color::Black)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the outcome of the position like [`Board::status`], reporting a win for
+    /// the side that gave the third check. Material is only insufficient when both
+    /// sides have a bare king, since any other piece can still give check.
+    fn status(&self) -> GameResult {
+        match self.winner() {
+            Some(Color::White) => GameResult::WhiteWins,
+            Some(Color::Black) => GameResult::BlackWins,
+            None => match self.board.terminal_status() {
+                Some(result) => result,
+                None if self.board.combined_bitboard().count_bits() == 2 => GameResult::Draw,
+                None => GameResult::Unfinished,
+            },
+        }
+    }
+}
+
+/// Parses the checks given per side from a remaining-checks field such as `3+3`
+/// or a checks-given field such as `+0+0`.
+fn parse_checks(field: &str) -> Option<[u8; 2]> {
+    let (given, field) = match field.strip_prefix('+') {
+        Some(field) => (true, field),
+        None => (false, field),
+    };
+    let (white, black) = field.split_once('+')?;
+
+    let mut checks: [u8; 2] = [0; 2];
+    for (count, digits) in checks.iter_mut().zip([white, black]) {
+        let value: u8 = digits
+            .parse()
+            .ok()
+            .filter(|&value| value <= CHECKS_TO_WIN)?;
+        *count = if given { value } else { CHECKS_TO_WIN - value };
+    }
+    Some(checks)
+}
@@ -0,0 +1,117 @@
+/*
+    Laura-Core: a fast and efficient move generator for chess engines.
+
+    Copyright (C) 2024-2025 HansTibberio <hanstiberio@proton.me>
+
+    Laura-Core is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    Laura-Core is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with Laura-Core. If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! JavaScript bindings for the move generator, enabled by the `wasm` feature.
+//!
+//! Exposes a `Board` class to JavaScript through `wasm-bindgen`, with moves exchanged as UCI
+//! strings. Build the module with
+//! `cargo build --release --target wasm32-unknown-unknown --features wasm` and run
+//! `wasm-bindgen` on the output, after adding `cdylib` to the crate types
+//! (e.g. `cargo rustc --crate-type cdylib ...`).
+//!
+//! ```js
+//! const board = Board.fromFen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+//! board.legalMoves();   // ["a2a3", "b2b3", ...]
+//! board.san("g1f3");    // "Nf3"
+//! board.makeMove("g1f3");
+//! board.toFen();
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{AllMoves, Board, CastlingNotation, Move, gen_moves};
+
+/// A chess position exported to JavaScript as the `Board` class.
+#[wasm_bindgen(js_name = Board)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WasmBoard {
+    board: Board,
+}
+
+#[wasm_bindgen(js_class = Board)]
+impl WasmBoard {
+    /// Creates a board holding the standard starting position.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmBoard {
+        WasmBoard::default()
+    }
+
+    /// Parses a FEN string into a board, throwing an error if the FEN is invalid.
+    #[wasm_bindgen(js_name = fromFen)]
+    pub fn from_fen(fen: &str) -> Result<WasmBoard, JsError> {
+        Board::from_str(fen)
+            .map(|board| WasmBoard { board })
+            .map_err(|err| JsError::new(&err.to_string()))
+    }
+
+    /// Returns the FEN string of the position.
+    #[wasm_bindgen(js_name = toFen)]
+    pub fn to_fen(&self) -> String {
+        self.board.to_fen().to_string()
+    }
+
+    /// Returns the legal moves of the position as UCI strings.
+    #[wasm_bindgen(js_name = legalMoves)]
+    pub fn legal_moves(&self) -> Vec<String> {
+        gen_moves::<AllMoves>(&self.board)
+            .iter()
+            .map(|&mv| {
+                self.board
+                    .to_uci(mv, CastlingNotation::KingDestination)
+                    .to_string()
+            })
+            .collect()
+    }
+
+    /// Plays a move given as a UCI string, throwing an error if it is malformed or illegal.
+    #[wasm_bindgen(js_name = makeMove)]
+    pub fn make_move(&mut self, uci: &str) -> Result<(), JsError> {
+        self.board = self
+            .board
+            .make_uci_move(uci)
+            .map_err(|err| JsError::new(&err.to_string()))?;
+        Ok(())
+    }
+
+    /// Converts a move given as a UCI string to Standard Algebraic Notation, throwing an error
+    /// if it is malformed or illegal.
+    pub fn san(&self, uci: &str) -> Result<String, JsError> {
+        let mv: Move = self
+            .board
+            .find_move(uci)
+            .map_err(|err| JsError::new(&err.to_string()))?;
+        Ok(self.board.to_san(mv).to_string())
+    }
+}
+
+impl From<Board> for WasmBoard {
+    fn from(board: Board) -> Self {
+        WasmBoard { board }
+    }
+}
+
+impl From<WasmBoard> for Board {
+    fn from(board: WasmBoard) -> Self {
+        board.board
+    }
+}
@@ -18,8 +18,11 @@
 */
 
 use core::fmt;
+use core::num::ParseIntError;
+use core::ops::{BitXor, BitXorAssign};
+use core::str::FromStr;
 
-use crate::{CastleRights, Piece, Square};
+use crate::{Board, CastleRights, Color, File, Move, MoveType, Piece, Square};
 
 /// A 2D array containing Zobrist hash keys for each piece on every square.
 /// This is used to represent piece-square combinations in the hash calculation.
@@ -55,6 +58,58 @@ pub(crate) const KEY_CASTLE: [u64; CastleRights::NUM_CASTLING_RIGHTS] = [
     15514870633266398266, 4145308009146480642, 1368927690897079779, 9219866075479163426, 18135025827871201084, 9252195192974115523, 16055831919479314978, 7099352943904536037, 14227028345699399555, 17024309137276059108, 3699590268613639980, 7078737726619998058, 5200215836309567793, 17008814149287031565, 10377147447733916373, 2994850491838402462
 ];
 
+/// Zobrist hash keys for the rook file of each castling right, indexed by the right (white
+/// kingside, white queenside, black kingside, black queenside) and the file of its rook.
+/// The standard H and A files hash to zero, so only Chess960 rights castling with another
+/// rook change the castling key.
+pub(crate) const KEY_CASTLE_FILE: [[u64; File::NUM_FILES]; 4] =
+    castle_file_keys(&mut 0x6361_7374_6C65_3936);
+
+/// Draws rook file keys from the SplitMix64 `state`, leaving the standard files at zero.
+const fn castle_file_keys(state: &mut u64) -> [[u64; File::NUM_FILES]; 4] {
+    let mut keys: [[u64; File::NUM_FILES]; 4] = [[0; File::NUM_FILES]; 4];
+
+    let mut right: usize = 0;
+    while right < 4 {
+        let default: File = if right % 2 == 0 { File::H } else { File::A };
+        let mut file: usize = 0;
+        while file < File::NUM_FILES {
+            if file != default as usize {
+                keys[right][file] = splitmix64(state);
+            }
+            file += 1;
+        }
+        right += 1;
+    }
+    keys
+}
+
+/// Returns the hash key of the castling rights: the key of the four castling flags, combined
+/// with the file key of every granted right castling with a rook other than the standard one.
+#[inline(always)]
+const fn castle_key(
+    castle: &[u64; CastleRights::NUM_CASTLING_RIGHTS],
+    castle_files: &[[u64; File::NUM_FILES]; 4],
+    rights: CastleRights,
+) -> u64 {
+    let mut key: u64 = castle[rights.to_index()];
+    if rights.has_custom_rook_files() {
+        const COLORS: [Color; 2] = [Color::White, Color::Black];
+        let mut color: usize = 0;
+        while color < 2 {
+            if rights.has_kingside(COLORS[color]) {
+                key ^= castle_files[2 * color][rights.kingside_rook_file(COLORS[color]) as usize];
+            }
+            if rights.has_queenside(COLORS[color]) {
+                key ^=
+                    castle_files[2 * color + 1][rights.queenside_rook_file(COLORS[color]) as usize];
+            }
+            color += 1;
+        }
+    }
+    key
+}
+
 /// A Zobrist hash key for the side to move.
 /// This key is XORed into the hash when it's White's turn to move.
 /// It's omitted when Black is to move, ensuring the hash differs between
@@ -79,6 +134,71 @@ impl fmt::Display for Zobrist {
     }
 }
 
+impl fmt::LowerHex for Zobrist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for Zobrist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+/// Parses a `Zobrist` hash from a hexadecimal string, with or without a `0x` prefix,
+/// such as the output of its [`Display`](fmt::Display) implementation.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let key: Zobrist = Board::kiwipete().zobrist();
+/// assert_eq!(key.to_string().parse(), Ok(key));
+/// assert_eq!(format!("{key:#x}").parse(), Ok(key));
+/// assert_eq!("0xFF".parse(), Ok(Zobrist(255)));
+/// ```
+impl FromStr for Zobrist {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits: &str = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        u64::from_str_radix(digits, 16).map(Zobrist)
+    }
+}
+
+impl From<u64> for Zobrist {
+    #[inline(always)]
+    fn from(key: u64) -> Self {
+        Zobrist(key)
+    }
+}
+
+impl From<Zobrist> for u64 {
+    #[inline(always)]
+    fn from(key: Zobrist) -> Self {
+        key.0
+    }
+}
+
+impl BitXor for Zobrist {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, other: Self) -> Self {
+        Zobrist(self.0 ^ other.0)
+    }
+}
+
+impl BitXorAssign for Zobrist {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, other: Self) {
+        self.0 ^= other.0;
+    }
+}
+
 impl Zobrist {
     /// Creates a `Zobrist` instance with a hash value of zero.
     #[inline(always)]
@@ -110,12 +230,10 @@ impl Zobrist {
 
     /// Updates the Zobrist hash to reflect changes in the castling rights. The
     /// hash is updated by XOR-ing the current value with a precomputed key for
-    /// the current castling rights.
+    /// the current castling rights, including the rook files of Chess960 rights.
     #[inline(always)]
     pub fn hash_castle(&mut self, castle: CastleRights) {
-        unsafe {
-            self.0 ^= KEY_CASTLE.get_unchecked(castle.to_index());
-        }
+        self.0 ^= castle_key(&KEY_CASTLE, &KEY_CASTLE_FILE, castle);
     }
 
     /// Updates the Zobrist hash by swapping the old castling rights with the new ones.
@@ -126,10 +244,8 @@ impl Zobrist {
     /// accurately reflects the current castling rights of the position.
     #[inline(always)]
     pub fn swap_castle_hash(&mut self, old: CastleRights, new: CastleRights) {
-        unsafe {
-            self.0 ^= KEY_CASTLE.get_unchecked(old.to_index());
-            self.0 ^= KEY_CASTLE.get_unchecked(new.to_index());
-        }
+        self.0 ^= castle_key(&KEY_CASTLE, &KEY_CASTLE_FILE, old);
+        self.0 ^= castle_key(&KEY_CASTLE, &KEY_CASTLE_FILE, new);
     }
 
     /// Updates the Zobrist hash to reflect a change in the side to move. The hash
@@ -139,3 +255,164 @@ impl Zobrist {
         self.0 ^= KEY_SIDE;
     }
 }
+
+/// A complete set of Zobrist keys, used to hash positions against an alternative key set.
+///
+/// Boards always maintain their [`Zobrist`] hash with the built-in keys ([`ZobristTables::DEFAULT`]).
+/// Other key sets, generated from a seed or supplied by the user, can hash any board from scratch
+/// with [`ZobristTables::hash`] and follow its moves incrementally with [`ZobristTables::key_after`],
+/// which is useful for reproducibility experiments and for testing the collision behaviour of
+/// transposition tables.
+///
+/// # Example
+/// ```
+/// # use laura_core::*;
+/// let board: Board = Board::kiwipete();
+/// assert_eq!(ZobristTables::DEFAULT.hash(&board), board.zobrist());
+///
+/// const TABLES: ZobristTables = ZobristTables::from_seed(42);
+/// let key: Zobrist = TABLES.hash(&board);
+/// assert_ne!(key, board.zobrist());
+///
+/// let mv: Move = board.find_move("e1g1").unwrap();
+/// assert_eq!(TABLES.key_after(&board, key, mv), TABLES.hash(&board.make_move(mv)));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ZobristTables {
+    /// Keys for each piece on every square, indexed by [`Piece::to_index`] and [`Square::to_index`].
+    pub piece_square: [[u64; Square::NUM_SQUARES]; Piece::NUM_PIECES],
+
+    /// Keys for the en passant square, indexed by [`Square::to_index`].
+    pub enpassant: [u64; Square::NUM_SQUARES],
+
+    /// Keys for the castling rights, indexed by [`CastleRights::to_index`].
+    pub castle: [u64; CastleRights::NUM_CASTLING_RIGHTS],
+
+    /// Keys for the rook file of each granted castling right, indexed by the right (white
+    /// kingside, white queenside, black kingside, black queenside) and [`File::to_index`].
+    /// The standard H and A files should have zero keys, so that standard positions hash
+    /// only with [`ZobristTables::castle`].
+    pub castle_files: [[u64; File::NUM_FILES]; 4],
+
+    /// Key XORed into the hash when it's White's turn to move.
+    pub side: u64,
+}
+
+impl Default for ZobristTables {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ZobristTables {
+    /// The keys used by [`Board`] to maintain its [`Zobrist`] hash.
+    pub const DEFAULT: Self = Self {
+        piece_square: KEY_PIECE_SQUARE,
+        enpassant: KEY_ENPASSANT,
+        castle: KEY_CASTLE,
+        castle_files: KEY_CASTLE_FILE,
+        side: KEY_SIDE,
+    };
+
+    /// Generates a key set from a seed using the SplitMix64 generator.
+    ///
+    /// The same seed always produces the same keys, on every platform.
+    pub const fn from_seed(seed: u64) -> Self {
+        let mut state: u64 = seed;
+        let mut tables: Self = Self {
+            piece_square: [[0; Square::NUM_SQUARES]; Piece::NUM_PIECES],
+            enpassant: [0; Square::NUM_SQUARES],
+            castle: [0; CastleRights::NUM_CASTLING_RIGHTS],
+            castle_files: [[0; File::NUM_FILES]; 4],
+            side: 0,
+        };
+
+        let mut piece: usize = 0;
+        while piece < Piece::NUM_PIECES {
+            let mut square: usize = 0;
+            while square < Square::NUM_SQUARES {
+                tables.piece_square[piece][square] = splitmix64(&mut state);
+                square += 1;
+            }
+            piece += 1;
+        }
+
+        let mut square: usize = 0;
+        while square < Square::NUM_SQUARES {
+            tables.enpassant[square] = splitmix64(&mut state);
+            square += 1;
+        }
+
+        let mut castle: usize = 0;
+        while castle < CastleRights::NUM_CASTLING_RIGHTS {
+            tables.castle[castle] = splitmix64(&mut state);
+            castle += 1;
+        }
+
+        tables.side = splitmix64(&mut state);
+        tables.castle_files = castle_file_keys(&mut state);
+        tables
+    }
+
+    /// Computes the [`Zobrist`] hash of the position reached after the given move, from
+    /// `key`, the hash of the board using these keys, without making the move.
+    ///
+    /// This maintains a hash under these keys incrementally, the same way [`Board::make_move`]
+    /// maintains [`Board::zobrist`] with the built-in keys: the result equals
+    /// `self.hash(&board.make_move(mv))` when `key` equals `self.hash(board)`.
+    pub fn key_after(&self, board: &Board, key: Zobrist, mv: Move) -> Zobrist {
+        let mut key: u64 = key.0;
+
+        for delta in board.move_deltas(mv).iter() {
+            key ^= self.piece_square[delta.piece.to_index()][delta.square.to_index()];
+        }
+
+        if let Some(square) = board.enpassant_square {
+            key ^= self.enpassant[square.to_index()];
+        }
+
+        if mv.get_type() == MoveType::DoublePawn {
+            key ^= self.enpassant[mv.get_src().forward(board.side).to_index()];
+        }
+
+        key ^= castle_key(&self.castle, &self.castle_files, board.castling);
+        key ^= castle_key(
+            &self.castle,
+            &self.castle_files,
+            board.castling.update(mv.get_src(), mv.get_dest()),
+        );
+        key ^= self.side;
+
+        Zobrist(key)
+    }
+
+    /// Computes the [`Zobrist`] hash of the board from scratch using these keys.
+    pub fn hash(&self, board: &Board) -> Zobrist {
+        let mut key: u64 = castle_key(&self.castle, &self.castle_files, board.castling);
+
+        for (index, piece) in board.piece_map.iter().enumerate() {
+            if let Some(piece) = piece {
+                key ^= self.piece_square[piece.to_index()][index];
+            }
+        }
+
+        if let Some(square) = board.enpassant_square {
+            key ^= self.enpassant[square.to_index()];
+        }
+
+        if board.side == Color::White {
+            key ^= self.side;
+        }
+
+        Zobrist(key)
+    }
+}
+
+/// Advances the SplitMix64 state and returns the next pseudorandom value.
+pub(crate) const fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z: u64 = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "alloc")]
+
+use laura_core::*;
+
+#[test]
+fn test_gen_moves_vec() {
+    let board: Board = Board::kiwipete();
+    let moves: Vec<Move> = gen_moves_vec::<TacticalMoves>(&board);
+    assert_eq!(
+        moves.as_slice(),
+        gen_moves::<TacticalMoves>(&board).as_slice()
+    );
+}
+
+#[test]
+fn test_move_list_vec_conversions() {
+    let move_list: MoveList = gen_moves::<AllMoves>(&Board::kiwipete());
+
+    let moves: Vec<Move> = move_list.into();
+    assert_eq!(moves.len(), 48);
+    assert_eq!(
+        MoveList::from(moves.as_slice()).as_slice(),
+        move_list.as_slice()
+    );
+    assert_eq!(MoveList::from(moves).as_slice(), move_list.as_slice());
+
+    let overflow: Vec<Move> = vec![Move::null(); 1000];
+    assert_eq!(
+        MoveList::from(overflow).len(),
+        MoveList::from_iter([Move::null(); 1000]).len()
+    );
+}
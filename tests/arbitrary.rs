@@ -0,0 +1,50 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use laura_core::{Board, CastleRights, Move, Piece, Square};
+use std::str::FromStr;
+
+/// A deterministic byte stream standing in for fuzzer input.
+fn bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state: u64 = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 56) as u8
+        })
+        .collect()
+}
+
+#[test]
+fn test_arbitrary_types() {
+    let data: Vec<u8> = bytes(1, 4096);
+    let mut u: Unstructured = Unstructured::new(&data);
+    for _ in 0..256 {
+        let square: Square = u.arbitrary().unwrap();
+        assert!(square.to_index() < 64);
+        let piece: Piece = u.arbitrary().unwrap();
+        assert!(piece.to_index() < 12);
+        let rights: CastleRights = u.arbitrary().unwrap();
+        assert_eq!(rights.to_string().parse::<CastleRights>(), Ok(rights));
+        let mv: Move = u.arbitrary().unwrap();
+        assert_eq!(Move::new(mv.get_src(), mv.get_dest(), mv.get_type()), mv);
+    }
+}
+
+#[test]
+fn test_arbitrary_board() {
+    for seed in 0..64 {
+        let data: Vec<u8> = bytes(seed, 1024);
+        let board: Board = Board::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(board.validate(), Ok(()));
+        assert_eq!(Board::from_str(&board.to_fen().to_string()), Ok(board));
+    }
+
+    // Exhausted input still yields a valid board.
+    assert_eq!(
+        Board::arbitrary(&mut Unstructured::new(&[])),
+        Ok(Board::default())
+    );
+}
@@ -0,0 +1,234 @@
+use laura_core::*;
+use std::str::FromStr;
+
+#[test]
+fn test_discovered_check_candidates() {
+    let board: Board = Board::from_str("4k3/8/8/4N3/8/8/1B6/4R1K1 w - - 0 1").unwrap();
+    assert_eq!(
+        board.discovered_check_candidates(),
+        Square::E5.to_bitboard()
+    );
+
+    let board: Board = Board::from_str("4k3/8/2P5/8/Q7/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(
+        board.discovered_check_candidates(),
+        Square::C6.to_bitboard()
+    );
+
+    let board: Board = Board::from_str("4k3/8/2N5/1N6/B7/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(board.discovered_check_candidates(), BitBoard::EMPTY);
+
+    let board: Board = Board::from_str("4k3/8/4n3/4N3/8/8/8/4R1K1 w - - 0 1").unwrap();
+    assert_eq!(board.discovered_check_candidates(), BitBoard::EMPTY);
+
+    let board: Board = Board::from_str("6k1/8/8/8/6K1/8/6n1/6r1 b - - 0 1").unwrap();
+    assert_eq!(
+        board.discovered_check_candidates(),
+        Square::G2.to_bitboard()
+    );
+}
+
+#[test]
+fn test_attackers_to() {
+    let board: Board = Board::kiwipete();
+    let occupancy: BitBoard = board.combined_bitboard();
+
+    let attackers: BitBoard = board.attackers_to(Square::D5, occupancy);
+    let expected: BitBoard = Square::E4.to_bitboard()
+        | Square::C3.to_bitboard()
+        | Square::B6.to_bitboard()
+        | Square::F6.to_bitboard()
+        | Square::E6.to_bitboard();
+    assert_eq!(attackers, expected);
+
+    assert_eq!(
+        board.attackers_of(Color::White, Square::D5, occupancy),
+        Square::E4.to_bitboard() | Square::C3.to_bitboard()
+    );
+    assert_eq!(
+        board.attackers_of(Color::Black, Square::D5, occupancy),
+        Square::B6.to_bitboard() | Square::F6.to_bitboard() | Square::E6.to_bitboard()
+    );
+    assert_eq!(
+        board.attackers(Square::D5, occupancy),
+        board.attackers_of(Color::Black, Square::D5, occupancy)
+    );
+}
+
+#[test]
+fn test_attackers_of_xray() {
+    let board: Board = Board::from_str("4k3/8/8/8/8/8/R7/R3K3 w - - 0 1").unwrap();
+    let occupancy: BitBoard = board.combined_bitboard();
+    assert_eq!(
+        board.attackers_of(Color::White, Square::A8, occupancy),
+        Square::A2.to_bitboard()
+    );
+
+    let occupancy: BitBoard = occupancy.pop_square(Square::A2);
+    assert_eq!(
+        board.attackers_of(Color::White, Square::A8, occupancy) & occupancy,
+        Square::A1.to_bitboard()
+    );
+}
+
+#[test]
+fn test_is_attacked_by() {
+    let board: Board = Board::default();
+    assert!(board.is_attacked_by(Color::White, Square::F3));
+    assert!(!board.is_attacked_by(Color::Black, Square::F3));
+    assert!(board.is_attacked_by(Color::Black, Square::F6));
+    assert!(!board.is_attacked_by(Color::White, Square::E4));
+
+    let board: Board = board.make_uci_move("e2e4").unwrap();
+    assert_eq!(board.side(), Color::Black);
+    assert!(board.is_attacked_by(Color::White, Square::D5));
+    assert!(board.is_attacked_by(Color::White, Square::H5));
+    assert!(!board.is_attacked_by(Color::Black, Square::D5));
+}
+
+#[test]
+fn test_attacked_squares() {
+    let board: Board = Board::default();
+    assert_eq!(
+        board.attacked_squares(Color::White),
+        BitBoard::RANK_3 | BitBoard::RANK_2 | BitBoard(0x7E)
+    );
+    assert_eq!(
+        board.attacked_squares(Color::Black),
+        board.attacked_squares(Color::White).flip()
+    );
+
+    let board: Board = Board::from_str("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+    let expected: BitBoard = (BitBoard::FILE_A | BitBoard::RANK_1).pop_square(Square::A1)
+        & !BitBoard(0xE0)
+        | get_king_attacks(Square::E1);
+    assert_eq!(board.attacked_squares(Color::White), expected);
+
+    let board: Board = Board::kiwipete();
+    for square in BitBoard::FULL {
+        for color in [Color::White, Color::Black] {
+            assert_eq!(
+                board.attacked_squares(color).get_square(square),
+                board.is_attacked_by(color, square)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_attacked_twice() {
+    let board: Board = Board::default();
+    let twice: BitBoard = board.attacked_twice(Color::White);
+    assert!(twice.get_square(Square::F3));
+    assert!(twice.get_square(Square::D2));
+    assert!(!twice.get_square(Square::E5));
+    assert!(!twice.get_square(Square::E4));
+
+    let board: Board = Board::kiwipete();
+    for color in [Color::White, Color::Black] {
+        let (attacked, twice) = board.attack_maps(color);
+        assert_eq!(attacked, board.attacked_squares(color));
+        assert_eq!(twice, board.attacked_twice(color));
+
+        let occupancy: BitBoard = board.combined_bitboard();
+        for square in BitBoard::FULL {
+            let count: u32 = board.attackers_of(color, square, occupancy).count_bits();
+            assert_eq!(twice.get_square(square), count >= 2, "{square}");
+        }
+    }
+}
+
+#[test]
+fn test_safe_checks() {
+    let board: Board = Board::from_str("4k3/8/8/5N2/8/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(
+        board.safe_checks(Color::White, PieceType::Knight),
+        Square::D6.to_bitboard() | Square::G7.to_bitboard()
+    );
+
+    let board: Board = Board::from_str("4k3/8/r7/5N2/8/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(
+        board.safe_checks(Color::White, PieceType::Knight),
+        Square::G7.to_bitboard()
+    );
+
+    let board: Board = Board::from_str("4k3/8/5P2/8/8/8/8/R3K3 w - - 0 1").unwrap();
+    assert!(board.safe_checks(Color::White, PieceType::Pawn).is_empty());
+    assert!(board.safe_checks(Color::White, PieceType::King).is_empty());
+    assert_eq!(
+        board.safe_checks(Color::White, PieceType::Rook),
+        Square::A8.to_bitboard()
+    );
+    assert!(board.safe_checks(Color::Black, PieceType::Queen).is_empty());
+}
+
+#[test]
+fn test_pawn_attacks_and_pushes() {
+    let board: Board = Board::default();
+    assert_eq!(board.pawn_attacks(Color::White), BitBoard::RANK_3);
+    assert_eq!(board.pawn_attacks(Color::Black), BitBoard::RANK_6);
+    assert_eq!(
+        board.pawn_push_targets(Color::White),
+        BitBoard::RANK_3 | BitBoard::RANK_4
+    );
+    assert_eq!(
+        board.pawn_push_targets(Color::Black),
+        BitBoard::RANK_6 | BitBoard::RANK_5
+    );
+
+    let board: Board = Board::kiwipete();
+    let pushes: BitBoard = gen_moves::<QuietMoves>(&board)
+        .iter()
+        .filter(|mv| board.piece_on(mv.get_src()).map(|p| p.piece_type()) == Some(PieceType::Pawn))
+        .map(|mv| mv.get_dest())
+        .collect();
+    assert!(pushes.is_subset_of(board.pawn_push_targets(Color::White)));
+    assert!(
+        (board.pawn_attacks(Color::White) | board.pawn_attacks(Color::Black)).is_subset_of(
+            board.attacked_squares(Color::White) | board.attacked_squares(Color::Black)
+        )
+    );
+}
+
+fn slide_attacks(square: Square, blockers: BitBoard, directions: &[Direction]) -> BitBoard {
+    let mut attacks: BitBoard = BitBoard::EMPTY;
+    for &dir in directions {
+        let mut step: BitBoard = square.to_bitboard().shift(dir);
+        while !step.is_empty() {
+            attacks |= step;
+            if !(step & blockers).is_empty() {
+                break;
+            }
+            step = step.shift(dir);
+        }
+    }
+    attacks
+}
+
+#[test]
+fn test_slider_attacks_reference() {
+    let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+    for _ in 0..256 {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        let sparse: u64 = seed & (seed >> 11) & (seed >> 23);
+        for blockers in [
+            BitBoard(seed),
+            BitBoard(sparse),
+            BitBoard::EMPTY,
+            BitBoard::FULL,
+        ] {
+            for square in BitBoard::FULL {
+                assert_eq!(
+                    get_rook_attacks(square, blockers),
+                    slide_attacks(square, blockers, &Direction::ORTHOGONAL)
+                );
+                assert_eq!(
+                    get_bishop_attacks(square, blockers),
+                    slide_attacks(square, blockers, &Direction::DIAGONAL)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use laura_core::*;
+
+#[test]
+fn test_bitboard_collect() {
+    let bitboard: BitBoard = Rank::Two.squares().collect();
+    assert_eq!(bitboard, BitBoard::RANK_2);
+
+    let bitboard: BitBoard = BitBoard::FULL.collect();
+    assert_eq!(bitboard, BitBoard::FULL);
+
+    let bitboard: BitBoard = core::iter::empty().collect();
+    assert!(bitboard.is_empty());
+
+    let mut bitboard: BitBoard = File::A.to_bitboard();
+    bitboard.extend(File::H.squares());
+    assert_eq!(bitboard, File::A.to_bitboard() | File::H.to_bitboard());
+
+    bitboard.extend([Square::A1, Square::H8]);
+    assert_eq!(bitboard.count_bits(), 16);
+}
+
+#[test]
+fn test_bitboard_shift_n() {
+    for square in BitBoard::FULL {
+        let bitboard: BitBoard = square.to_bitboard();
+        for direction in Direction::ALL {
+            let expected: BitBoard = square
+                .walk(direction)
+                .nth(1)
+                .map_or(BitBoard::EMPTY, Square::to_bitboard);
+            assert_eq!(bitboard.shift_n::<2>(direction), expected);
+            assert_eq!(bitboard.shift_n::<0>(direction), bitboard);
+            assert_eq!(bitboard.shift_n::<1>(direction), bitboard.shift(direction));
+        }
+    }
+
+    assert_eq!(
+        BitBoard::RANK_2.shift_n::<5>(Direction::North),
+        BitBoard::RANK_7
+    );
+    assert_eq!(
+        BitBoard::FULL.shift_n::<7>(Direction::West),
+        BitBoard::FILE_A
+    );
+    assert!(BitBoard::FULL.shift_n::<8>(Direction::South).is_empty());
+}
+
+#[test]
+fn test_bitboard_lsb_msb() {
+    let mut bitboard: BitBoard = BitBoard::RANK_4 | BitBoard::FILE_C;
+    let expected: Vec<Square> = bitboard.collect();
+    let mut popped: Vec<Square> = Vec::new();
+    while let Some(square) = bitboard.pop_lsb() {
+        popped.push(square);
+    }
+    assert_eq!(popped, expected);
+    assert!(bitboard.is_empty());
+
+    for square in BitBoard::FULL {
+        let below: BitBoard = BitBoard(square.to_bitboard().0 - 1);
+        let above: BitBoard = !below & !square.to_bitboard();
+        assert_eq!((below | square.to_bitboard()).msb(), Some(square));
+        assert_eq!(
+            (above | square.to_bitboard()).isolate_lsb(),
+            square.to_bitboard()
+        );
+    }
+}
+
+#[test]
+fn test_bitboard_set_relations() {
+    let board: Board = Board::kiwipete();
+    let white: BitBoard = board.sides_bitboard[Color::White as usize];
+    let black: BitBoard = board.sides_bitboard[Color::Black as usize];
+    let occupied: BitBoard = board.combined_bitboard();
+
+    assert!(occupied.contains(white) && occupied.contains(black));
+    assert!(white.is_subset_of(occupied) && !occupied.is_subset_of(white));
+    assert!(white.is_disjoint(black) && !white.intersects(black));
+    assert!(occupied.intersects(board.kings()));
+    assert!(BitBoard::EMPTY.is_subset_of(BitBoard::EMPTY));
+    assert!(BitBoard::EMPTY.is_disjoint(BitBoard::FULL));
+    assert!(!BitBoard::EMPTY.intersects(BitBoard::FULL));
+}
+
+#[test]
+fn test_bitboard_transforms() {
+    for square in BitBoard::FULL {
+        let file: usize = square.file().to_index();
+        let rank: usize = square.rank().to_index();
+        let at = |file: usize, rank: usize| {
+            Square::from_file_rank(File::from_index(file), Rank::from_index(rank)).to_bitboard()
+        };
+        let bitboard: BitBoard = square.to_bitboard();
+
+        assert_eq!(bitboard.mirror_horizontal(), at(7 - file, rank));
+        assert_eq!(bitboard.flip(), at(file, 7 - rank));
+        assert_eq!(bitboard.flip_diag_a1h8(), at(rank, file));
+        assert_eq!(bitboard.flip_diag_a8h1(), at(7 - rank, 7 - file));
+        assert_eq!(bitboard.rotate_90(), at(rank, 7 - file));
+        assert_eq!(bitboard.rotate_180(), at(7 - file, 7 - rank));
+        assert_eq!(bitboard.rotate_270(), at(7 - rank, file));
+    }
+
+    let bitboard: BitBoard = Board::kiwipete().combined_bitboard();
+    assert_eq!(bitboard.rotate_90().rotate_90(), bitboard.rotate_180());
+    assert_eq!(bitboard.rotate_90().rotate_270(), bitboard);
+    assert_eq!(bitboard.rotate_180(), bitboard.flip().mirror_horizontal());
+}
@@ -0,0 +1,836 @@
+use laura_core::*;
+use std::str::FromStr;
+
+const POSITIONS: [&str; 4] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w Kq f6 0 3",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 3 17",
+];
+
+#[test]
+fn test_board_mirror() {
+    let board: Board = Board::kiwipete();
+    assert_eq!(
+        board.mirror().to_fen(),
+        "r3k2r/pppbbppp/2n2q1P/1P2p3/3pn3/BN2PNP1/P1PPQPB1/R3K2R b KQkq - 0 1"
+    );
+
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let mirror: Board = board.mirror();
+        assert_eq!(mirror.mirror(), board);
+        assert_eq!(
+            Board::from_str(&mirror.to_fen().to_string()).unwrap(),
+            mirror
+        );
+        assert_eq!(mirror.checkers.count_bits(), board.checkers.count_bits());
+        assert_eq!(
+            gen_moves::<AllMoves>(&mirror).len(),
+            gen_moves::<AllMoves>(&board).len()
+        );
+    }
+}
+
+#[test]
+fn test_board_geometric_transforms() {
+    let board: Board = Board::from_str(POSITIONS[2]).unwrap();
+    assert_eq!(
+        board.flip_horizontal().to_fen(),
+        "rnbkqbnr/pp1p1ppp/8/2pPp3/8/8/PPP1PPPP/RNBKQBNR w - c6 0 3"
+    );
+    assert_eq!(
+        board.flip_vertical().to_fen(),
+        "RNBQKBNR/PPPP1PPP/8/8/3pPp2/8/ppp1p1pp/rnbqkbnr w - - 0 3"
+    );
+    assert_eq!(
+        Board::default().flip_horizontal().flip_horizontal(),
+        Board::from_str("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1").unwrap()
+    );
+
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        for transformed in [
+            board.flip_vertical(),
+            board.flip_horizontal(),
+            board.rotate_180(),
+        ] {
+            assert_eq!(transformed.side, board.side);
+            assert_eq!(
+                Board::from_str(&transformed.to_fen().to_string()).unwrap(),
+                transformed
+            );
+            assert_eq!(
+                transformed.combined_bitboard().count_bits(),
+                board.combined_bitboard().count_bits()
+            );
+        }
+
+        let occupancy: BitBoard = board.combined_bitboard();
+        assert_eq!(board.flip_vertical().combined_bitboard(), occupancy.flip());
+        assert_eq!(
+            board.flip_horizontal().combined_bitboard(),
+            occupancy.mirror_horizontal()
+        );
+        assert_eq!(
+            board.rotate_180().combined_bitboard(),
+            occupancy.rotate_180()
+        );
+        assert_eq!(board.rotate_180().rotate_180().piece_map, board.piece_map);
+        assert_eq!(board.flip_vertical().flip_horizontal(), board.rotate_180());
+    }
+}
+
+#[test]
+fn test_board_builder() {
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(BoardBuilder::from(board).build(), Ok(board));
+    }
+
+    let mut castling: CastleRights = CastleRights::null();
+    castling.set_white_kingside();
+    let board: Board = BoardBuilder::new()
+        .piece(Square::E1, Piece::WK)
+        .piece(Square::H1, Piece::WR)
+        .piece(Square::A8, Piece::BQ)
+        .piece(Square::B8, Piece::BK)
+        .piece(Square::C5, Piece::WP)
+        .piece(Square::D5, Piece::BP)
+        .castling(castling)
+        .enpassant(Some(Square::D6))
+        .fifty_move(0)
+        .full_move(40)
+        .build()
+        .unwrap();
+    assert_eq!(
+        board,
+        Board::from_str("qk6/8/8/2Pp4/8/8/8/4K2R w K d6 0 40").unwrap()
+    );
+
+    let builder: BoardBuilder = BoardBuilder::from(Board::default());
+    assert_eq!(
+        builder.clear(Square::E8).build(),
+        Err(BoardParseError::InvalidKingCount)
+    );
+    assert_eq!(
+        builder.piece(Square::D4, Piece::WK).build(),
+        Err(BoardParseError::InvalidKingCount)
+    );
+    assert_eq!(
+        builder.enpassant(Some(Square::E4)).build(),
+        Err(BoardParseError::InvalidEnPassantRank)
+    );
+    assert_eq!(
+        builder.fifty_move(101).build(),
+        Err(BoardParseError::HalfmoveClockOverflow)
+    );
+    assert_eq!(
+        builder.full_move(0).build(),
+        Err(BoardParseError::FullmoveMustBePositive)
+    );
+    assert!(builder.side(Color::Black).build().unwrap().side == Color::Black);
+}
+
+#[test]
+fn test_board_validate() {
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(board.validate(), Ok(()));
+        assert_eq!(board.mirror().validate(), Ok(()));
+    }
+
+    let cases: [(&str, BoardValidationError); 5] = [
+        (
+            "P3k3/8/8/8/8/8/8/4K3 w - - 0 1",
+            BoardValidationError::PawnOnBackRank,
+        ),
+        (
+            "4k3/8/8/8/8/8/8/4K2R w KQ - 0 1",
+            BoardValidationError::InconsistentCastlingRights,
+        ),
+        (
+            "4k3/8/8/8/8/8/4Q3/4K3 w - - 0 1",
+            BoardValidationError::OpponentInCheck,
+        ),
+        (
+            "4k3/8/8/8/8/2b2n2/8/r3K3 w - - 0 1",
+            BoardValidationError::TooManyCheckers,
+        ),
+        (
+            "4k3/8/8/8/8/8/8/4K3 w - e6 0 1",
+            BoardValidationError::InvalidEnPassant,
+        ),
+    ];
+    for (fen, error) in cases {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(board.validate(), Err(error), "{fen}");
+    }
+
+    let mut board: Board = Board::default();
+    board.remove_piece(Square::E1);
+    assert_eq!(
+        board.validate(),
+        Err(BoardValidationError::InvalidKingCount(Color::White))
+    );
+    board.set_piece(Piece::WK, Square::E1);
+    board.set_piece(Piece::BK, Square::E4);
+    assert_eq!(
+        board.validate(),
+        Err(BoardValidationError::InvalidKingCount(Color::Black))
+    );
+
+    let mut board: Board = Board::default();
+    board.piece_map.set(Square::E4, Some(Piece::WQ));
+    assert_eq!(
+        board.validate(),
+        Err(BoardValidationError::InconsistentBitboards)
+    );
+}
+
+#[test]
+fn test_permissive_fen() {
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let short: String = fen.split_whitespace().take(4).collect::<Vec<_>>().join(" ");
+        assert_eq!(
+            Board::from_str(&short),
+            Err(BoardParseError::MissingHalfmoveClock)
+        );
+        let parsed: Board = Board::from_fen_with(&short, FenOptions::PERMISSIVE).unwrap();
+        assert_eq!(parsed.piece_map, board.piece_map);
+        assert_eq!(parsed.zobrist, board.zobrist);
+        assert_eq!((parsed.fifty_move, parsed.full_move), (0, 1));
+
+        assert_eq!(Board::from_fen_with(fen, FenOptions::PERMISSIVE), Ok(board));
+        assert_eq!(Board::from_fen_with(fen, FenOptions::default()), Ok(board));
+    }
+
+    let board: Board =
+        Board::from_fen_with("4k3/8/8/8/8/8/8/4K3 w - - 12", FenOptions::PERMISSIVE).unwrap();
+    assert_eq!((board.fifty_move, board.full_move), (12, 1));
+    assert_eq!(
+        Board::from_fen_with("4k3/8/8/8/8/8/8/4K3 w -", FenOptions::PERMISSIVE),
+        Err(BoardParseError::MissingEnPassant)
+    );
+}
+
+#[test]
+fn test_normalize_ep() {
+    let options: FenOptions = FenOptions {
+        normalize_enpassant: true,
+        ..FenOptions::STRICT
+    };
+
+    // A capturing pawn keeps the square.
+    let fen: &str = POSITIONS[2];
+    let board: Board = Board::from_fen_with(fen, options).unwrap();
+    assert_eq!(board, Board::from_str(fen).unwrap());
+    assert_eq!(board.enpassant_square, Some(Square::F6));
+
+    // No pawn next to the double-pushed pawn.
+    let board: Board = Board::from_fen_with(
+        "rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 2",
+        options,
+    )
+    .unwrap();
+    assert_eq!(board.enpassant_square, None);
+    assert_eq!(
+        board,
+        Board::from_str("rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 2").unwrap()
+    );
+
+    // The capturing pawn is pinned against its king, so the capture is illegal.
+    let mut board: Board = Board::from_str("4k3/8/8/r2pP2K/8/8/8/8 w - d6 0 1").unwrap();
+    board.normalize_ep();
+    assert_eq!(board.enpassant_square, None);
+    assert_eq!(
+        board.zobrist,
+        Board::from_str("4k3/8/8/r2pP2K/8/8/8/8 w - - 0 1")
+            .unwrap()
+            .zobrist
+    );
+}
+
+#[test]
+fn test_fen_layout_errors() {
+    assert_eq!(
+        Board::from_str("4k3/8/8/8 w - - 0 1"),
+        Err(BoardParseError::InvalidBoardLayout)
+    );
+    assert_eq!(
+        Board::from_str("4k3/8/8/8/8/8/8/8/4K3 w - - 0 1"),
+        Err(BoardParseError::InvalidBoardLayout)
+    );
+    assert_eq!(
+        Board::from_str("8/8/8/8/8/8/8/4K3 w - - 0 1"),
+        Err(BoardParseError::InvalidKingCount)
+    );
+    assert_eq!(
+        Board::from_str("4k3/8/8/8/8/8/8/3KK3 w - - 0 1"),
+        Err(BoardParseError::InvalidKingCount)
+    );
+}
+
+#[test]
+fn test_packed_roundtrip() {
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let packed: [u8; PACKED_BOARD_SIZE] = board.to_packed().unwrap();
+        assert_eq!(Board::from_packed(&packed), Ok(board), "{fen}");
+    }
+
+    let board: Board = Board::from_str("4k3/8/8/8/3Pp3/8/8/4K3 b - d3 99 300").unwrap();
+    assert_eq!(Board::from_packed(&board.to_packed().unwrap()), Ok(board));
+
+    let crowded: Board =
+        Board::from_str("rnbqkbnr/pppppppp/pppppppp/8/8/PPPPPPPP/PPPPPPPP/RNBQKBNR w - - 0 1")
+            .unwrap();
+    assert_eq!(crowded.to_packed(), None);
+
+    let mut packed: [u8; PACKED_BOARD_SIZE] = Board::default().to_packed().unwrap();
+    packed[8] = 0x0F;
+    assert_eq!(
+        Board::from_packed(&packed),
+        Err(BoardParseError::InvalidBoardLayout)
+    );
+}
+
+#[test]
+fn test_move_deltas() {
+    for fen in POSITIONS.iter().copied().chain([
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+    ]) {
+        let board: Board = Board::from_str(fen).unwrap();
+        for mv in gen_moves::<AllMoves>(&board) {
+            let (new_board, deltas) = board.make_move_with_deltas(mv);
+            assert_eq!(new_board, board.make_move(mv));
+
+            let mut piece_map: [Option<Piece>; 64] = board.piece_map.to_array();
+            for delta in deltas.iter() {
+                match delta.kind {
+                    DeltaKind::Removed => {
+                        assert_eq!(piece_map[delta.square.to_index()], Some(delta.piece));
+                        piece_map[delta.square.to_index()] = None;
+                    }
+                    DeltaKind::Added => piece_map[delta.square.to_index()] = Some(delta.piece),
+                }
+            }
+            assert_eq!(piece_map, new_board.piece_map.to_array(), "{fen} {mv}");
+        }
+    }
+
+    let board: Board = Board::kiwipete();
+    let castle: Move = Move::new(Square::E1, Square::G1, MoveType::KingCastle);
+    assert_eq!(board.move_deltas(castle).len(), 4);
+}
+
+#[test]
+fn test_key_after() {
+    for fen in POSITIONS.iter().copied().chain([
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1",
+    ]) {
+        let board: Board = Board::from_str(fen).unwrap();
+        for mv in gen_moves::<AllMoves>(&board) {
+            assert_eq!(
+                board.key_after(mv),
+                board.make_move(mv).zobrist(),
+                "{fen} {mv}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_incremental_checkers() {
+    fn walk(board: &Board, depth: usize) {
+        for mv in gen_moves::<AllMoves>(board) {
+            let child: Board = board.make_move(mv);
+            assert_eq!(child.checkers, child.checkers(), "{} {mv}", board.to_fen());
+
+            let mut lazy: Board = board.make_move_lazy(mv);
+            assert_eq!(lazy.checkers, Board::UNKNOWN_CHECKERS);
+            // Readers compute the checkers of a lazy board without caching them first.
+            assert_eq!(
+                gen_moves::<AllMoves>(&lazy).len(),
+                gen_moves::<AllMoves>(&child).len(),
+                "{} {mv}",
+                board.to_fen()
+            );
+            assert_eq!(
+                count_legal_moves::<AllMoves>(&lazy),
+                count_legal_moves::<AllMoves>(&child)
+            );
+            assert_eq!(lazy.status(), child.status());
+            assert_eq!(
+                lazy.try_null_move().is_some(),
+                child.try_null_move().is_some()
+            );
+            lazy.cache_checkers();
+            assert_eq!(lazy, child, "{} {mv}", board.to_fen());
+            if depth > 1 {
+                walk(&child, depth - 1);
+            }
+        }
+    }
+
+    for fen in POSITIONS.iter().copied().chain([
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+        "5k2/8/8/8/8/8/8/4K2R w K - 0 1",
+        "8/8/1P2K3/8/2n5/1q6/8/5k2 b - - 0 1",
+        "8/5k2/8/5N2/5Q2/2K5/8/8 w - - 0 1",
+    ]) {
+        walk(&Board::from_str(fen).unwrap(), 3);
+    }
+}
+
+#[test]
+fn test_board_listener() {
+    #[derive(Default)]
+    struct Mirror {
+        piece_map: Vec<Option<Piece>>,
+        states: Vec<Zobrist>,
+    }
+
+    impl BoardListener for Mirror {
+        fn on_piece_added(&mut self, piece: Piece, square: Square) {
+            self.piece_map[square.to_index()] = Some(piece);
+        }
+
+        fn on_piece_removed(&mut self, _piece: Piece, square: Square) {
+            self.piece_map[square.to_index()] = None;
+        }
+
+        fn on_state_changed(&mut self, board: &Board) {
+            self.states.push(board.zobrist());
+        }
+    }
+
+    let start: Board = Board::kiwipete();
+    let listener: Mirror = Mirror {
+        piece_map: start.piece_map.to_array().to_vec(),
+        states: Vec::new(),
+    };
+    let mut board: ListenedBoard<Mirror> = ListenedBoard::new(start, listener);
+
+    for uci in ["e1g1", "h3g2", "f3f6"] {
+        let mv: Move = board.board().find_move(uci).unwrap();
+        board.make_move(mv);
+    }
+    board.null_move();
+
+    let (board, listener) = board.into_parts();
+    assert_eq!(listener.piece_map, board.piece_map.to_array().to_vec());
+    assert_eq!(listener.states.len(), 4);
+    assert_eq!(listener.states.last(), Some(&board.zobrist()));
+}
+
+#[test]
+fn test_polyglot_key() {
+    // Reference keys from the Polyglot book format specification.
+    let sequences: [(&str, u64); 9] = [
+        ("", 0x463b96181691fc9c),
+        ("e2e4", 0x823c9b50fd114196),
+        ("e2e4 d7d5", 0x0756b94461c50fb0),
+        ("e2e4 d7d5 e4e5", 0x662fafb965db29d4),
+        ("e2e4 d7d5 e4e5 f7f5", 0x22a48b5a8e47ff78),
+        ("e2e4 d7d5 e4e5 f7f5 e1e2", 0x652a607ca3f242c1),
+        ("e2e4 d7d5 e4e5 f7f5 e1e2 e8f7", 0x00fdd303c946bdd9),
+        ("a2a4 b7b5 h2h4 b5b4 c2c4", 0x3c8123ea7b067637),
+        ("a2a4 b7b5 h2h4 b5b4 c2c4 b4c3 a1a3", 0x5c3f9b829b279560),
+    ];
+
+    for (moves, key) in sequences {
+        let board: Board = Board::default().apply_uci_moves(moves).unwrap();
+        assert_eq!(board.polyglot_key(), Zobrist(key), "{moves}");
+    }
+}
+
+#[test]
+fn test_pawn_key() {
+    let board: Board = Board::kiwipete();
+    for mv in gen_moves::<AllMoves>(&board).iter() {
+        let child: Board = board.make_move(*mv);
+        let parsed: Board = Board::from_str(&child.to_fen().to_string()).unwrap();
+        assert_eq!(child.pawn_key(), parsed.pawn_key(), "{mv}");
+
+        let pawn_moved: bool =
+            board.piece_on(mv.get_src()).unwrap().piece_type() == PieceType::Pawn;
+        let pawn_captured: bool = mv.get_type() == MoveType::EnPassant
+            || board
+                .piece_on(mv.get_dest())
+                .is_some_and(|piece| piece.piece_type() == PieceType::Pawn);
+        assert_eq!(
+            child.pawn_key() == board.pawn_key(),
+            !pawn_moved && !pawn_captured,
+            "{mv}"
+        );
+    }
+
+    let only_pawns: Board = Board::from_str("4k3/pppppppp/8/8/8/8/PPPPPPPP/4K3 b - - 0 1").unwrap();
+    assert_eq!(only_pawns.pawn_key(), Board::default().pawn_key());
+    assert_eq!(Board::empty().pawn_key(), Zobrist::null());
+}
+
+#[test]
+fn test_zobrist_tables() {
+    let tables: ZobristTables = ZobristTables::from_seed(0xDEAD_BEEF);
+    assert_eq!(tables, ZobristTables::from_seed(0xDEAD_BEEF));
+    assert_ne!(tables, ZobristTables::from_seed(0xDEAD_BEF0));
+    assert_eq!(ZobristTables::default(), ZobristTables::DEFAULT);
+
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(
+            ZobristTables::DEFAULT.hash(&board),
+            board.zobrist(),
+            "{fen}"
+        );
+
+        for mv in gen_moves::<AllMoves>(&board) {
+            let child: Board = board.make_move(mv);
+            assert_eq!(
+                ZobristTables::DEFAULT.hash(&child),
+                child.zobrist(),
+                "{fen} {mv}"
+            );
+            assert_ne!(tables.hash(&child), tables.hash(&board), "{fen} {mv}");
+            assert_eq!(
+                tables.key_after(&board, tables.hash(&board), mv),
+                tables.hash(&child),
+                "{fen} {mv}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_chess960_castle_key() {
+    // The same placement with queenside rights castling with the A or B rook.
+    let a_rook: Board = "1r2k3/8/8/8/8/8/8/RR2K3 w Ab - 0 1".parse().unwrap();
+    let b_rook: Board = "1r2k3/8/8/8/8/8/8/RR2K3 w Bb - 0 1".parse().unwrap();
+    assert_eq!(a_rook.castling.to_index(), b_rook.castling.to_index());
+    assert_ne!(a_rook.zobrist(), b_rook.zobrist());
+    assert_ne!(
+        ZobristTables::from_seed(7).hash(&a_rook),
+        ZobristTables::from_seed(7).hash(&b_rook)
+    );
+
+    for board in [a_rook, b_rook] {
+        assert_eq!(ZobristTables::DEFAULT.hash(&board), board.zobrist());
+        for mv in gen_moves::<AllMoves>(&board) {
+            let child: Board = board.make_move(mv);
+            assert_eq!(ZobristTables::DEFAULT.hash(&child), child.zobrist(), "{mv}");
+            assert_eq!(board.key_after(mv), child.zobrist(), "{mv}");
+        }
+    }
+
+    // Once the rights are gone the positions transpose.
+    let king_move = |board: Board| board.make_move(board.find_move("e1e2").unwrap());
+    assert_eq!(king_move(a_rook).castling, king_move(b_rook).castling);
+    assert_eq!(king_move(a_rook).zobrist(), king_move(b_rook).zobrist());
+}
+
+#[test]
+fn test_random_playout() {
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    let mut rng = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mated: Board = Board::from_str("k7/1Q6/1K6/8/8/8/8/8 b - - 0 1").unwrap();
+    assert_eq!(mated.random_move(&mut rng), None);
+    assert_eq!(mated.random_playout(&mut rng, 0), GameResult::WhiteWins);
+
+    let draws: [&str; 4] = [
+        "k7/2Q5/1K6/8/8/8/8/8 b - - 0 1",
+        "4k3/8/8/8/8/8/8/2B1K3 w - - 0 1",
+        "4k3/8/8/8/8/8/5b2/2B1K1B1 w - - 0 1",
+        "4k3/8/8/8/8/8/4P3/R3K3 w - - 100 80",
+    ];
+    for fen in draws {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(
+            board.random_playout(&mut rng, 10),
+            GameResult::Draw,
+            "{fen}"
+        );
+    }
+
+    let board: Board = Board::default();
+    assert_eq!(board.random_playout(&mut rng, 0), GameResult::Unfinished);
+
+    // Long random games keep the board consistent and always terminate.
+    for _ in 0..32 {
+        let mut board: Board = Board::default();
+        while let Some(mv) = board.random_move(&mut rng) {
+            board = board.make_move(mv);
+            assert_eq!(board.validate(), Ok(()));
+            if board.fifty_move() >= 100 {
+                break;
+            }
+        }
+        assert_ne!(
+            Board::default().random_playout(&mut rng, 10_000),
+            GameResult::Unfinished
+        );
+    }
+}
+
+#[test]
+fn test_board_status() {
+    let results: [(&str, GameResult); 7] = [
+        ("k7/1Q6/1K6/8/8/8/8/8 b - - 0 1", GameResult::WhiteWins),
+        (
+            "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3",
+            GameResult::BlackWins,
+        ),
+        ("k7/2Q5/1K6/8/8/8/8/8 b - - 0 1", GameResult::Draw),
+        ("4k3/8/8/8/8/8/5b2/2B1K1B1 w - - 0 1", GameResult::Draw),
+        ("4k3/8/8/8/8/8/4P3/R3K3 w - - 100 80", GameResult::Draw),
+        ("4k3/8/8/8/8/8/4P3/R3K3 w - - 99 80", GameResult::Unfinished),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            GameResult::Unfinished,
+        ),
+    ];
+    for (fen, result) in results {
+        assert_eq!(Board::from_str(fen).unwrap().status(), result, "{fen}");
+    }
+
+    let board: Board = Board::from_str("8/8/8/3K4/8/8/8/k7 w - - 0 1").unwrap();
+    assert!(board.king_in_center(Color::White));
+    assert!(!board.king_in_center(Color::Black));
+    assert_eq!(BitBoard::CENTER.count_bits(), 4);
+    assert!(BitBoard::CENTER.get_square(Square::E4));
+}
+
+#[test]
+fn test_board_lookups() {
+    let board: Board = Board::kiwipete();
+    let piece_types: [PieceType; 6] = [
+        PieceType::Pawn,
+        PieceType::Knight,
+        PieceType::Bishop,
+        PieceType::Rook,
+        PieceType::Queen,
+        PieceType::King,
+    ];
+    for color in [Color::White, Color::Black] {
+        for piece_type in piece_types {
+            assert_eq!(
+                board.pieces_of(color, piece_type),
+                board.piece_presence(Piece::new(piece_type, color))
+            );
+        }
+    }
+    assert_eq!(
+        board.pieces_of(Color::White, PieceType::Queen),
+        board.allied_queens()
+    );
+    assert_eq!(
+        board.pieces_of(Color::Black, PieceType::Queen),
+        board.enemy_queens()
+    );
+
+    assert_eq!(board.king_square(Color::White), Square::E1);
+    assert_eq!(board.king_square(Color::Black), Square::E8);
+    assert_eq!(board.allied_king_square(), Square::E1);
+    assert_eq!(board.null_move().allied_king_square(), Square::E8);
+
+    assert_eq!(board.non_pawn_material(Color::White).count_bits(), 7);
+    assert!(board.has_non_pawn_material(Color::Black));
+    let endgame: Board = Board::from_str("4k3/4p3/8/8/8/8/4P3/4K2R w K - 0 1").unwrap();
+    assert_eq!(
+        endgame.non_pawn_material(Color::White),
+        Square::H1.to_bitboard()
+    );
+    assert!(!endgame.has_non_pawn_material(Color::Black));
+
+    assert!(board.has_bishop_pair(Color::White) && board.has_bishop_pair(Color::Black));
+    assert!(!board.opposite_colored_bishops());
+    let bishops: [(&str, bool, bool); 4] = [
+        ("4kb2/8/8/8/8/8/8/2B1K3 w - - 0 1", false, false),
+        ("2b1k3/8/8/8/8/8/8/2B1K3 w - - 0 1", false, true),
+        ("4kb2/8/8/8/8/8/8/2B1KB2 w - - 0 1", true, false),
+        ("4k3/8/8/8/8/8/8/2B1KB2 w - - 0 1", true, false),
+    ];
+    for (fen, pair, opposite) in bishops {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(board.has_bishop_pair(Color::White), pair, "{fen}");
+        assert_eq!(board.opposite_colored_bishops(), opposite, "{fen}");
+    }
+}
+
+#[test]
+fn test_repetition_key() {
+    let play = |board: Board, moves: &[&str]| -> Board {
+        moves
+            .iter()
+            .fold(board, |board, uci| board.make_uci_move(uci).unwrap())
+    };
+    let cycle: [&str; 4] = ["b1c3", "b8c6", "c3b1", "c6b8"];
+
+    let board: Board = Board::default();
+    let repeated: Board = play(board, &cycle);
+    assert_ne!(repeated, board);
+    assert_eq!(repeated.zobrist(), board.zobrist());
+    assert_eq!(repeated.repetition_key(), board.repetition_key());
+    assert!(repeated.same_position(&board));
+    assert!(!play(board, &cycle[..2]).same_position(&board));
+
+    // An en passant square that can be captured keeps the position apart from its repetition.
+    let board: Board = play(Board::default(), &["e2e4", "g8f6", "e4e5", "d7d5"]);
+    assert_eq!(board.enpassant_square, Some(Square::D6));
+    let repeated: Board = play(board, &cycle);
+    assert_ne!(repeated.repetition_key(), board.repetition_key());
+    assert!(!repeated.same_position(&board));
+
+    // Losing the castling rights does too.
+    let board: Board = play(Board::default(), &["e2e4", "e7e5"]);
+    let repeated: Board = play(board, &["e1e2", "e8e7", "e2e1", "e7e8"]);
+    assert_ne!(repeated.repetition_key(), board.repetition_key());
+    assert!(!repeated.same_position(&board));
+}
+
+#[test]
+fn test_piece_map() {
+    let board: Board = Board::kiwipete();
+    let pieces: [Option<Piece>; 64] = board.piece_map.to_array();
+    assert_eq!(PieceMap::from(pieces), board.piece_map);
+    for (index, piece) in board.piece_map.iter().enumerate() {
+        assert_eq!(piece, board.piece_on(Square::from_index(index)));
+    }
+
+    let mut map: PieceMap = PieceMap::EMPTY;
+    map.set(Square::A1, Some(Piece::BK));
+    map.set(Square::B1, Some(Piece::WP));
+    map.set(Square::A1, None);
+    assert_eq!(map.get(Square::A1), None);
+    assert_eq!(map.get(Square::B1), Some(Piece::WP));
+    assert_eq!(core::mem::size_of::<PieceMap>(), 32);
+}
+
+#[test]
+fn test_board_hash() {
+    use std::collections::HashSet;
+
+    let play = |moves: &[&str]| -> Board {
+        moves
+            .iter()
+            .fold(Board::default(), |board: Board, uci: &&str| {
+                board.make_uci_move(uci).unwrap()
+            })
+    };
+
+    let mut seen: HashSet<Board> = HashSet::new();
+    assert!(seen.insert(play(&["g1f3", "g8f6", "b1c3", "b8c6"])));
+    // The same position through another move order is equal and hashes alike.
+    assert!(!seen.insert(play(&["b1c3", "b8c6", "g1f3", "g8f6"])));
+    // Returning to the starting position changes the move counters, so the boards differ.
+    assert!(seen.insert(Board::default()));
+    assert!(seen.insert(play(&["g1f3", "g8f6", "f3g1", "f6g8"])));
+    assert_eq!(seen.len(), 3);
+}
+
+#[test]
+fn test_zobrist_conversions() {
+    let key: Zobrist = Zobrist::from(0x00AB_CDEF_0123_4567u64);
+    assert_eq!(u64::from(key), 0x00AB_CDEF_0123_4567);
+    assert_eq!(key.to_string(), "00abcdef01234567");
+    assert_eq!(format!("{key:x}"), "abcdef01234567");
+    assert_eq!(format!("{key:#018X}"), "0x00ABCDEF01234567");
+
+    assert_eq!(Zobrist::from_str("00abcdef01234567"), Ok(key));
+    assert_eq!(Zobrist::from_str("0xABCDEF01234567"), Ok(key));
+    assert!(Zobrist::from_str("").is_err());
+    assert!(Zobrist::from_str("0x").is_err());
+    assert!(Zobrist::from_str("12345678901234567").is_err());
+    assert!(Zobrist::from_str("xyz").is_err());
+
+    // XOR-ing the side key in and out of a hash.
+    let board: Board = Board::default();
+    let black: Board =
+        Board::from_str("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1").unwrap();
+    let side: Zobrist = board.zobrist() ^ black.zobrist();
+    let mut key: Zobrist = black.zobrist();
+    key ^= side;
+    assert_eq!(key, board.zobrist());
+    assert_eq!(key ^ key, Zobrist::null());
+}
+
+#[test]
+fn test_board_display_with() {
+    let board: Board =
+        Board::from_str("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    assert_eq!(
+        board.display_with(DisplayOptions::default()).to_string(),
+        board.to_string()
+    );
+
+    let grid: String = board.display_with(DisplayOptions::COMPACT).to_string();
+    assert!(!grid.contains("FEN"));
+    assert!(!grid.contains("Side to move"));
+    assert!(grid.starts_with("\n\t+---+"));
+    assert!(grid.contains("     8  | r |   |   |   | k |   |   | r | "));
+
+    let flipped: String = board
+        .display_with(DisplayOptions {
+            perspective: Color::Black,
+            unicode: true,
+            coordinates: false,
+            ..DisplayOptions::COMPACT
+        })
+        .to_string();
+    let rows: Vec<&str> = flipped.lines().filter(|line| line.contains('|')).collect();
+    assert_eq!(rows.len(), 8);
+    assert_eq!(rows[0], "\t| ♖ |   |   | ♔ |   |   |   | ♖ | ");
+    assert_eq!(rows[7], "\t| ♜ |   |   | ♚ |   |   |   | ♜ | ");
+    assert!(!flipped.contains('A'));
+}
+
+#[test]
+fn test_write_fen() {
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+
+        let mut written: String = String::new();
+        board.write_fen(&mut written).unwrap();
+        assert_eq!(written, fen);
+        assert_eq!(board.fen().to_string(), fen);
+        assert_eq!(format!("{:?}", board.fen()), format!("{fen:?}"));
+
+        assert_eq!(board.to_fen(), fen);
+        assert!(board.fen() != fen[..fen.len() - 1]);
+        assert!(board.fen() != format!("{fen} ").as_str());
+    }
+}
+
+#[test]
+fn test_fen_into() {
+    let mut buf: [u8; MAX_FEN_LENGTH] = [0; MAX_FEN_LENGTH];
+    for fen in POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(board.fen_into(&mut buf), Ok(fen));
+        assert_eq!(board.fen_into(&mut buf[..fen.len()]), Ok(fen));
+        assert_eq!(
+            board.fen_into(&mut buf[..fen.len() - 1]),
+            Err(FenError::BufferTooSmall(fen.len()))
+        );
+    }
+
+    let mut board: Board = Board::from_str(POSITIONS[2]).unwrap();
+    board.fifty_move = u8::MAX;
+    board.full_move = u16::MAX;
+    assert_eq!(
+        board.fen_into(&mut buf),
+        Ok("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w Kq f6 255 65535")
+    );
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "bytemuck")]
+
+use laura_core::{AllMoves, BitBoard, Board, Move, MoveList, MoveType, Square, gen_moves};
+
+#[test]
+fn test_bytemuck_pod() {
+    let moves: [Move; 2] = [
+        Move::new(Square::E2, Square::E4, MoveType::DoublePawn),
+        Move::new(Square::B7, Square::C8, MoveType::CapPromoQueen),
+    ];
+    let bytes: &[u8] = bytemuck::cast_slice(&moves);
+    assert_eq!(bytes.len(), 4);
+    assert_eq!(
+        bytemuck::checked::pod_read_unaligned::<Move>(&bytes[2..]),
+        moves[1]
+    );
+
+    let bitboard: BitBoard = BitBoard::RANK_2;
+    assert_eq!(bytemuck::cast::<BitBoard, u64>(bitboard), bitboard.0);
+}
+
+#[test]
+fn test_bytemuck_invalid_move() {
+    // 0b0110 is not an assigned move type code.
+    let raw: u16 = 0b0110 << 12 | 12 << 6 | 28;
+    assert!(bytemuck::checked::try_cast::<u16, Move>(raw).is_err());
+    assert!(bytemuck::checked::try_from_bytes::<Move>(&raw.to_ne_bytes()).is_err());
+
+    let list: MoveList = gen_moves::<AllMoves>(&Board::default());
+    let mut bytes: Vec<u8> = bytemuck::bytes_of(&list).to_vec();
+    bytes[0..2].copy_from_slice(&raw.to_ne_bytes());
+    let aligned: Vec<usize> = bytes
+        .chunks_exact(std::mem::size_of::<usize>())
+        .map(|chunk: &[u8]| usize::from_ne_bytes(chunk.try_into().unwrap()))
+        .collect();
+    assert!(bytemuck::checked::try_from_bytes::<MoveList>(bytemuck::cast_slice(&aligned)).is_err());
+}
+
+#[test]
+fn test_bytemuck_move_list() {
+    let list: MoveList = gen_moves::<AllMoves>(&Board::kiwipete());
+    let bytes: &[u8] = bytemuck::bytes_of(&list);
+
+    let read: &MoveList = bytemuck::checked::try_from_bytes(bytes).unwrap();
+    assert_eq!(read.as_slice(), list.as_slice());
+
+    let empty: MoveList = bytemuck::Zeroable::zeroed();
+    assert!(empty.is_empty());
+
+    let mut corrupted: Vec<usize> = vec![0; std::mem::size_of::<MoveList>() / 8];
+    *corrupted.last_mut().unwrap() = usize::MAX;
+    assert!(
+        bytemuck::checked::try_from_bytes::<MoveList>(bytemuck::cast_slice(&corrupted)).is_err()
+    );
+}
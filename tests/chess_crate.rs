@@ -0,0 +1,70 @@
+#![cfg(feature = "chess")]
+
+use chess::{ChessMove, MoveGen};
+use laura_core::*;
+use std::str::FromStr;
+
+const FENS: [&str; 4] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+];
+
+#[test]
+fn test_chess_board_round_trip() {
+    for fen in FENS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let converted: chess::Board = chess::Board::try_from(&board).unwrap();
+        assert_eq!(converted, chess::Board::from_str(fen).unwrap(), "{fen}");
+        assert_eq!(Board::try_from(&converted), Ok(board), "{fen}");
+    }
+
+    let board: Board = Board::from_str("1r2k1r1/8/8/8/8/8/8/1R2K1R1 w GBgb - 0 1").unwrap();
+    assert!(matches!(
+        chess::Board::try_from(&board),
+        Err(chess::Error::InvalidBoard)
+    ));
+}
+
+#[test]
+fn test_chess_moves() {
+    for fen in FENS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let converted: chess::Board = chess::Board::try_from(&board).unwrap();
+        let expected: Vec<ChessMove> = MoveGen::new_legal(&converted).collect();
+        let moves: MoveList = gen_moves::<AllMoves>(&board);
+        assert_eq!(moves.len(), expected.len(), "{fen}");
+
+        for &mv in moves.iter() {
+            let m: ChessMove = ChessMove::from(mv);
+            assert!(expected.contains(&m), "{fen} {mv}");
+            assert_eq!(board.from_chess_move(m), Some(mv));
+
+            let after: chess::Board = converted.make_move_new(m);
+            let mut expected: Board = board.make_move(mv);
+            expected.normalize_ep();
+            let expected: Board = BoardBuilder::from(expected)
+                .fifty_move(0)
+                .full_move(1)
+                .build()
+                .unwrap();
+            let mut converted: Board = Board::try_from(&after).unwrap();
+            converted.normalize_ep();
+            assert_eq!(converted, expected, "{fen} {mv}");
+        }
+    }
+}
+
+#[test]
+fn test_chess_primitives() {
+    for index in 0..Square::NUM_SQUARES {
+        let square: Square = Square::from_index(index);
+        assert_eq!(chess::Square::from(square).to_string(), square.to_string());
+        assert_eq!(Square::from(chess::Square::from(square)), square);
+    }
+    for index in 0..12 {
+        let piece_type: PieceType = Piece::from_index(index).unwrap().piece_type();
+        assert_eq!(PieceType::from(chess::Piece::from(piece_type)), piece_type);
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "cozy-chess")]
+
+use laura_core::*;
+use std::str::FromStr;
+
+const FENS: [&str; 5] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 12 40",
+    "1r2k1r1/8/8/8/8/8/8/1R2K1R1 b GBgb - 3 17",
+];
+
+#[test]
+fn test_cozy_chess_board_round_trip() {
+    for fen in FENS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let cozy: cozy_chess::Board = cozy_chess::Board::try_from(&board).unwrap();
+        assert_eq!(cozy, fen.parse::<cozy_chess::Board>().unwrap(), "{fen}");
+        assert_eq!(Board::try_from(&cozy), Ok(board), "{fen}");
+    }
+}
+
+#[test]
+fn test_cozy_chess_moves() {
+    for fen in FENS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let cozy: cozy_chess::Board = cozy_chess::Board::try_from(&board).unwrap();
+
+        let mut expected: Vec<cozy_chess::Move> = Vec::new();
+        cozy.generate_moves(|moves| {
+            expected.extend(moves);
+            false
+        });
+        let moves: MoveList = gen_moves::<AllMoves>(&board);
+        assert_eq!(moves.len(), expected.len(), "{fen}");
+
+        for &mv in moves.iter() {
+            let m: cozy_chess::Move = board.to_cozy_move(mv);
+            assert!(expected.contains(&m), "{fen} {mv}");
+            assert_eq!(board.from_cozy_move(m), Some(mv));
+
+            let mut after: cozy_chess::Board = cozy.clone();
+            after.play(m);
+            assert_eq!(
+                Board::try_from(&after),
+                Ok(board.make_move(mv)),
+                "{fen} {mv}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_cozy_chess_primitives() {
+    for index in 0..Square::NUM_SQUARES {
+        let square: Square = Square::from_index(index);
+        assert_eq!(
+            cozy_chess::Square::from(square).to_string(),
+            square.to_string()
+        );
+        assert_eq!(Square::from(cozy_chess::Square::from(square)), square);
+    }
+    for index in 0..12 {
+        let piece_type: PieceType = Piece::from_index(index).unwrap().piece_type();
+        assert_eq!(
+            PieceType::from(cozy_chess::Piece::from(piece_type)),
+            piece_type
+        );
+    }
+}
+
+#[test]
+fn test_cozy_chess_unsupported_castling() {
+    let cozy: cozy_chess::Board =
+        cozy_chess::Board::from_fen("1rk3r1/8/8/8/8/8/8/1RK3R1 w GBgb - 0 1", true).unwrap();
+    assert!(matches!(
+        Board::try_from(&cozy),
+        Err(BoardParseError::InvalidCastlingRights(_))
+    ));
+}
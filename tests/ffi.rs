@@ -0,0 +1,57 @@
+#![cfg(feature = "ffi")]
+
+use laura_core::ffi::*;
+use laura_core::*;
+use std::ffi::CString;
+use std::ptr::{null, null_mut};
+
+#[test]
+fn test_ffi_board() {
+    let fen: CString =
+        CString::new("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    let board: *mut Board = unsafe { laura_board_from_fen(fen.as_ptr()) };
+    assert!(!board.is_null());
+    assert_eq!(unsafe { laura_board_perft(board, 2) }, 2039);
+
+    let mut moves: [u16; 256] = [0; 256];
+    let count: usize = unsafe { laura_board_gen_moves(board, moves.as_mut_ptr(), moves.len()) };
+    assert_eq!(count, 48);
+    assert_eq!(unsafe { laura_board_gen_moves(board, null_mut(), 0) }, 48);
+
+    let mut few: [u16; 4] = [0; 4];
+    assert_eq!(
+        unsafe { laura_board_gen_moves(board, few.as_mut_ptr(), few.len()) },
+        48
+    );
+    assert_eq!(few, moves[..4]);
+
+    let copy: *mut Board = unsafe { laura_board_clone(board) };
+    assert!(unsafe { laura_board_make_move(copy, moves[0]) });
+    assert_eq!(
+        unsafe { *copy },
+        unsafe { *board }.make_move(Move(moves[0]))
+    );
+    assert!(!unsafe { laura_board_make_move(copy, 0) });
+
+    unsafe {
+        laura_board_free(copy);
+        laura_board_free(board);
+    }
+}
+
+#[test]
+fn test_ffi_errors() {
+    let invalid: CString = CString::new("not a fen").unwrap();
+    assert!(unsafe { laura_board_from_fen(invalid.as_ptr()) }.is_null());
+    assert!(unsafe { laura_board_from_fen(null()) }.is_null());
+    assert!(unsafe { laura_board_clone(null()) }.is_null());
+    assert_eq!(unsafe { laura_board_perft(null(), 3) }, 0);
+    assert_eq!(unsafe { laura_board_gen_moves(null(), null_mut(), 0) }, 0);
+    assert!(!unsafe { laura_board_make_move(null_mut(), 0) });
+    unsafe { laura_board_free(null_mut()) };
+
+    let board: *mut Board = laura_board_new();
+    assert_eq!(unsafe { laura_board_perft(board, 3) }, 8902);
+    unsafe { laura_board_free(board) };
+}
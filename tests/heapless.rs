@@ -0,0 +1,21 @@
+#![cfg(feature = "heapless")]
+
+use laura_core::*;
+use std::str::FromStr;
+
+#[test]
+fn test_heapless_fen() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 3 17",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(board.to_heapless_fen(), fen);
+        assert_eq!(board.to_heapless_fen_with::<80>().unwrap(), fen);
+        assert_eq!(
+            board.to_heapless_fen_with::<24>(),
+            Err(FenError::BufferTooSmall(fen.len()))
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use laura_core::*;
+
+#[test]
+fn test_pawn_spans() {
+    assert_eq!(
+        front_span(Color::White, Square::E4),
+        Square::E5.walk(Direction::North).collect::<BitBoard>() | Square::E5.to_bitboard()
+    );
+    assert_eq!(
+        front_span(Color::Black, Square::E4),
+        Square::E4.walk(Direction::South).collect()
+    );
+    assert_eq!(
+        rear_span(Color::White, Square::E4),
+        front_span(Color::Black, Square::E4)
+    );
+    assert!(front_span(Color::White, Square::A8).is_empty());
+    assert!(rear_span(Color::Black, Square::A8).is_empty());
+
+    assert_eq!(
+        passed_pawn_mask(Color::White, Square::A6),
+        BitBoard::EMPTY
+            .set_square(Square::A7)
+            .set_square(Square::A8)
+            .set_square(Square::B7)
+            .set_square(Square::B8)
+    );
+
+    for square in BitBoard::FULL {
+        for color in [Color::White, Color::Black] {
+            let file: BitBoard = square.file().to_bitboard();
+            assert_eq!(
+                front_span(color, square) | rear_span(color, square) | square.to_bitboard(),
+                file
+            );
+            assert_eq!(front_span(color, square), rear_span(!color, square));
+
+            let adjacent: BitBoard = square.file().adjacent() | file;
+            let passed: BitBoard = passed_pawn_mask(color, square);
+            assert!(passed.is_subset_of(adjacent));
+            assert_eq!(passed & file, front_span(color, square));
+        }
+    }
+}
+
+#[test]
+fn test_king_safety_masks() {
+    let shield: BitBoard = [
+        Square::F2,
+        Square::G2,
+        Square::H2,
+        Square::F3,
+        Square::G3,
+        Square::H3,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(pawn_shield_mask(Color::White, Square::G1), shield);
+    assert_eq!(pawn_shield_mask(Color::Black, Square::G8), shield.flip());
+    assert_eq!(pawn_shield_mask(Color::White, Square::A1).count_bits(), 4);
+    assert!(pawn_shield_mask(Color::White, Square::E8).is_empty());
+
+    let zone: BitBoard = king_zone(Color::White, Square::E1);
+    assert_eq!(zone.count_bits(), 9);
+    assert!(zone.contains(get_king_attacks(Square::E1)));
+    assert!(zone.get_square(Square::E3) && !zone.get_square(Square::E4));
+
+    for square in BitBoard::FULL {
+        for color in [Color::White, Color::Black] {
+            let zone: BitBoard = king_zone(color, square);
+            assert!(zone.contains(get_king_attacks(square) | square.to_bitboard()));
+            assert!(zone.contains(pawn_shield_mask(color, square)));
+        }
+    }
+}
+
+#[test]
+fn test_outpost_mask() {
+    let mask: BitBoard = outpost_mask(Color::White, Square::D5);
+    let expected: BitBoard = [
+        Square::C6,
+        Square::C7,
+        Square::C8,
+        Square::E6,
+        Square::E7,
+        Square::E8,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(mask, expected);
+    assert!(outpost_mask(Color::Black, Square::A2).get_square(Square::B1));
+    assert!(outpost_mask(Color::White, Square::H7).contains(Square::G8.to_bitboard()));
+
+    for square in BitBoard::FULL {
+        for color in [Color::White, Color::Black] {
+            let mask: BitBoard = outpost_mask(color, square);
+            assert_eq!(
+                mask | front_span(color, square),
+                passed_pawn_mask(color, square)
+            );
+            assert!(mask.is_disjoint(square.file().to_bitboard()));
+
+            // Every enemy pawn able to capture on the square must sit inside the mask.
+            let attackers: BitBoard = get_pawn_attacks(color, square);
+            assert!(attackers.is_subset_of(mask));
+        }
+    }
+}
+
+#[test]
+fn test_pawn_attack_span() {
+    let span: BitBoard = pawn_attack_span(Color::White, Square::B2);
+    assert!(span.contains(get_pawn_attacks(Color::White, Square::B2)));
+    assert!(span.contains(get_pawn_attacks(Color::White, Square::B6)));
+    assert!(!span.get_square(Square::B3));
+    assert!(pawn_attack_span(Color::Black, Square::B2).contains(Square::C1.to_bitboard()));
+}
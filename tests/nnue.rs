@@ -0,0 +1,185 @@
+#![cfg(feature = "nnue")]
+
+use laura_core::*;
+use std::str::FromStr;
+
+fn sorted(indices: impl Iterator<Item = usize>) -> Vec<usize> {
+    let mut indices: Vec<usize> = indices.collect();
+    indices.sort_unstable();
+    indices
+}
+
+#[test]
+fn test_halfkp_indices() {
+    let board: Board = Board::default();
+    let white: Vec<usize> = sorted(board.halfkp_indices(Color::White));
+    let black: Vec<usize> = sorted(board.halfkp_indices(Color::Black));
+
+    // White pawn on a2, king on e1: 4 * 641 + 1 + 0 * 64 + 8.
+    assert!(white.contains(&2573));
+    // Black pawn on a7 seen by Black, rotated to h2 with the king on d1: 3 * 641 + 1 + 0 * 64 + 15.
+    assert!(black.contains(&1939));
+    assert!(
+        white
+            .iter()
+            .chain(&black)
+            .all(|&index| index < HALFKP_DIMENSIONS)
+    );
+    assert_eq!(white.len(), 30);
+}
+
+#[test]
+fn test_halfkav2_indices() {
+    let board: Board = Board::default();
+    let white: Vec<usize> = sorted(board.halfkav2_indices(Color::White));
+
+    // Both kings share plane 10: e1 and e8 with the white king on e1.
+    assert!(white.contains(&(4 * 704 + 10 * 64 + 4)));
+    assert!(white.contains(&(4 * 704 + 10 * 64 + 60)));
+    assert_eq!(white, sorted(board.halfkav2_indices(Color::Black)));
+
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 3 17",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        let mirror: Board = board.mirror();
+        for color in [Color::White, Color::Black] {
+            assert_eq!(
+                sorted(board.halfkav2_indices(color)),
+                sorted(mirror.halfkav2_indices(!color))
+            );
+        }
+        assert!(
+            board
+                .halfkav2_indices(Color::Black)
+                .all(|index| index < HALFKAV2_DIMENSIONS)
+        );
+    }
+}
+
+#[test]
+fn test_packed_sfen_roundtrip() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "4k3/8/8/8/3Pp3/8/8/4K3 b - d3 99 300",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 3 17",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        let packed: [u8; PACKED_SFEN_SIZE] = board.to_packed_sfen().unwrap();
+        assert_eq!(Board::from_packed_sfen(&packed), Ok(board), "{fen}");
+    }
+
+    // More pieces than fit in 256 bits.
+    let crowded: Board =
+        Board::from_str("rnbqkbnr/pppppppp/pppppppp/8/8/PPPPPPPP/PPPPPPPP/RNBQKBNR w - - 0 1")
+            .unwrap();
+    assert_eq!(crowded.to_packed_sfen(), None);
+    // White has no king in Horde.
+    #[cfg(feature = "variants")]
+    assert_eq!(Horde::default().board.to_packed_sfen(), None);
+
+    // Side to move, then the white king on e1 and the black king on e8 in 7 bits each.
+    let packed: [u8; PACKED_SFEN_SIZE] = Board::default().to_packed_sfen().unwrap();
+    assert_eq!(packed[0], 4 << 1);
+    assert_eq!(packed[1] & 0x7F, 60);
+
+    // The white king square is out of range.
+    let mut packed: [u8; PACKED_SFEN_SIZE] = packed;
+    packed[0] |= 0b1000_0000;
+    assert_eq!(
+        Board::from_packed_sfen(&packed),
+        Err(BoardParseError::InvalidBoardLayout)
+    );
+}
+
+#[test]
+fn test_stockfish_moves() {
+    let board: Board = Board::kiwipete();
+    // Castling is encoded as the king moving onto its rook.
+    let castle: Move = board.find_move("e1g1").unwrap();
+    assert_eq!(board.to_stockfish_move(castle), 3 << 14 | 4 << 6 | 7);
+    let quiet: Move = board.find_move("e2a6").unwrap();
+    assert_eq!(board.to_stockfish_move(quiet), 12 << 6 | 40);
+
+    let board: Board = Board::from_str("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let promotion: Move = board.find_move("b7b8n").unwrap();
+    assert_eq!(board.to_stockfish_move(promotion), 1 << 14 | 49 << 6 | 57);
+    let promotion: Move = board.find_move("b7b8q").unwrap();
+    assert_eq!(
+        board.to_stockfish_move(promotion),
+        1 << 14 | 3 << 12 | 49 << 6 | 57
+    );
+
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        for &mv in gen_moves::<AllMoves>(&board).iter() {
+            let raw: u16 = board.to_stockfish_move(mv);
+            assert_eq!(board.from_stockfish_move(raw), Some(mv), "{fen} {mv}");
+        }
+    }
+}
+
+#[test]
+fn test_packed_sfen_value() {
+    let board: Board = Board::kiwipete();
+    let value: PackedSfenValue = PackedSfenValue {
+        board,
+        score: -137,
+        mv: board.find_move("e1c1").unwrap(),
+        ply: 42,
+        result: -1,
+    };
+    let bytes: [u8; PACKED_SFEN_VALUE_SIZE] = value.to_bytes().unwrap();
+    assert_eq!(bytes[..32], board.to_packed_sfen().unwrap());
+    assert_eq!(bytes[32..34], (-137i16).to_le_bytes());
+    assert_eq!(bytes[38], 0xFF);
+    assert_eq!(PackedSfenValue::from_bytes(&bytes), Ok(value));
+
+    let mut bytes: [u8; PACKED_SFEN_VALUE_SIZE] = bytes;
+    bytes[34..36].copy_from_slice(&0u16.to_le_bytes());
+    assert_eq!(
+        PackedSfenValue::from_bytes(&bytes),
+        Err(PackedSfenError::IllegalMove(0))
+    );
+}
+
+#[test]
+fn test_bullet_records() {
+    let board: Board = Board::kiwipete();
+    let record: [u8; BULLET_BOARD_SIZE] = board.to_bullet(-40, GameResult::BlackWins).unwrap();
+    assert_eq!(record[0..8], board.combined_bitboard().0.to_le_bytes());
+    // White rook on a1 in the low nibble, then the White king on e1.
+    assert_eq!(record[8], 0x53);
+    assert_eq!(record[24..26], (-40i16).to_le_bytes());
+    assert_eq!(record[26], 0);
+    assert_eq!((record[27], record[28]), (4, 4));
+    assert_eq!(record[29..], [0; 3]);
+    assert_eq!(board.to_bullet(0, GameResult::Unfinished), None);
+
+    let crowded: Board =
+        Board::from_str("rnbqkbnr/pppppppp/pppppppp/8/8/PPPPPPPP/PPPPPPPP/RNBQKBNR w - - 0 1")
+            .unwrap();
+    assert_eq!(crowded.to_bullet(0, GameResult::Draw), None);
+
+    // A record is stored from the side to move's point of view.
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 3 17",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(
+            board.to_bullet(120, GameResult::WhiteWins),
+            board.mirror().to_bullet(-120, GameResult::BlackWins),
+            "{fen}"
+        );
+        let record: [u8; BULLET_BOARD_SIZE] = board.to_bullet(120, GameResult::Draw).unwrap();
+        assert_eq!(record[24..26], (-120i16).to_le_bytes());
+        assert_eq!(record[26], 1);
+    }
+}
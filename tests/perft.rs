@@ -0,0 +1,199 @@
+use laura_core::*;
+use std::str::FromStr;
+
+const PERFT_POSITIONS: [(&str, usize, u64); 6] = [
+    (
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        4,
+        197281,
+    ),
+    (
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        3,
+        97862,
+    ),
+    ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4, 43238),
+    (
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        3,
+        9467,
+    ),
+    (
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        3,
+        62379,
+    ),
+    (
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+        3,
+        89890,
+    ),
+];
+
+fn check_counts(board: &Board, depth: usize) {
+    assert_eq!(
+        count_legal_moves::<AllMoves>(board),
+        gen_moves::<AllMoves>(board).len(),
+        "{board}"
+    );
+    assert_eq!(
+        count_legal_moves::<QuietMoves>(board),
+        gen_moves::<QuietMoves>(board).len(),
+        "{board}"
+    );
+    assert_eq!(
+        count_legal_moves::<TacticalMoves>(board),
+        gen_moves::<TacticalMoves>(board).len(),
+        "{board}"
+    );
+    if depth > 0 {
+        for mv in gen_moves::<AllMoves>(board) {
+            check_counts(&board.make_move(mv), depth - 1);
+        }
+    }
+}
+
+#[test]
+fn test_perft() {
+    for (fen, depth, nodes) in PERFT_POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(perft(&board, depth), nodes, "{fen}");
+    }
+    assert_eq!(perft(&Board::default(), 0), 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_perft_hashed() {
+    for (fen, depth, nodes) in PERFT_POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        assert_eq!(
+            perft_hashed(&board, depth + 1, 1),
+            perft(&board, depth + 1),
+            "{fen}"
+        );
+        assert_eq!(perft_hashed(&board, depth, 0), nodes, "{fen}");
+    }
+}
+
+#[test]
+fn test_count_legal_moves() {
+    for (fen, depth, _) in PERFT_POSITIONS {
+        let board: Board = Board::from_str(fen).unwrap();
+        check_counts(&board, depth.min(3) - 1);
+    }
+}
+
+#[test]
+fn test_perft_stats() {
+    let stats = |fen: &str, depth: usize| perft_stats(&Board::from_str(fen).unwrap(), depth);
+
+    assert_eq!(
+        stats(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            4
+        ),
+        PerftStats {
+            nodes: 197281,
+            captures: 1576,
+            en_passants: 0,
+            castles: 0,
+            promotions: 0,
+            checks: 469,
+            discovered_checks: 0,
+            double_checks: 0,
+            checkmates: 8,
+        }
+    );
+    assert_eq!(
+        stats(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            3
+        ),
+        PerftStats {
+            nodes: 97862,
+            captures: 17102,
+            en_passants: 45,
+            castles: 3162,
+            promotions: 0,
+            checks: 993,
+            discovered_checks: 0,
+            double_checks: 0,
+            checkmates: 1,
+        }
+    );
+    assert_eq!(
+        stats("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 5),
+        PerftStats {
+            nodes: 674624,
+            captures: 52051,
+            en_passants: 1165,
+            castles: 0,
+            promotions: 0,
+            checks: 52950,
+            discovered_checks: 1292,
+            double_checks: 3,
+            checkmates: 0,
+        }
+    );
+    assert_eq!(
+        stats(
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            3
+        ),
+        PerftStats {
+            nodes: 9467,
+            captures: 1021,
+            en_passants: 4,
+            castles: 0,
+            promotions: 120,
+            checks: 38,
+            // The wiki table lists 0, but c5xb6 uncovers the b4 bishop in two lines.
+            discovered_checks: 2,
+            double_checks: 0,
+            checkmates: 22,
+        }
+    );
+}
+
+#[test]
+fn test_perft_epd() {
+    const SUITE: &str = "\
+rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - ;D1 20 ;D2 400 ;D3 8902 ;D4 197281
+r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1 ;D1 48 ;D2 2039 ;D3 97862
+8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - ; id \"position 3\" ;D1 14 ;D2 191 ;D3 2812";
+
+    let mut results: usize = 0;
+    for record in SUITE.lines() {
+        for result in perft_epd(record).unwrap() {
+            assert!(result.passed(), "{record}: {result:?}");
+            results += 1;
+        }
+    }
+    assert_eq!(results, 10);
+
+    let runner = perft_epd(SUITE.lines().next().unwrap()).unwrap();
+    assert_eq!(runner.board(), &Board::default());
+    let depths: Vec<usize> = runner
+        .with_max_depth(2)
+        .map(|result| result.depth)
+        .collect();
+    assert_eq!(depths, [1, 2]);
+
+    let wrong = perft_epd("4k3/8/8/8/8/8/8/4K3 w - - ;D1 6 ;D2 25").unwrap();
+    let passed: Vec<bool> = wrong.map(|result| result.passed()).collect();
+    assert_eq!(passed, [false, true]);
+
+    assert_eq!(
+        perft_epd("4k3/8/8/8/8/8/8/4K3 x - - ;D1 5").unwrap_err(),
+        PerftEpdError::InvalidFen(BoardParseError::InvalidSideToMove)
+    );
+    assert_eq!(
+        perft_epd("4k3/8/8/8/8/8/8/4K3 w - - ;D1 5 ;Dx 25").unwrap_err(),
+        PerftEpdError::InvalidDepth(1)
+    );
+    assert_eq!(
+        perft_epd("4k3/8/8/8/8/8/8/4K3 w - - ;D1 5 25").unwrap_err(),
+        PerftEpdError::InvalidNodeCount(0)
+    );
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "proptest")]
+
+use laura_core::*;
+use proptest::prelude::*;
+use std::str::FromStr;
+
+fn board_and_move() -> impl Strategy<Value = (Board, Move)> {
+    any_board()
+        .prop_filter("no legal moves", |board| {
+            !gen_moves::<AllMoves>(board).is_empty()
+        })
+        .prop_flat_map(|board| (Just(board), any_legal_move(&board)))
+}
+
+proptest! {
+    #[test]
+    fn test_any_board_round_trips(board in any_board()) {
+        prop_assert_eq!(board.validate(), Ok(()));
+        prop_assert_eq!(Board::from_str(&board.to_fen().to_string()), Ok(board));
+        prop_assert_eq!(ZobristTables::DEFAULT.hash(&board), board.zobrist());
+    }
+
+    #[test]
+    fn test_any_legal_move((board, mv) in board_and_move()) {
+        let uci: String = mv.to_string();
+        prop_assert_eq!(board.move_from_uci(&uci), Ok(mv));
+        let child: Board = board.make_move(mv);
+        prop_assert_eq!(child.validate(), Ok(()));
+        prop_assert_eq!(board.key_after(mv), child.zobrist());
+    }
+}
@@ -0,0 +1,202 @@
+use laura_core::*;
+
+#[test]
+fn test_get_line() {
+    assert_eq!(
+        get_line(Square::A1, Square::C3),
+        BitBoard(0x8040201008040201)
+    );
+    assert_eq!(
+        get_line(Square::C3, Square::A1),
+        get_line(Square::A1, Square::C3)
+    );
+    assert_eq!(
+        get_line(Square::E2, Square::E7),
+        BitBoard(0x1010101010101010)
+    );
+    assert_eq!(
+        get_line(Square::B4, Square::G4),
+        BitBoard(0x00000000FF000000)
+    );
+    assert_eq!(
+        get_line(Square::H1, Square::A8),
+        BitBoard(0x0102040810204080)
+    );
+    assert!(get_line(Square::A1, Square::B3).is_empty());
+    assert!(get_line(Square::D4, Square::D4).is_empty());
+
+    for a in BitBoard::FULL {
+        for b in BitBoard::FULL {
+            let between: BitBoard = get_between(a, b);
+            let line: BitBoard = get_line(a, b);
+            assert_eq!(line.is_empty(), between.is_empty());
+            assert_eq!(between & !line, BitBoard::EMPTY);
+        }
+    }
+}
+
+#[test]
+fn test_aligned() {
+    assert!(aligned(Square::A1, Square::C3, Square::H8));
+    assert!(aligned(Square::E1, Square::E4, Square::E8));
+    assert!(aligned(Square::B4, Square::G4, Square::A4));
+    assert!(!aligned(Square::A1, Square::C3, Square::C4));
+    assert!(!aligned(Square::A1, Square::B3, Square::C5));
+    assert!(!aligned(Square::D4, Square::D4, Square::D5));
+}
+
+#[test]
+fn test_get_ray() {
+    assert_eq!(
+        get_ray(Square::D4, Direction::North),
+        BitBoard(0x0808080800000000)
+    );
+    assert_eq!(
+        get_ray(Square::A1, Direction::NorthEast),
+        BitBoard(0x8040201008040200)
+    );
+    assert!(get_ray(Square::H5, Direction::East).is_empty());
+    assert!(get_ray(Square::A1, Direction::SouthWest).is_empty());
+
+    for square in BitBoard::FULL {
+        let rook: BitBoard = Direction::ORTHOGONAL
+            .iter()
+            .fold(BitBoard::EMPTY, |acc, &dir| acc | get_ray(square, dir));
+        let bishop: BitBoard = Direction::DIAGONAL
+            .iter()
+            .fold(BitBoard::EMPTY, |acc, &dir| acc | get_ray(square, dir));
+        assert_eq!(rook, get_rook_rays(square));
+        assert_eq!(bishop, get_bishop_rays(square));
+
+        for dir in Direction::ALL {
+            let ray: BitBoard = get_ray(square, dir);
+            if let Some(next) = square.to_bitboard().shift(dir).to_square() {
+                assert!(ray.get_square(next));
+                assert!(get_ray(next, -dir).get_square(square));
+            } else {
+                assert!(ray.is_empty());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_direction() {
+    for dir in Direction::ALL {
+        assert_eq!(dir.opposite().opposite(), dir);
+        assert_eq!(dir.offset(), -dir.opposite().offset());
+        assert_ne!(dir.is_orthogonal(), dir.is_diagonal());
+        assert_eq!(Direction::from_index(dir.to_index()), dir);
+    }
+
+    let bitboard: BitBoard = BitBoard(1 << Square::E4 as u64);
+    for dir in Direction::ALL {
+        let target: Square = Square::from_index((Square::E4 as i8 + dir.offset()) as usize);
+        assert_eq!(bitboard.shift(dir), target.to_bitboard());
+    }
+}
+
+#[test]
+fn test_square_distances() {
+    assert_eq!(Square::B2.file_distance(Square::G7), 5);
+    assert_eq!(Square::B2.rank_distance(Square::C8), 6);
+    assert_eq!(Square::E4.chebyshev_distance(Square::E4), 0);
+    assert_eq!(Square::E4.manhattan_distance(Square::B6), 5);
+
+    for a in BitBoard::FULL {
+        for b in BitBoard::FULL {
+            let distance: u8 = a.chebyshev_distance(b);
+            assert_eq!(get_distance(a, b), distance);
+            assert_eq!(get_distance(b, a), distance);
+            assert_eq!(distance == 1, get_king_attacks(a).get_square(b));
+            assert!(a.manhattan_distance(b) >= distance);
+        }
+    }
+}
+
+#[test]
+fn test_square_iterators() {
+    let rank: Vec<Square> = Rank::Three.squares().collect();
+    assert_eq!(rank.len(), 8);
+    assert_eq!(rank[0], Square::A3);
+    assert_eq!(rank[7], Square::H3);
+
+    let file: Vec<Square> = File::G.squares().collect();
+    assert_eq!(file.first(), Some(&Square::G1));
+    assert_eq!(file.last(), Some(&Square::G8));
+
+    for rank in Rank::ALL {
+        assert!(rank.squares().all(|sq| sq.rank() == rank));
+    }
+    for file in File::ALL {
+        assert!(file.squares().all(|sq| file.to_bitboard().get_square(sq)));
+    }
+
+    let walk: Vec<Square> = Square::E4.walk(Direction::South).collect();
+    assert_eq!(walk, [Square::E3, Square::E2, Square::E1]);
+    assert_eq!(Square::H8.walk(Direction::NorthEast).count(), 0);
+
+    for square in BitBoard::FULL {
+        for dir in Direction::ALL {
+            let ray: BitBoard = square
+                .walk(dir)
+                .fold(BitBoard::EMPTY, |acc, sq| acc.set_square(sq));
+            assert_eq!(ray, get_ray(square, dir));
+        }
+    }
+}
+
+#[test]
+fn test_diagonals() {
+    assert_eq!(Square::C3.diagonal(), Diagonal::A1);
+    assert_eq!(Square::A8.diagonal(), Diagonal::A8);
+    assert_eq!(Square::G8.diagonal(), Diagonal::A2);
+    assert_eq!(Square::C3.anti_diagonal(), AntiDiagonal::E1);
+    assert_eq!(Square::A8.anti_diagonal(), AntiDiagonal::H1);
+    assert_eq!(Square::H8.anti_diagonal(), AntiDiagonal::H8);
+
+    assert_eq!(Diagonal::A1.to_bitboard(), BitBoard(0x8040201008040201));
+    assert_eq!(AntiDiagonal::H1.to_bitboard(), BitBoard(0x0102040810204080));
+
+    let mut union: BitBoard = BitBoard::EMPTY;
+    for (index, diagonal) in Diagonal::ALL.into_iter().enumerate() {
+        assert_eq!(Diagonal::from_index(index), diagonal);
+        assert_eq!(union & diagonal.to_bitboard(), BitBoard::EMPTY);
+        union |= diagonal.to_bitboard();
+    }
+    assert_eq!(union, BitBoard::FULL);
+
+    let mut union: BitBoard = BitBoard::EMPTY;
+    for (index, anti) in AntiDiagonal::ALL.into_iter().enumerate() {
+        assert_eq!(AntiDiagonal::from_index(index), anti);
+        assert_eq!(union & anti.to_bitboard(), BitBoard::EMPTY);
+        union |= anti.to_bitboard();
+    }
+    assert_eq!(union, BitBoard::FULL);
+
+    for square in BitBoard::FULL {
+        let rays: BitBoard = square.diagonal().to_bitboard() | square.anti_diagonal().to_bitboard();
+        assert_eq!(rays.pop_square(square), get_bishop_rays(square));
+    }
+}
+
+#[test]
+fn test_adjacent_files_and_relative_ranks() {
+    assert_eq!(File::A.adjacent(), BitBoard::FILE_B);
+    assert_eq!(File::H.adjacent(), BitBoard::FILE_G);
+    assert_eq!(File::D.adjacent(), BitBoard::FILE_C | BitBoard::FILE_E);
+    for file in File::ALL {
+        assert!(file.adjacent().is_disjoint(file.to_bitboard()));
+    }
+
+    assert_eq!(Rank::Two.relative(Color::White), Rank::Two);
+    assert_eq!(Rank::Two.relative(Color::Black), Rank::Seven);
+    assert_eq!(Square::E7.relative_rank(Color::Black), Rank::Two);
+    for rank in Rank::ALL {
+        assert_eq!(rank.relative(Color::Black).relative(Color::Black), rank);
+        assert_eq!(
+            rank.relative(Color::Black).to_bitboard(),
+            rank.to_bitboard().flip()
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use laura_core::{Board, Move, MoveType, Square};
+use laura_core::{
+    AllMoves, Board, Move, MoveType, SanAnnotation, SanOptions, SanParseError, Square, gen_moves,
+    parse_san,
+};
 use std::str::FromStr;
 
 #[test]
@@ -87,3 +90,139 @@ fn test_san_ilegal_move() {
     assert_ne!(board.to_san(mv), "Na1xc2#");
     assert_eq!(board.to_san(mv), "e4");
 }
+
+#[test]
+fn test_lan() {
+    let board: Board = Board::default();
+    let mv: Move = Move::new(Square::G1, Square::F3, MoveType::Quiet);
+    assert_eq!(board.to_lan(mv), "Ng1-f3");
+    let mv: Move = Move::new(Square::E2, Square::E4, MoveType::DoublePawn);
+    assert_eq!(board.to_lan(mv), "e2-e4");
+
+    let board: Board = Board::from_str("3q3k/4P3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let mv: Move = Move::new(Square::E7, Square::D8, MoveType::CapPromoQueen);
+    assert_eq!(board.to_lan(mv), "e7xd8=Q+");
+
+    let board: Board = Board::from_str("k7/6R1/7Q/8/8/8/8/K7 w - - 0 1").unwrap();
+    let mv: Move = Move::new(Square::H6, Square::H8, MoveType::Quiet);
+    assert_eq!(board.to_lan(mv), "Qh6-h8#");
+
+    let board: Board = Board::from_str("r3k3/8/8/8/8/8/8/4K2R w Kq - 0 1").unwrap();
+    let mv: Move = Move::new(Square::E1, Square::G1, MoveType::KingCastle);
+    assert_eq!(board.to_lan(mv), "O-O");
+}
+
+#[test]
+fn test_san_figurine() {
+    let options: SanOptions = SanOptions {
+        figurine: true,
+        ..SanOptions::DEFAULT
+    };
+
+    let board: Board = Board::default();
+    let mv: Move = Move::new(Square::G1, Square::F3, MoveType::Quiet);
+    assert_eq!(board.to_san(mv).with_options(options), "♘f3");
+    let mv: Move = Move::new(Square::E2, Square::E4, MoveType::DoublePawn);
+    assert_eq!(board.to_san(mv).with_options(options), "e4");
+
+    let board: Board = Board::from_str("2kr3r/8/8/R7/4Q2Q/8/8/R1K4Q w - - 0 1").unwrap();
+    let mv: Move = Move::new(Square::H4, Square::E1, MoveType::Quiet);
+    assert_eq!(board.to_san(mv).with_options(options), "♕h4e1");
+
+    let board: Board = Board::from_str("1r6/P3k3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let mv: Move = Move::new(Square::A7, Square::B8, MoveType::CapPromoQueen);
+    assert_eq!(board.to_san(mv).with_options(options).to_string(), "axb8=♕");
+}
+
+#[test]
+fn test_san_castling_options() {
+    let board: Board = Board::from_str("3k4/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    let mv: Move = Move::new(Square::E1, Square::G1, MoveType::KingCastle);
+    assert_eq!(board.to_san(mv), "O-O");
+
+    let board: Board = Board::from_str("5k2/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    assert_eq!(board.to_san(mv), "O-O+");
+
+    let board: Board = Board::from_str("4rkr1/4p1p1/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    assert_eq!(board.to_san(mv), "O-O#");
+
+    let options: SanOptions = SanOptions {
+        zero_castling: true,
+        ..SanOptions::DEFAULT
+    };
+    assert_eq!(board.to_san(mv).with_options(options), "0-0#");
+
+    let board: Board = Board::from_str("r3k3/8/8/8/8/8/8/5RK1 b q - 0 1").unwrap();
+    let mv: Move = Move::new(Square::E8, Square::C8, MoveType::QueenCastle);
+    assert_eq!(board.to_san(mv).with_options(options), "0-0-0");
+}
+
+#[test]
+fn test_san_cached() {
+    let board: Board = Board::kiwipete();
+    let mv: Move = Move::new(Square::E2, Square::A6, MoveType::Capture);
+    let san = board.to_san(mv);
+    assert_eq!(san.as_str(), "Bxa6");
+    assert_eq!(san.to_string(), "Bxa6");
+    assert_eq!(san.mv(), mv);
+    assert_eq!(san.board(), &board);
+}
+
+#[test]
+fn test_parse_san_roundtrip() {
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "2kr3r/8/8/R7/4Q2Q/8/8/R1K4Q w - - 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        for mv in gen_moves::<AllMoves>(&board) {
+            let san = board.to_san(mv);
+            assert_eq!(board.parse_san(san.as_str()), Ok(mv), "{fen} {san}");
+        }
+    }
+}
+
+#[test]
+fn test_parse_san_annotations() {
+    let board: Board =
+        Board::from_str("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+    let ep: Move = Move::new(Square::E5, Square::F6, MoveType::EnPassant);
+    assert_eq!(parse_san("exf6", &board), Ok((ep, None)));
+    assert_eq!(parse_san("exf6 e.p.", &board), Ok((ep, None)));
+    assert_eq!(
+        parse_san("exf6e.p.!!", &board),
+        Ok((ep, Some(SanAnnotation::Brilliant)))
+    );
+    assert_eq!(
+        parse_san("Nf3+?!", &board),
+        Ok((
+            Move::new(Square::G1, Square::F3, MoveType::Quiet),
+            Some(SanAnnotation::Dubious)
+        ))
+    );
+    assert_eq!(board.parse_san("Ngf3??"), board.parse_san("Nf3"));
+    assert_eq!(board.parse_san("e4"), board.parse_san("e2e4"));
+    assert_eq!(board.parse_san("Nf3!!!"), Err(SanParseError::InvalidSyntax));
+
+    let board: Board = Board::from_str("1r6/P3k3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let mv: Move = Move::new(Square::A7, Square::B8, MoveType::CapPromoKnight);
+    assert_eq!(board.parse_san("axb8N"), Ok(mv));
+    assert_eq!(board.parse_san("ab8=N+"), Ok(mv));
+
+    let board: Board = Board::kiwipete();
+    assert_eq!(board.parse_san("0-0-0"), board.parse_san("O-O-O"));
+    assert_eq!(
+        board.parse_san("Nd1"),
+        Ok(Move::new(Square::C3, Square::D1, MoveType::Quiet))
+    );
+    assert_eq!(board.parse_san("Ke3"), Err(SanParseError::IllegalMove));
+    assert_eq!(board.parse_san("Zf3"), Err(SanParseError::InvalidSyntax));
+    assert_eq!(board.parse_san(""), Err(SanParseError::InvalidSyntax));
+
+    let board: Board = Board::from_str("2kr3r/8/8/R7/4Q2Q/8/8/R1K4Q w - - 0 1").unwrap();
+    assert_eq!(board.parse_san("Ra3"), Err(SanParseError::AmbiguousMove));
+    assert_eq!(board.parse_san("Qe1"), Err(SanParseError::AmbiguousMove));
+    assert_eq!(board.parse_san("Qhe1"), Err(SanParseError::AmbiguousMove));
+}
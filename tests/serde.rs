@@ -0,0 +1,54 @@
+#![cfg(feature = "serde")]
+
+use laura_core::{Board, CastleRights, Move, MoveType, Piece, Square, Zobrist};
+use std::str::FromStr;
+
+#[test]
+fn test_serde_roundtrip() {
+    let board: Board = Board::kiwipete();
+    let json: String = serde_json::to_string(&board).unwrap();
+    assert_eq!(
+        json,
+        "\"r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1\""
+    );
+    assert_eq!(serde_json::from_str::<Board>(&json).unwrap(), board);
+
+    let mv: Move = Move::new(Square::E1, Square::G1, MoveType::KingCastle);
+    let json: String = serde_json::to_string(&mv).unwrap();
+    assert_eq!(serde_json::from_str::<Move>(&json).unwrap(), mv);
+
+    assert_eq!(serde_json::to_string(&Square::E4).unwrap(), "\"e4\"");
+    assert_eq!(
+        serde_json::from_str::<Square>("\"h8\"").unwrap(),
+        Square::H8
+    );
+
+    let rights: CastleRights = CastleRights::from_str("Kq").unwrap();
+    assert_eq!(serde_json::to_string(&rights).unwrap(), "\"Kq\"");
+    assert_eq!(
+        serde_json::from_str::<CastleRights>("\"Kq\"").unwrap(),
+        rights
+    );
+
+    assert_eq!(serde_json::to_string(&Piece::BN).unwrap(), "\"n\"");
+    assert_eq!(serde_json::from_str::<Piece>("\"Q\"").unwrap(), Piece::WQ);
+
+    let zobrist: Zobrist = board.zobrist();
+    let json: String = serde_json::to_string(&zobrist).unwrap();
+    assert_eq!(serde_json::from_str::<Zobrist>(&json).unwrap(), zobrist);
+}
+
+#[test]
+fn test_serde_errors() {
+    assert!(serde_json::from_str::<Board>("\"8/8 w - - 0 1\"").is_err());
+    assert!(serde_json::from_str::<Square>("\"i9\"").is_err());
+    assert!(serde_json::from_str::<CastleRights>("\"KX\"").is_err());
+    assert!(serde_json::from_str::<Piece>("\"x\"").is_err());
+    assert!(serde_json::from_str::<Square>("4").is_err());
+
+    // 0b0110 is not an assigned move type code.
+    let raw: u16 = 0b0110 << 12 | 12 << 6 | 28;
+    let err: serde_json::Error = serde_json::from_str::<Move>(&raw.to_string()).unwrap_err();
+    assert!(err.to_string().contains("invalid move type code"));
+    assert!(serde_json::from_str::<Move>("0").is_ok());
+}
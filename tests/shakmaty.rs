@@ -0,0 +1,86 @@
+#![cfg(feature = "shakmaty")]
+
+use laura_core::*;
+use shakmaty::fen::Fen;
+use shakmaty::uci::UciMove;
+use shakmaty::{CastlingMode, Chess, Position};
+use std::str::FromStr;
+
+const FENS: [&str; 5] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 12 40",
+    "1r2k1r1/8/8/8/8/8/8/1R2K1R1 b GBgb - 3 17",
+];
+
+#[test]
+fn test_shakmaty_board_round_trip() {
+    for fen in FENS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let chess: Chess = Chess::try_from(&board).unwrap();
+        assert_eq!(Board::try_from(&chess), Ok(board), "{fen}");
+
+        let expected: Chess = Fen::from_ascii(fen.as_bytes())
+            .unwrap()
+            .into_position(CastlingMode::Chess960)
+            .unwrap();
+        assert_eq!(Board::try_from(&expected), Ok(board), "{fen}");
+    }
+}
+
+#[test]
+fn test_shakmaty_moves() {
+    for fen in FENS {
+        let board: Board = Board::from_str(fen).unwrap();
+        let chess: Chess = Chess::try_from(&board).unwrap();
+        let moves: MoveList = gen_moves::<AllMoves>(&board);
+        assert_eq!(moves.len(), chess.legal_moves().len(), "{fen}");
+
+        for &mv in moves.iter() {
+            let m: shakmaty::Move = board.to_shakmaty_move(mv);
+            assert!(chess.is_legal(&m), "{fen} {mv}");
+            assert_eq!(board.from_shakmaty_move(&m), Some(mv));
+
+            let after: Chess = chess.clone().play(&m).unwrap();
+            assert_eq!(
+                Board::try_from(&after),
+                Ok(board.make_move(mv)),
+                "{fen} {mv}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_shakmaty_primitives() {
+    for index in 0..Square::NUM_SQUARES {
+        let square: Square = Square::from_index(index);
+        assert_eq!(
+            shakmaty::Square::from(square).to_string(),
+            square.to_string()
+        );
+        assert_eq!(Square::from(shakmaty::Square::from(square)), square);
+    }
+    for index in 0..12 {
+        let piece: Piece = Piece::from_index(index).unwrap();
+        assert_eq!(shakmaty::Piece::from(piece).char(), piece.to_char());
+        assert_eq!(Piece::from(shakmaty::Piece::from(piece)), piece);
+    }
+
+    let board: Board = Board::default();
+    let mv: Move = board.find_move("e2e4").unwrap();
+    assert_eq!(UciMove::from(mv).to_string(), "e2e4");
+}
+
+#[test]
+fn test_shakmaty_unsupported_castling() {
+    let chess: Chess = Fen::from_ascii(b"1rk3r1/8/8/8/8/8/8/1RK3R1 w GBgb - 0 1")
+        .unwrap()
+        .into_position(CastlingMode::Chess960)
+        .unwrap();
+    assert!(matches!(
+        Board::try_from(&chess),
+        Err(BoardParseError::InvalidCastlingRights(_))
+    ));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "simd")]
+
+use laura_core::*;
+use std::str::FromStr;
+
+#[test]
+fn test_attacks_x4() {
+    let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+    for _ in 0..256 {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        let blockers: BitBoard = BitBoard(seed & (seed >> 9));
+        let squares: [Square; 4] =
+            [0, 16, 32, 48].map(|shift| Square::from_index(((seed >> shift) & 63) as usize));
+
+        let rooks: [BitBoard; 4] = get_rook_attacks_x4(squares, blockers);
+        let bishops: [BitBoard; 4] = get_bishop_attacks_x4(squares, blockers);
+        for (lane, &square) in squares.iter().enumerate() {
+            assert_eq!(rooks[lane], get_rook_attacks(square, blockers));
+            assert_eq!(bishops[lane], get_bishop_attacks(square, blockers));
+        }
+    }
+}
+
+#[test]
+fn test_batched_slider_attacks() {
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        let occupancy: BitBoard = board.combined_bitboard();
+        for color in [Color::White, Color::Black] {
+            let allied: BitBoard = board.sides_bitboard[color as usize];
+            let orthogonal: BitBoard = (board.rooks() | board.queens()) & allied;
+            let diagonal: BitBoard = (board.bishops() | board.queens()) & allied;
+
+            let mut expected: BitBoard = BitBoard::EMPTY;
+            for square in orthogonal {
+                expected |= get_rook_attacks(square, occupancy);
+            }
+            for square in diagonal {
+                expected |= get_bishop_attacks(square, occupancy);
+            }
+            assert_eq!(
+                batched_slider_attacks(orthogonal, diagonal, occupancy),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "strict")]
+
+use laura_core::*;
+
+#[test]
+fn test_strict_legal_moves() {
+    let board: Board = Board::kiwipete();
+    for mv in gen_moves::<AllMoves>(&board) {
+        let child: Board = board.make_move(mv);
+        for reply in gen_moves::<AllMoves>(&child) {
+            child.make_move(reply);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "illegal move")]
+fn test_strict_wrong_move_type() {
+    Board::default().make_move(Move::new(Square::E2, Square::E4, MoveType::Quiet));
+}
+
+#[test]
+#[should_panic(expected = "illegal move")]
+fn test_strict_pinned_piece() {
+    let board: Board = "k3r3/8/8/8/8/8/4R3/4K3 w - - 0 1".parse().unwrap();
+    board.make_move(Move::new(Square::E2, Square::A2, MoveType::Quiet));
+}
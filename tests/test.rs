@@ -1,3 +1,6 @@
+// Several assertions compare against literal booleans, in the style of the original tests.
+#![allow(clippy::bool_assert_comparison)]
+
 use std::str::FromStr;
 
 use laura_core::*;
@@ -52,6 +55,81 @@ fn test_movelist_iter() {
     }
 }
 
+#[test]
+fn test_movelist_editing() {
+    let board: Board = Board::kiwipete();
+    let moves: MoveList = gen_moves::<AllMoves>(&board);
+
+    let mut captures: MoveList = moves;
+    captures.retain(|mv| mv.is_capture());
+    let collected: MoveList = moves.iter().copied().filter(|mv| mv.is_capture()).collect();
+    assert_eq!(captures.as_slice(), collected.as_slice());
+
+    let mut doubled: MoveList = moves;
+    doubled.extend(moves);
+    doubled.extend(moves);
+    assert_eq!(doubled.len(), moves.len() * 3);
+    doubled.extend(moves.iter().copied().cycle().take(1000));
+    assert_eq!(doubled.len(), 252);
+    doubled.truncate(moves.len());
+    assert_eq!(doubled.as_slice(), moves.as_slice());
+
+    let last: Move = doubled[doubled.len() - 1];
+    let first: Move = doubled.swap_remove(0);
+    assert_eq!(first, moves[0]);
+    assert_eq!(doubled[0], last);
+    assert_eq!(doubled.len(), moves.len() - 1);
+}
+
+#[test]
+fn test_movelist_pick_best() {
+    let board: Board = Board::kiwipete();
+    let mut moves: MoveList = gen_moves::<AllMoves>(&board);
+    let original: MoveList = moves;
+    let score = |mv: &Move| (mv.0 as i32 * 7919) % 1000;
+    let mut scores: Vec<i32> = moves.iter().map(score).collect();
+
+    let mut picked: Vec<Move> = Vec::new();
+    while let Some(mv) = moves.pick_best(&mut scores, picked.len()) {
+        assert_eq!(scores[picked.len()], score(&mv));
+        picked.push(mv);
+    }
+    assert_eq!(picked.len(), original.len());
+    assert!(
+        picked
+            .windows(2)
+            .all(|pair| score(&pair[0]) >= score(&pair[1]))
+    );
+    assert_eq!(moves.as_slice(), picked.as_slice());
+    assert_eq!(moves.pick_best(&mut scores, 100), None);
+}
+
+#[test]
+#[should_panic]
+fn test_movelist_swap_remove_out_of_bounds() {
+    MoveList::default().swap_remove(0);
+}
+
+#[test]
+fn test_movelist_display_uci() {
+    assert_eq!(MoveList::default().display_uci().to_string(), "");
+
+    let board: Board = Board::from_str("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1").unwrap();
+    let moves: MoveList = gen_moves::<AllMoves>(&board);
+    let expected: Vec<String> = moves.iter().map(|mv| mv.to_string()).collect();
+    assert_eq!(moves.display_uci().to_string(), expected.join(" "));
+
+    let pv: MoveList = ["e2e4", "e7e5", "e1e2"]
+        .iter()
+        .scan(Board::default(), |board: &mut Board, uci: &&str| {
+            let mv: Move = board.find_move(uci).unwrap();
+            *board = board.make_move(mv);
+            Some(mv)
+        })
+        .collect();
+    assert_eq!(pv.display_uci().to_string(), "e2e4 e7e5 e1e2");
+}
+
 #[test]
 fn test_piece_new() {
     let piece: Piece = Piece::new(PieceType::King, Color::White);
@@ -126,6 +204,111 @@ fn test_castling_from_string() {
     println!("{}", castle_rights);
 }
 
+#[test]
+fn test_castling_rook_files() {
+    let rights: CastleRights = CastleRights::from_str("HAha").unwrap();
+    assert_eq!(rights, CastleRights::from_str("KQkq").unwrap());
+    assert_eq!(rights.to_string(), "KQkq");
+
+    let rights: CastleRights = CastleRights::from_str("Gq").unwrap();
+    assert_eq!(rights.kingside_rook_file(Color::White), File::G);
+    assert_eq!(rights.queenside_rook_file(Color::Black), File::A);
+    assert_eq!(rights.swap_colors().to_string(), "Qg");
+    assert_eq!(rights.swap_colors().swap_colors(), rights);
+    assert_eq!(
+        CastleRights::from_str("KE"),
+        Err(CastleRightsParseError::InvalidChar('E'))
+    );
+
+    // Rights are cleared by square identity, following the rook files.
+    assert!(
+        rights
+            .update(Square::H1, Square::H5)
+            .has_kingside(Color::White)
+    );
+    assert!(
+        !rights
+            .update(Square::G1, Square::G5)
+            .has_kingside(Color::White)
+    );
+    assert!(
+        !rights
+            .update(Square::B2, Square::G1)
+            .has_kingside(Color::White)
+    );
+
+    // Castling with a rook standing on the king's destination.
+    let board: Board = Board::from_str("4k3/8/8/8/8/8/8/R3K1R1 w GA - 0 1").unwrap();
+    assert_eq!(board.castling_rights().to_string(), "GQ");
+    let castle: Move = Move::new(Square::E1, Square::G1, MoveType::KingCastle);
+    assert!(gen_moves::<AllMoves>(&board).contains(&castle));
+    assert_eq!(board.move_from_uci("e1g1"), Ok(castle));
+    let board: Board = board.make_move(castle);
+    assert_eq!(board.piece_on(Square::G1), Some(Piece::WK));
+    assert_eq!(board.piece_on(Square::F1), Some(Piece::WR));
+    assert_eq!(board.castling_rights(), CastleRights::null());
+    assert_eq!(
+        board.to_fen().to_string(),
+        "4k3/8/8/8/8/8/8/R4RK1 b - - 1 1"
+    );
+
+    // The kingside path of a B-file rook crosses the queenside squares.
+    let board: Board = Board::from_str("4k3/8/8/8/8/8/8/1RN1K3 w B - 0 1").unwrap();
+    let castle: Move = Move::new(Square::E1, Square::C1, MoveType::QueenCastle);
+    assert!(!gen_moves::<AllMoves>(&board).contains(&castle));
+    let board: Board = Board::from_str("4k3/8/8/8/8/8/8/1R2K3 w B - 0 1").unwrap();
+    assert!(gen_moves::<AllMoves>(&board).contains(&castle));
+}
+
+#[test]
+fn test_castling_rights_editing() {
+    let mut rights: CastleRights = CastleRights::null();
+    rights.grant(Color::White, CastleSide::Queen);
+    rights.grant(Color::Black, CastleSide::King);
+    assert_eq!(rights.to_string(), "Qk");
+    assert!(rights.has(Color::Black, CastleSide::King));
+    assert!(!rights.has(Color::Black, CastleSide::Queen));
+
+    rights.revoke(Color::White, CastleSide::Queen);
+    assert_eq!(rights, CastleRights::null().with_kingside(Color::Black));
+    assert_eq!(
+        CastleRights::null()
+            .with_kingside(Color::White)
+            .with_queenside(Color::White)
+            .with_kingside(Color::Black)
+            .with_queenside(Color::Black),
+        CastleRights::from_str("KQkq").unwrap()
+    );
+
+    let all: CastleRights = CastleRights::from_str("KQkq").unwrap();
+    assert_eq!(
+        all.iter().collect::<Vec<(Color, CastleSide)>>(),
+        [
+            (Color::White, CastleSide::King),
+            (Color::White, CastleSide::Queen),
+            (Color::Black, CastleSide::King),
+            (Color::Black, CastleSide::Queen),
+        ]
+    );
+    assert_eq!(CastleRights::null().into_iter().count(), 0);
+
+    // Composition keeps the rook files of the rights involved.
+    let white: CastleRights = CastleRights::from_str("GB").unwrap();
+    let black: CastleRights = CastleRights::from_str("kq").unwrap();
+    let mut combined: CastleRights = white | black;
+    assert_eq!(combined.to_string(), "GBkq");
+    assert_eq!(combined - black, white);
+    assert_eq!(combined - white, black);
+    assert_eq!(white | all, CastleRights::from_str("GBkq").unwrap());
+
+    combined -= CastleRights::null().with_kingside(Color::White);
+    assert_eq!(combined.to_string(), "Bkq");
+    assert!(!combined.has(Color::White, CastleSide::King));
+    assert_eq!(combined.kingside_rook_file(Color::White), File::H);
+    combined |= white;
+    assert_eq!(combined.to_string(), "GBkq");
+}
+
 #[test]
 fn test_bishop_magic_attacks() {
     let blockers: BitBoard = BitBoard(76631562411574272);
@@ -230,6 +413,15 @@ fn test_null_move() {
     println!("{}", board);
 }
 
+#[test]
+fn test_try_null_move() {
+    let board: Board = Board::default().make_uci_move("e2e4").unwrap();
+    assert_eq!(board.try_null_move(), Some(board.null_move()));
+
+    let check: Board = Board::from_str("4k3/8/8/8/8/8/4r3/4K3 w - - 0 1").unwrap();
+    assert_eq!(check.try_null_move(), None);
+}
+
 #[test]
 fn test_uci_move() {
     let board: Board = Board::default();
@@ -288,6 +480,65 @@ fn test_tactical_moves() {
     }
 }
 
+#[test]
+fn test_movegen_context() {
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "8/8/2k5/5q2/5n2/8/5K2/8 b - - 0 1",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        let ctx: MovegenContext = MovegenContext::new(&board);
+        let mut staged: Vec<Move> = gen_moves_with::<TacticalMoves>(&board, &ctx).to_vec();
+        staged.extend(gen_moves_with::<QuietMoves>(&board, &ctx));
+        staged.sort_by_key(|mv| mv.0);
+
+        let mut all: Vec<Move> = gen_moves::<AllMoves>(&board).to_vec();
+        all.sort_by_key(|mv| mv.0);
+        assert_eq!(staged, all, "{fen}");
+    }
+
+    let board: Board = Board::from_str("4k3/8/8/b7/8/8/3R4/4K2r w - - 0 1").unwrap();
+    let ctx: MovegenContext = MovegenContext::new(&board);
+    assert_eq!(ctx.king_square, Square::E1);
+    assert!(ctx.diagonal_pins.get_square(Square::D2));
+    assert_eq!(
+        ctx.check_mask,
+        get_between(Square::E1, Square::H1) | Square::H1.to_bitboard()
+    );
+}
+
+#[test]
+fn test_gen_moves_for() {
+    const WHITE: usize = Color::White as usize;
+    const BLACK: usize = Color::Black as usize;
+
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+        "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N w - - 0 1",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        let moves: MoveList = match board.side() {
+            Color::White => gen_moves_for::<WHITE, AllMoves>(&board),
+            Color::Black => gen_moves_for::<BLACK, AllMoves>(&board),
+        };
+        assert_eq!(
+            moves.as_slice(),
+            gen_moves::<AllMoves>(&board).as_slice(),
+            "{fen}"
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "COLOR must be the side to move")]
+fn test_gen_moves_for_wrong_side() {
+    gen_moves_for::<{ Color::Black as usize }, AllMoves>(&Board::default());
+}
+
 #[test]
 fn test_board_from_string() {
     let board: Board =
@@ -310,3 +561,292 @@ fn test_board_default() {
     assert_eq!(board.enpassant_square, None);
     assert_eq!(board.zobrist(), Zobrist(0xc18ae40f70a32d9b));
 }
+
+#[test]
+fn test_parse_errors() {
+    use std::error::Error;
+
+    assert_eq!(Square::from_str("e9"), Err(SquareParseError::InvalidName));
+    assert_eq!(
+        CastleRights::from_str("KX"),
+        Err(CastleRightsParseError::InvalidChar('X'))
+    );
+
+    let error: BoardParseError = Board::from_str("4k3/8/8/8/8/8/8/4K3 w KX - 0 1").unwrap_err();
+    assert_eq!(
+        error,
+        BoardParseError::InvalidCastlingRights(CastleRightsParseError::InvalidChar('X'))
+    );
+    assert_eq!(
+        error.source().map(|source| source.to_string()),
+        Some(CastleRightsParseError::InvalidChar('X').to_string())
+    );
+
+    let boxed: Box<dyn Error> =
+        Box::new(Board::from_str("4k3/7/8/8/8/8/8/4K3 w - - 0 1").unwrap_err());
+    assert_eq!(
+        boxed.to_string(),
+        "A FEN rank does not contain exactly 8 squares"
+    );
+    assert!(boxed.source().is_none());
+}
+
+#[test]
+fn test_uci_move_errors() {
+    let board: Board = Board::default();
+    assert_eq!(board.find_move("e2e"), Err(UciMoveError::InvalidLength));
+    assert_eq!(board.find_move("e2e4q4"), Err(UciMoveError::InvalidLength));
+    assert_eq!(
+        board.find_move("e2i4"),
+        Err(UciMoveError::InvalidSquare(SquareParseError::InvalidName))
+    );
+    assert_eq!(
+        board.find_move("e7e8k"),
+        Err(UciMoveError::InvalidPromotion('k'))
+    );
+    assert_eq!(board.find_move("e2e5"), Err(UciMoveError::IllegalMove));
+    assert_eq!(board.make_uci_move("e1e2"), Err(UciMoveError::IllegalMove));
+}
+
+#[test]
+fn test_parse_uci() {
+    assert_eq!(Move::parse_uci("g1f3"), Ok((Square::G1, Square::F3, None)));
+    assert_eq!(
+        Move::parse_uci("a2a1r"),
+        Ok((Square::A2, Square::A1, Some(PieceType::Rook)))
+    );
+    assert_eq!(
+        Move::parse_uci("a7a8Q"),
+        Err(UciMoveError::InvalidPromotion('Q'))
+    );
+    assert_eq!(Move::parse_uci("e2"), Err(UciMoveError::InvalidLength));
+}
+
+#[test]
+fn test_write_uci() {
+    let mut buf: [u8; 6] = [0u8; 6];
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        for mv in gen_moves::<AllMoves>(&board) {
+            assert_eq!(mv.write_uci(&mut buf), mv.to_string());
+        }
+    }
+
+    #[cfg(feature = "variants")]
+    {
+        let drop: Move = Move::new_drop(PieceType::Knight, Square::F3);
+        assert_eq!(drop.write_uci(&mut buf), "N@f3");
+        assert_eq!(drop, "N@f3");
+    }
+}
+
+#[test]
+fn test_move_from_uci() {
+    // Every legal move must round-trip through its UCI string with the same move type.
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        for mv in gen_moves::<AllMoves>(&board) {
+            assert_eq!(board.move_from_uci(&mv.to_string()), Ok(mv));
+        }
+    }
+
+    let board: Board = Board::kiwipete();
+    assert_eq!(
+        board.move_from_uci("e1g1").map(|mv| mv.get_type()),
+        Ok(MoveType::KingCastle)
+    );
+    assert_eq!(board.move_from_uci("e1e3"), Err(UciMoveError::IllegalMove));
+    assert_eq!(board.move_from_uci("a7a6"), Err(UciMoveError::IllegalMove));
+    assert_eq!(board.move_from_uci("e2e4q"), Err(UciMoveError::IllegalMove));
+}
+
+#[test]
+fn test_castling_notation() {
+    let board: Board = Board::kiwipete();
+    for mv in gen_moves::<AllMoves>(&board) {
+        for notation in [
+            CastlingNotation::KingDestination,
+            CastlingNotation::KingTakesRook,
+        ] {
+            let uci: String = board.to_uci(mv, notation).to_string();
+            assert_eq!(board.move_from_uci(&uci), Ok(mv), "{uci}");
+        }
+    }
+
+    let black: Board = board.make_uci_move("a2a3").unwrap();
+    assert_eq!(
+        black.make_uci_move("e8h8").unwrap(),
+        black.make_uci_move("e8g8").unwrap()
+    );
+    let castle: Move = black.move_from_uci("e8a8").unwrap();
+    assert_eq!(castle.get_type(), MoveType::QueenCastle);
+    assert_eq!(
+        black
+            .to_uci(castle, CastlingNotation::KingTakesRook)
+            .to_string(),
+        "e8a8"
+    );
+    assert_eq!(
+        black
+            .to_uci(castle, CastlingNotation::default())
+            .to_string(),
+        castle.to_string()
+    );
+
+    // Taking the own rook still needs the castling right and a clear path.
+    let board: Board = Board::from_str("r3k2r/8/8/8/8/8/8/R3K2R w Kq - 0 1").unwrap();
+    assert_eq!(board.move_from_uci("e1a1"), Err(UciMoveError::IllegalMove));
+    assert!(board.move_from_uci("e1h1").is_ok());
+    let board: Board = Board::default();
+    assert_eq!(board.move_from_uci("e1h1"), Err(UciMoveError::IllegalMove));
+    assert_eq!(
+        Board::from_uci_position(
+            "position fen r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1 moves e1a1 e8h8"
+        ),
+        Ok(Board::from_str("r4rk1/8/8/8/8/8/8/2KR3R w - - 2 2").unwrap())
+    );
+}
+
+#[test]
+fn test_apply_uci_moves() {
+    let board: Board = Board::default();
+    assert_eq!(board.apply_uci_moves(""), Ok(board));
+    assert_eq!(
+        board
+            .apply_uci_moves("  e2e4 c7c5\tg1f3  ")
+            .unwrap()
+            .to_fen(),
+        "rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+    );
+    assert_eq!(
+        board.apply_uci_moves("e2e4 e2e4"),
+        Err(UciMoveError::IllegalMove)
+    );
+    assert_eq!(
+        board.apply_uci_moves("e2e4 e7"),
+        Err(UciMoveError::InvalidLength)
+    );
+}
+
+#[test]
+fn test_uci_position() {
+    assert_eq!(
+        Board::from_uci_position("position startpos"),
+        Ok(Board::default())
+    );
+    assert_eq!(
+        Board::from_uci_position("startpos moves e2e4")
+            .unwrap()
+            .to_fen(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    );
+
+    let kiwipete: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let command: String = format!("position fen {kiwipete} moves e1g1 a6e2");
+    let board: Board = Board::from_uci_position(&command).unwrap();
+    assert_eq!(
+        board,
+        Board::kiwipete().apply_uci_moves("e1g1 a6e2").unwrap()
+    );
+
+    // Short FENs without move counters are accepted.
+    assert_eq!(
+        Board::from_uci_position("position fen 4k3/8/8/8/8/8/8/4K3 w - - moves e1d1")
+            .unwrap()
+            .to_fen(),
+        "4k3/8/8/8/8/8/8/3K4 b - - 1 1"
+    );
+
+    assert_eq!(
+        Board::from_uci_position("position"),
+        Err(UciPositionError::MissingPosition)
+    );
+    assert_eq!(
+        Board::from_uci_position("position startpos e2e4"),
+        Err(UciPositionError::UnexpectedToken(2))
+    );
+    assert_eq!(
+        Board::from_uci_position("position kiwipete"),
+        Err(UciPositionError::UnexpectedToken(1))
+    );
+    assert_eq!(
+        Board::from_uci_position("position fen 4k3/8/8/8/8/8/8/4K3 w - - 0 1 e1d1"),
+        Err(UciPositionError::UnexpectedToken(8))
+    );
+    assert_eq!(
+        Board::from_uci_position("position fen 4k3/8/8/8/8/8/8/4K2 w - - 0 1"),
+        Err(UciPositionError::InvalidFen(
+            2,
+            BoardParseError::InvalidBoardLayout
+        ))
+    );
+    assert_eq!(
+        Board::from_uci_position("position fen moves e2e4"),
+        Err(UciPositionError::InvalidFen(
+            2,
+            BoardParseError::FenTooShort
+        ))
+    );
+    assert_eq!(
+        Board::from_uci_position("position startpos moves e2e4 e7e5 e1e2q"),
+        Err(UciPositionError::InvalidMove(5, UciMoveError::IllegalMove))
+    );
+}
+
+#[test]
+fn test_scored_move_list() {
+    let board: Board = Board::kiwipete();
+    let moves: MoveList = gen_moves::<AllMoves>(&board);
+    let score = |mv: Move| (mv.0 as i32 * 7919) % 1000;
+
+    let mut sorted: ScoredMoveList = ScoredMoveList::from_moves(&moves, score);
+    assert_eq!(sorted.len(), moves.len());
+    sorted.sort_unstable_by_score();
+    assert!(sorted.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+    let mut picked: ScoredMoveList = ScoredMoveList::from_moves(&moves, score);
+    let mut order: Vec<i32> = Vec::new();
+    while let Some(scored) = picked.pick_max() {
+        assert_eq!(scored.score, score(scored.mv));
+        order.push(scored.score);
+    }
+    assert!(picked.is_empty());
+    assert_eq!(
+        order,
+        sorted
+            .iter()
+            .map(|scored| scored.score)
+            .collect::<Vec<i32>>()
+    );
+}
+
+#[test]
+fn test_ext_move() {
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+    ] {
+        let board: Board = Board::from_str(fen).unwrap();
+        for mv in gen_moves::<AllMoves>(&board) {
+            let ext: ExtMove = board.ext_move(mv);
+            assert_eq!(Move::from(ext), mv);
+            assert_eq!(ext.to_string(), mv.to_string());
+            assert_eq!(Some(ext.piece()), board.piece_on(mv.get_src()));
+            assert_eq!(ext.captured().is_some(), mv.is_capture(), "{fen} {mv}");
+        }
+    }
+
+    let board: Board =
+        Board::from_str("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+    let ext: ExtMove = board.ext_move(board.find_move("e5f6").unwrap());
+    assert_eq!((ext.piece(), ext.captured()), (Piece::WP, Some(Piece::BP)));
+    assert_eq!(ExtMove::new(Move::null(), Piece::BK, None).captured(), None);
+}
@@ -0,0 +1,459 @@
+#![cfg(feature = "variants")]
+
+use laura_core::*;
+
+#[test]
+fn test_crazyhouse_perft() {
+    // Reference counts from shakmaty.
+    let positions: [(&str, &[u64]); 4] = [
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1",
+            &[20, 400, 8902, 197281],
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R[] w KQkq - 0 1",
+            &[48, 2039, 106456],
+        ),
+        (
+            "r1b1k2r/ppp2ppp/2n5/3np3/1b6/2N2N2/PPPP1PPP/R1B1KB1R[QPqp] w KQkq - 0 7",
+            &[95, 9254, 669195],
+        ),
+        (
+            "rnbqk1nr/ppp2ppp/8/4b3/8/8/PPPPQ~PPP/RNB1KBNR[Pp] b KQkq - 0 5",
+            &[69, 4200, 218260],
+        ),
+    ];
+
+    for (fen, counts) in positions {
+        let position: Crazyhouse = fen.parse().unwrap();
+        for (depth, &count) in counts.iter().enumerate() {
+            assert_eq!(
+                position.perft(depth + 1),
+                count,
+                "{fen} at depth {}",
+                depth + 1
+            );
+        }
+    }
+}
+
+#[test]
+fn test_crazyhouse_fen() {
+    let fen: &str = "rnbqk1nr/ppp2ppp/8/4b3/8/8/PPPPQ~PPP/RNB1KBNR[QPbp] b KQkq - 0 5";
+    let position: Crazyhouse = fen.parse().unwrap();
+    assert_eq!(position.to_string(), fen);
+    assert_eq!(position.promoted, BitBoard::EMPTY.set_square(Square::E2));
+    assert_eq!(position.pockets.count(Color::White, PieceType::Queen), 1);
+    assert_eq!(position.pockets.count(Color::Black, PieceType::Bishop), 1);
+
+    let ninth_rank: Crazyhouse = "rnbqk1nr/ppp2ppp/8/4b3/8/8/PPPPQ~PPP/RNB1KBNR/QPbp b KQkq - 0 5"
+        .parse()
+        .unwrap();
+    assert_eq!(ninth_rank, position);
+
+    let standard: Crazyhouse = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        .parse()
+        .unwrap();
+    assert_eq!(standard, Crazyhouse::default());
+    assert_eq!(standard.zobrist(), Board::default().zobrist());
+
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[K] w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[Q w KQkq - 0 1",
+    ] {
+        assert_eq!(
+            fen.parse::<Crazyhouse>(),
+            Err(BoardParseError::InvalidPocket)
+        );
+    }
+    assert_eq!(
+        "~nbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1".parse::<Crazyhouse>(),
+        Err(BoardParseError::InvalidBoardLayout)
+    );
+}
+
+#[test]
+fn test_crazyhouse_moves() {
+    let position: Crazyhouse = "4k3/1P6/8/8/8/8/8/4K3[] w - - 0 1".parse().unwrap();
+    let position: Crazyhouse = position.make_move(position.move_from_uci("b7b8q").unwrap());
+    assert!(position.promoted.get_square(Square::B8));
+
+    // A captured promoted piece goes to the pocket as a pawn.
+    let position: Crazyhouse = "1Q~2k3/8/8/8/8/8/7K/1r6[] b - - 0 1".parse().unwrap();
+    let position: Crazyhouse = position.make_move(position.move_from_uci("b1b8").unwrap());
+    assert_eq!(position.pockets.count(Color::Black, PieceType::Pawn), 1);
+    assert_eq!(position.pockets.count(Color::Black, PieceType::Queen), 0);
+    assert_eq!(position.promoted, BitBoard::EMPTY);
+
+    let position: Crazyhouse = position.make_move(position.move_from_uci("h2g2").unwrap());
+    let drop: Move = position.move_from_uci("P@e4").unwrap();
+    assert_eq!(drop, Move::new_drop(PieceType::Pawn, Square::E4));
+    assert!(drop == "P@e4");
+    assert!(position.gen_moves::<QuietMoves>().contains(&drop));
+    assert!(
+        position
+            .gen_moves::<TacticalMoves>()
+            .iter()
+            .all(|mv| !mv.is_drop())
+    );
+
+    // Returning `false` stops the enumeration among board moves and among drops.
+    let mut seen: usize = 0;
+    assert!(!position.enumerate_moves::<AllMoves, _>(|_| {
+        seen += 1;
+        false
+    }));
+    assert_eq!(seen, 1);
+    let mut seen: usize = 0;
+    assert!(!position.enumerate_moves::<AllMoves, _>(|mv: Move| {
+        seen += 1;
+        !mv.is_drop()
+    }));
+    assert_eq!(seen, count_legal_moves::<AllMoves>(&position.board) + 1);
+
+    assert_eq!(
+        position.move_from_uci("K@e4"),
+        Err(UciMoveError::InvalidDropPiece('K'))
+    );
+    assert_eq!(
+        position.move_from_uci("N@e4"),
+        Err(UciMoveError::IllegalMove)
+    );
+    assert_eq!(
+        position.move_from_uci("P@e1"),
+        Err(UciMoveError::IllegalMove)
+    );
+    assert_eq!(
+        position.move_from_uci("P@e8"),
+        Err(UciMoveError::IllegalMove)
+    );
+
+    let position: Crazyhouse = position.make_move(drop);
+    assert!(position.pockets.is_empty());
+    assert_eq!(position.to_string(), "1r2k3/8/8/8/4p3/8/6K1/8[] w - - 0 3");
+}
+
+#[test]
+fn test_crazyhouse_drop_mates() {
+    let mut position: Crazyhouse = "6k1/5ppp/8/8/8/8/8/4K3[R] w - - 0 1".parse().unwrap();
+    let mate: Move = Move::new_drop(PieceType::Rook, Square::E8);
+    let check: Move = Move::new_drop(PieceType::Rook, Square::F8);
+    assert!(position.gen_moves::<AllMoves>().contains(&mate));
+
+    position.drop_mates = false;
+    let moves: MoveList = position.gen_moves::<AllMoves>();
+    assert!(!moves.contains(&mate));
+    assert!(moves.contains(&check));
+    assert_eq!(
+        moves.len() + 5,
+        Crazyhouse {
+            drop_mates: true,
+            ..position
+        }
+        .perft(1) as usize
+    );
+}
+
+#[test]
+fn test_atomic_perft() {
+    // Reference counts from shakmaty.
+    let positions: [(&str, &[u64]); 5] = [
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            &[20, 400, 8902, 197326],
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            &[48, 1939, 88298],
+        ),
+        (
+            "rnbqkb1r/pp2pppp/5n2/2ppP3/8/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 4",
+            &[32, 961, 30019],
+        ),
+        (
+            "r4b1r/2kb1N2/p2Bpnp1/8/2Pp3p/1P1PPP2/P5PP/R3K2R b KQ - 0 1",
+            &[4, 148, 4462, 155172],
+        ),
+        ("8/8/8/3kK3/8/8/3q4/8 w - - 0 1", &[6, 157, 936, 26482]),
+    ];
+
+    for (fen, counts) in positions {
+        let position: Atomic = fen.parse().unwrap();
+        for (depth, &count) in counts.iter().enumerate() {
+            assert_eq!(
+                position.perft(depth + 1),
+                count,
+                "{fen} at depth {}",
+                depth + 1
+            );
+        }
+    }
+}
+
+#[test]
+fn test_atomic_rules() {
+    // Touching kings cannot give check, and the king may not capture.
+    let position: Atomic = "8/8/8/3kK3/8/8/3q4/8 w - - 0 1".parse().unwrap();
+    assert!(position.board.checkers.is_empty());
+    assert_eq!(
+        position.move_from_uci("e5d5"),
+        Err(UciMoveError::IllegalMove)
+    );
+
+    // Exploding the enemy king wins, even with a pinned piece.
+    let position: Atomic = "8/4r3/8/1k6/2n5/8/4B3/4K3 w - - 0 1".parse().unwrap();
+    assert_eq!(
+        position.move_from_uci("e2d3"),
+        Err(UciMoveError::IllegalMove)
+    );
+    let next: Atomic = position.make_move(position.move_from_uci("e2c4").unwrap());
+    assert_eq!(next.winner(), Some(Color::White));
+    assert_eq!(next.perft(1), 0);
+    assert_eq!(next.board.combined_bitboard().count_bits(), 2);
+
+    // Exploded rooks lose their castling rights.
+    let position: Atomic = "r3k3/1p6/8/8/8/8/6n1/R3K2R b KQq - 0 1".parse().unwrap();
+    let next: Atomic = position.make_move(position.move_from_uci("a8a1").unwrap());
+    assert_eq!(next.to_string(), "4k3/1p6/8/8/8/8/6n1/4K2R w K - 0 2");
+    let next: Atomic = position.make_move(position.move_from_uci("g2h4").unwrap());
+    let next: Atomic = next.make_move(next.move_from_uci("e1g1").unwrap());
+    assert_eq!(next.to_string(), "r3k3/1p6/8/8/7n/8/8/R4RK1 b q - 2 2");
+
+    // Bare kings, or a single minor piece, cannot explode the enemy king.
+    let position: Atomic = "8/8/5K2/8/8/4k3/8/8 w - - 0 142".parse().unwrap();
+    assert_eq!(position.status(), GameResult::Draw);
+    let position: Atomic = "8/8/5K2/8/3n4/4k3/8/8 w - - 0 1".parse().unwrap();
+    assert_eq!(position.status(), GameResult::Draw);
+    let position: Atomic = "8/8/5K2/8/3r4/4k3/8/8 w - - 0 1".parse().unwrap();
+    assert_eq!(position.status(), GameResult::Unfinished);
+}
+
+#[test]
+fn test_horde_perft() {
+    // Reference counts from shakmaty.
+    let positions: [(&str, &[u64]); 4] = [
+        (Horde::START_FEN, &[8, 128, 1274, 23310]),
+        (
+            "4k3/pp4q1/3P2p1/8/P3PP2/PPP2r2/PPP5/PPPP4 b - - 1 1",
+            &[30, 241, 6633, 56539],
+        ),
+        (
+            "k7/5p2/4p2P/3p2P1/2p2P2/1p2P2P/p2P2P1/2P2P2 w - - 0 1",
+            &[13, 172, 2205, 33781],
+        ),
+        (
+            "rnbqkbnr/6p1/2p1Pp1P/P1PPPP2/Pp4PP/1p2PPPP/1P2PPPP/PP1nPPPP b kq a3 0 18",
+            &[34, 435, 14481],
+        ),
+    ];
+
+    for (fen, counts) in positions {
+        let position: Horde = fen.parse().unwrap();
+        for (depth, &count) in counts.iter().enumerate() {
+            assert_eq!(
+                position.perft(depth + 1),
+                count,
+                "{fen} at depth {}",
+                depth + 1
+            );
+        }
+    }
+}
+
+#[test]
+fn test_horde_rules() {
+    assert_eq!(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".parse::<Horde>(),
+        Err(BoardParseError::InvalidKingCount)
+    );
+    assert_eq!(Horde::default().validate(), Ok(()));
+    assert_eq!(Horde::default().board.to_packed(), None);
+    assert_eq!(
+        Board::from_fen_with(Horde::START_FEN, FenOptions::STRICT),
+        Err(BoardParseError::InvalidKingCount)
+    );
+
+    // First-rank pawns double push without an en passant square.
+    let position: Horde = "4k3/8/8/8/8/8/8/P7 w - - 0 1".parse().unwrap();
+    let mv: Move = position.move_from_uci("a1a3").unwrap();
+    assert!(mv.is_double_pawn());
+    assert_eq!(position.make_move(mv).board.enpassant_square, None);
+
+    // Black wins by capturing the whole horde.
+    let position: Horde = "4k3/8/8/8/8/8/1p6/P7 b - - 0 1".parse().unwrap();
+    assert_eq!(position.winner(), None);
+    let position: Horde = position.make_move(position.move_from_uci("b2a1q").unwrap());
+    assert_eq!(position.winner(), Some(Color::Black));
+    assert_eq!(position.perft(1), 0);
+    assert_eq!(position.validate(), Ok(()));
+}
+
+#[test]
+fn test_king_of_the_hill() {
+    // Reference counts from shakmaty.
+    let positions: [(&str, &[u64]); 3] = [
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            &[48, 2039, 97862],
+        ),
+        ("8/8/8/2k5/8/8/5K2/8 w - - 0 1", &[8, 63, 327, 2454]),
+        ("8/2k5/8/8/8/8/8/4K3 b - - 0 1", &[8, 40, 275, 1768]),
+    ];
+    for (fen, counts) in positions {
+        let position: KingOfTheHill = fen.parse().unwrap();
+        for (depth, &count) in counts.iter().enumerate() {
+            assert_eq!(
+                position.perft(depth + 1),
+                count,
+                "{fen} at depth {}",
+                depth + 1
+            );
+        }
+    }
+
+    let position: KingOfTheHill = "8/2k5/8/8/8/8/8/4K3 b - - 0 1".parse().unwrap();
+    let position: KingOfTheHill = position.make_move(position.move_from_uci("c7d6").unwrap());
+    assert_eq!(position.status(), GameResult::Unfinished);
+    let position: KingOfTheHill = position.make_move(position.move_from_uci("e1e2").unwrap());
+    let position: KingOfTheHill = position.make_move(position.move_from_uci("d6d5").unwrap());
+    assert_eq!(position.status(), GameResult::BlackWins);
+    assert!(position.gen_moves::<AllMoves>().is_empty());
+    assert_eq!(
+        position.move_from_uci("e2e3"),
+        Err(UciMoveError::IllegalMove)
+    );
+}
+
+#[test]
+fn test_three_check() {
+    // Reference counts from shakmaty.
+    let positions: [(&str, &[u64]); 3] = [
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 1+2 0 1",
+            &[48, 2039, 97862],
+        ),
+        ("4k3/8/8/8/8/8/8/4K2R w K - 1+3 0 1", &[15, 63, 1140, 6208]),
+        (
+            "rnb1kbnr/pppp1ppp/8/4p3/5PPq/8/PPPPP2P/RNBQKBNR w KQkq - 2+1 1 3",
+            &[0],
+        ),
+    ];
+    for (fen, counts) in positions {
+        let position: ThreeCheck = fen.parse().unwrap();
+        for (depth, &count) in counts.iter().enumerate() {
+            assert_eq!(
+                position.perft(depth + 1),
+                count,
+                "{fen} at depth {}",
+                depth + 1
+            );
+        }
+    }
+
+    let position: ThreeCheck = "4k3/8/8/8/8/8/8/4K2R w K - 0 1 +1+2".parse().unwrap();
+    assert_eq!(position.checks, [1, 2]);
+    assert_eq!(position.to_string(), "4k3/8/8/8/8/8/8/4K2R w K - 2+1 0 1");
+    assert_eq!(
+        position.to_string().parse::<ThreeCheck>().unwrap(),
+        position
+    );
+    assert_ne!(position.zobrist(), position.board.zobrist);
+    assert_eq!(ThreeCheck::default().zobrist(), Board::default().zobrist);
+
+    for fen in [
+        "4k3/8/8/8/8/8/8/4K2R w K - 4+3 0 1",
+        "4k3/8/8/8/8/8/8/4K2R w K - 3+3 0 1 +0+0",
+        "4k3/8/8/8/8/8/8/4K2R w K - 3+ 0 1",
+    ] {
+        assert_eq!(
+            fen.parse::<ThreeCheck>(),
+            Err(BoardParseError::InvalidCheckCount)
+        );
+    }
+
+    let position: ThreeCheck = "4k3/8/8/8/8/8/8/4K2R w K - 2+3 0 1".parse().unwrap();
+    let position: ThreeCheck = position.make_move(position.move_from_uci("h1h8").unwrap());
+    let position: ThreeCheck = position.make_move(position.move_from_uci("e8e7").unwrap());
+    assert_eq!(position.status(), GameResult::Unfinished);
+    let position: ThreeCheck = position.make_move(position.move_from_uci("h8h7").unwrap());
+    assert_eq!(position.checks_given(Color::White), 3);
+    assert_eq!(position.winner(), Some(Color::White));
+    assert_eq!(position.status(), GameResult::WhiteWins);
+    assert!(position.gen_moves::<AllMoves>().is_empty());
+    assert_eq!(
+        position.move_from_uci("e7e6"),
+        Err(UciMoveError::IllegalMove)
+    );
+
+    // The public counters saturate instead of overflowing.
+    let mut position: ThreeCheck = "4k3/8/8/8/8/8/8/4K2R w K - 0 1".parse().unwrap();
+    position.checks = [u8::MAX, 0];
+    let position: ThreeCheck = position.make_move(position.board.move_from_uci("h1h8").unwrap());
+    assert_eq!(position.checks, [u8::MAX, 0]);
+
+    let position: ThreeCheck = "4k3/8/8/8/8/8/8/4K3 w - - 0 1".parse().unwrap();
+    assert_eq!(position.status(), GameResult::Draw);
+}
+
+#[test]
+fn test_variant_trait() {
+    fn play<V: Variant>(fen: &str, moves: &[&str]) -> V {
+        moves
+            .iter()
+            .fold(fen.parse::<V>().unwrap(), |position, uci| {
+                position.make_move(position.move_from_uci(uci).unwrap())
+            })
+    }
+
+    let fools_mate: &str = "rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR w KQkq - 0 2";
+    assert_eq!(
+        play::<Board>(fools_mate, &[]).status(),
+        GameResult::Unfinished
+    );
+    assert_eq!(
+        play::<Board>(fools_mate, &["a2a3", "d8h4"]).status(),
+        GameResult::BlackWins
+    );
+    assert_eq!(
+        play::<Crazyhouse>(fools_mate, &["a2a3", "d8h4"]).status(),
+        GameResult::BlackWins
+    );
+    assert_eq!(
+        play::<Crazyhouse>(fools_mate, &["a2a3", "e5e4", "f3e4", "d8h4"]).status(),
+        GameResult::Unfinished
+    );
+
+    let atomic: Atomic = play(
+        "rnbqkbnr/ppp1pppp/8/3p4/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2",
+        &["d5e4", "d1e2", "d8d2"],
+    );
+    assert_eq!(atomic.status(), GameResult::BlackWins);
+    assert!(atomic.gen_moves::<AllMoves>().is_empty());
+
+    let horde: Horde = play("k7/8/8/8/8/8/p7/P7 w - - 0 1", &[]);
+    assert_eq!(horde.winner(), None);
+    assert_eq!(horde.status(), GameResult::Draw);
+    assert_eq!(
+        play::<Horde>("k7/8/8/8/8/8/8/7P b - - 0 1", &["a8b8"]).status(),
+        GameResult::Unfinished
+    );
+
+    assert_eq!(
+        [
+            Board::NAME,
+            Crazyhouse::NAME,
+            Atomic::NAME,
+            Horde::NAME,
+            KingOfTheHill::NAME,
+            ThreeCheck::NAME
+        ],
+        [
+            "chess",
+            "crazyhouse",
+            "atomic",
+            "horde",
+            "kingofthehill",
+            "3check"
+        ]
+    );
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "wasm")]
+
+use laura_core::wasm::WasmBoard;
+use laura_core::*;
+
+#[test]
+fn test_wasm_board() {
+    let mut board: WasmBoard = WasmBoard::new();
+    assert_eq!(board.legal_moves().len(), 20);
+    assert!(board.legal_moves().contains(&String::from("g1f3")));
+    assert_eq!(board.san("g1f3").ok().unwrap(), "Nf3");
+
+    assert!(board.make_move("e2e4").is_ok());
+    assert_eq!(
+        board.to_fen(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    );
+
+    let board: WasmBoard =
+        WasmBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .ok()
+            .unwrap();
+    assert_eq!(Board::from(board), Board::kiwipete());
+    assert_eq!(board.legal_moves().len(), 48);
+    assert!(board.legal_moves().contains(&String::from("e1g1")));
+    assert_eq!(board.san("e1c1").ok().unwrap(), "O-O-O");
+}